@@ -28,11 +28,19 @@ fn ints_json(bench: &mut Bencher) {
     Python::with_gil(|py| {
         let validator = build_schema_validator(py, "{'type': 'int'}");
 
-        let result = validator.validate_json(py, json(py, "123"), None, None, None).unwrap();
+        let result = validator
+            .validate_json(py, json(py, "123"), None, None, None, None, None, false, false)
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
 
-        bench.iter(|| black_box(validator.validate_json(py, json(py, "123"), None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, json(py, "123"), None, None, None, None, None, false, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -43,12 +51,20 @@ fn ints_python(bench: &mut Bencher) {
 
         let input = 123_i64.into_py(py);
         let input = input.as_ref(py);
-        let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python(py, input, None, None, None, None, None, None)
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 123);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -61,7 +77,13 @@ fn list_int_json(bench: &mut Bencher) {
             (0..100).map(|x| x.to_string()).collect::<Vec<String>>().join(",")
         );
 
-        bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, json(py, &code), None, None, None, None, None, false, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -82,7 +104,9 @@ fn list_int_python(bench: &mut Bencher) {
         let (validator, input) = list_int_input(py);
         let input = black_box(input.as_ref(py));
         bench.iter(|| {
-            let v = validator.validate_python(py, input, None, None, None, None).unwrap();
+            let v = validator
+                .validate_python(py, input, None, None, None, None, None, None)
+                .unwrap();
             black_box(v)
         })
     })
@@ -93,11 +117,15 @@ fn list_int_python_isinstance(bench: &mut Bencher) {
     Python::with_gil(|py| {
         let (validator, input) = list_int_input(py);
         let input = black_box(input.as_ref(py));
-        let v = validator.isinstance_python(py, input, None, None, None, None).unwrap();
+        let v = validator
+            .isinstance_python(py, input, None, None, None, None, None)
+            .unwrap();
         assert!(v);
 
         bench.iter(|| {
-            let v = validator.isinstance_python(py, input, None, None, None, None).unwrap();
+            let v = validator
+                .isinstance_python(py, input, None, None, None, None, None)
+                .unwrap();
             black_box(v)
         })
     })
@@ -115,7 +143,7 @@ fn list_error_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        match validator.validate_json(py, json(py, &code), None, None, None) {
+        match validator.validate_json(py, json(py, &code), None, None, None, None, None, false, false) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -127,7 +155,7 @@ fn list_error_json(bench: &mut Bencher) {
         };
 
         bench.iter(
-            || match validator.validate_json(py, json(py, &code), None, None, None) {
+            || match validator.validate_json(py, json(py, &code), None, None, None, None, None, false, false) {
                 Ok(_) => panic!("unexpectedly valid"),
                 Err(e) => black_box(e),
             },
@@ -147,7 +175,7 @@ fn list_error_python_input(py: Python<'_>) -> (SchemaValidator, PyObject) {
 
     let input = py.eval(&code, None, None).unwrap();
 
-    match validator.validate_python(py, input, None, None, None, None) {
+    match validator.validate_python(py, input, None, None, None, None, None, None) {
         Ok(_) => panic!("unexpectedly valid"),
         Err(e) => {
             let v = e.value(py);
@@ -167,7 +195,7 @@ fn list_error_python(bench: &mut Bencher) {
 
         let input = black_box(input.as_ref(py));
         bench.iter(|| {
-            let result = validator.validate_python(py, input, None, None, None, None);
+            let result = validator.validate_python(py, input, None, None, None, None, None, None);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -182,13 +210,17 @@ fn list_error_python_isinstance(bench: &mut Bencher) {
     Python::with_gil(|py| {
         let (validator, input) = list_error_python_input(py);
         let r = validator
-            .isinstance_python(py, black_box(input.as_ref(py)), None, None, None, None)
+            .isinstance_python(py, black_box(input.as_ref(py)), None, None, None, None, None)
             .unwrap();
         assert!(!r);
 
         let input = black_box(input.as_ref(py));
         bench.iter(|| {
-            black_box(validator.isinstance_python(py, input, None, None, None, None).unwrap());
+            black_box(
+                validator
+                    .isinstance_python(py, input, None, None, None, None, None)
+                    .unwrap(),
+            );
         })
     })
 }
@@ -202,7 +234,13 @@ fn list_any_json(bench: &mut Bencher) {
             (0..100).map(|x| x.to_string()).collect::<Vec<String>>().join(",")
         );
 
-        bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, json(py, &code), None, None, None, None, None, false, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -217,7 +255,9 @@ fn list_any_python(bench: &mut Bencher) {
         let input = py.eval(&code, None, None).unwrap();
         let input = black_box(input);
         bench.iter(|| {
-            let v = validator.validate_python(py, input, None, None, None, None).unwrap();
+            let v = validator
+                .validate_python(py, input, None, None, None, None, None, None)
+                .unwrap();
             black_box(v)
         })
     })
@@ -247,7 +287,13 @@ fn dict_json(bench: &mut Bencher) {
                 .join(", ")
         );
 
-        bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, json(py, &code), None, None, None, None, None, false, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -269,7 +315,9 @@ fn dict_python(bench: &mut Bencher) {
         let input = py.eval(&code, None, None).unwrap();
         let input = black_box(input);
         bench.iter(|| {
-            let v = validator.validate_python(py, input, None, None, None, None).unwrap();
+            let v = validator
+                .validate_python(py, input, None, None, None, None, None, None)
+                .unwrap();
             black_box(v)
         })
     })
@@ -297,7 +345,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = py.eval(&code, None, None).unwrap();
 
-        match validator.validate_python(py, input, None, None, None, None) {
+        match validator.validate_python(py, input, None, None, None, None, None, None) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -310,7 +358,7 @@ fn dict_value_error(bench: &mut Bencher) {
 
         let input = black_box(input);
         bench.iter(|| {
-            let result = validator.validate_python(py, input, None, None, None, None);
+            let result = validator.validate_python(py, input, None, None, None, None, None, None);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -345,7 +393,13 @@ fn typed_dict_json(bench: &mut Bencher) {
 
         let code = r#"{"a": 1, "b": 2, "c": 3, "d": 4, "e": 5, "f": 6, "g": 7, "h": 8, "i": 9, "j": 0}"#.to_string();
 
-        bench.iter(|| black_box(validator.validate_json(py, json(py, &code), None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, json(py, &code), None, None, None, None, None, false, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -376,7 +430,9 @@ fn typed_dict_python(bench: &mut Bencher) {
         let input = py.eval(&code, None, None).unwrap();
         let input = black_box(input);
         bench.iter(|| {
-            let v = validator.validate_python(py, input, None, None, None, None).unwrap();
+            let v = validator
+                .validate_python(py, input, None, None, None, None, None, None)
+                .unwrap();
             black_box(v)
         })
     })
@@ -416,7 +472,7 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         let input = py.eval(code, None, None).unwrap();
         let input = black_box(input);
 
-        match validator.validate_python(py, input, None, None, None, None) {
+        match validator.validate_python(py, input, None, None, None, None, None, None) {
             Ok(_) => panic!("unexpectedly valid"),
             Err(e) => {
                 let v = e.value(py);
@@ -428,7 +484,7 @@ fn typed_dict_deep_error(bench: &mut Bencher) {
         };
 
         bench.iter(|| {
-            let result = validator.validate_python(py, input, None, None, None, None);
+            let result = validator.validate_python(py, input, None, None, None, None, None, None);
 
             match result {
                 Ok(_) => panic!("unexpectedly valid"),
@@ -453,7 +509,11 @@ fn complete_model(bench: &mut Bencher) {
         let input = black_box(input);
 
         bench.iter(|| {
-            black_box(validator.validate_python(py, input, None, None, None, None).unwrap());
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            );
         })
     })
 }
@@ -472,10 +532,16 @@ fn nested_model_using_definitions(bench: &mut Bencher) {
         let input = complete_schema.call_method0("input_data_valid").unwrap();
         let input = black_box(input);
 
-        validator.validate_python(py, input, None, None, None, None).unwrap();
+        validator
+            .validate_python(py, input, None, None, None, None, None, None)
+            .unwrap();
 
         bench.iter(|| {
-            black_box(validator.validate_python(py, input, None, None, None, None).unwrap());
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            );
         })
     })
 }
@@ -494,10 +560,16 @@ fn nested_model_inlined(bench: &mut Bencher) {
         let input = complete_schema.call_method0("input_data_valid").unwrap();
         let input = black_box(input);
 
-        validator.validate_python(py, input, None, None, None, None).unwrap();
+        validator
+            .validate_python(py, input, None, None, None, None, None, None)
+            .unwrap();
 
         bench.iter(|| {
-            black_box(validator.validate_python(py, input, None, None, None, None).unwrap());
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            );
         })
     })
 }
@@ -509,12 +581,20 @@ fn literal_ints_few_python(bench: &mut Bencher) {
 
         let input = 4_i64.into_py(py);
         let input = input.as_ref(py);
-        let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python(py, input, None, None, None, None, None, None)
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 4);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -525,12 +605,20 @@ fn literal_strings_few_small_python(bench: &mut Bencher) {
 
         let input = py.eval("'4'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
-        let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python(py, input, None, None, None, None, None, None)
+            .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -544,12 +632,20 @@ fn literal_strings_few_large_python(bench: &mut Bencher) {
 
         let input = py.eval("'a' * 25 + '4'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
-        let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python(py, input, None, None, None, None, None, None)
+            .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -579,11 +675,19 @@ class Foo(Enum):
         );
 
         let input = py.eval("Foo.v4", Some(globals), None).unwrap();
-        let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python(py, input, None, None, None, None, None, None)
+            .unwrap();
         assert!(input.eq(result).unwrap());
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -594,12 +698,20 @@ fn literal_ints_many_python(bench: &mut Bencher) {
 
         let input = 99_i64.into_py(py);
         let input = input.as_ref(py);
-        let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python(py, input, None, None, None, None, None, None)
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -610,12 +722,20 @@ fn literal_strings_many_small_python(bench: &mut Bencher) {
 
         let input = py.eval("'99'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
-        let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python(py, input, None, None, None, None, None, None)
+            .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -629,12 +749,20 @@ fn literal_strings_many_large_python(bench: &mut Bencher) {
 
         let input = py.eval("'a' * 25 + '99'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
-        let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+        let result = validator
+            .validate_python(py, input, None, None, None, None, None, None)
+            .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input = black_box(input);
-        bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_python(py, input, None, None, None, None, None, None)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -644,12 +772,20 @@ fn literal_ints_many_json(bench: &mut Bencher) {
         let validator = build_schema_validator(py, "{'type': 'literal', 'expected': list(range(100))}");
 
         let input_json = py.eval("'99'", None, None).unwrap();
-        let result = validator.validate_json(py, input_json, None, None, None).unwrap();
+        let result = validator
+            .validate_json(py, input_json, None, None, None, None, None, false, false)
+            .unwrap();
         let result_int: i64 = result.extract(py).unwrap();
         assert_eq!(result_int, 99);
 
         let input_json = black_box(input_json);
-        bench.iter(|| black_box(validator.validate_json(py, input_json, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, input_json, None, None, None, None, None, false, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -664,12 +800,20 @@ fn literal_strings_many_large_json(bench: &mut Bencher) {
         let input = py.eval("'a' * 25 + '99'", None, None).unwrap();
         let input_json = py.eval("'\"' + 'a' * 25 + '99' + '\"'", None, None).unwrap();
         let input_str: String = input.extract().unwrap();
-        let result = validator.validate_json(py, input_json, None, None, None).unwrap();
+        let result = validator
+            .validate_json(py, input_json, None, None, None, None, None, false, false)
+            .unwrap();
         let result_str: String = result.extract(py).unwrap();
         assert_eq!(result_str, input_str);
 
         let input_json = black_box(input_json);
-        bench.iter(|| black_box(validator.validate_json(py, input_json, None, None, None).unwrap()))
+        bench.iter(|| {
+            black_box(
+                validator
+                    .validate_json(py, input_json, None, None, None, None, None, false, false)
+                    .unwrap(),
+            )
+        })
     })
 }
 
@@ -701,44 +845,76 @@ class Foo(Enum):
         {
             let input = py.eval("'null'", None, None).unwrap();
             let input_str: String = input.extract().unwrap();
-            let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+            let result = validator
+                .validate_python(py, input, None, None, None, None, None, None)
+                .unwrap();
             let result_str: String = result.extract(py).unwrap();
             assert_eq!(result_str, input_str);
 
             let input = black_box(input);
-            bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+            bench.iter(|| {
+                black_box(
+                    validator
+                        .validate_python(py, input, None, None, None, None, None, None)
+                        .unwrap(),
+                )
+            })
         }
 
         // Int
         {
             let input = py.eval("-1", None, None).unwrap();
             let input_int: i64 = input.extract().unwrap();
-            let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+            let result = validator
+                .validate_python(py, input, None, None, None, None, None, None)
+                .unwrap();
             let result_int: i64 = result.extract(py).unwrap();
             assert_eq!(result_int, input_int);
 
             let input = black_box(input);
-            bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+            bench.iter(|| {
+                black_box(
+                    validator
+                        .validate_python(py, input, None, None, None, None, None, None)
+                        .unwrap(),
+                )
+            })
         }
 
         // None
         {
             let input = py.eval("None", None, None).unwrap();
-            let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+            let result = validator
+                .validate_python(py, input, None, None, None, None, None, None)
+                .unwrap();
             assert!(input.eq(result).unwrap());
 
             let input = black_box(input);
-            bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+            bench.iter(|| {
+                black_box(
+                    validator
+                        .validate_python(py, input, None, None, None, None, None, None)
+                        .unwrap(),
+                )
+            })
         }
 
         // Enum
         {
             let input = py.eval("Foo.v4", Some(globals), None).unwrap();
-            let result = validator.validate_python(py, input, None, None, None, None).unwrap();
+            let result = validator
+                .validate_python(py, input, None, None, None, None, None, None)
+                .unwrap();
             assert!(input.eq(result).unwrap());
 
             let input = black_box(input);
-            bench.iter(|| black_box(validator.validate_python(py, input, None, None, None, None).unwrap()))
+            bench.iter(|| {
+                black_box(
+                    validator
+                        .validate_python(py, input, None, None, None, None, None, None)
+                        .unwrap(),
+                )
+            })
         }
     })
 }
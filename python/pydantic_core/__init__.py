@@ -24,6 +24,7 @@ from ._pydantic_core import (
     __version__,
     from_json,
     to_json,
+    to_json_stream,
     to_jsonable_python,
     validate_core_schema,
 )
@@ -64,6 +65,7 @@ __all__ = [
     'PydanticSerializationUnexpectedValue',
     'TzInfo',
     'to_json',
+    'to_json_stream',
     'from_json',
     'to_jsonable_python',
     'validate_core_schema',
@@ -80,6 +82,10 @@ class ErrorDetails(_TypedDict):
     """
     loc: tuple[int | str, ...]
     """Tuple of strings and ints identifying where in the schema the error occurred."""
+    field_loc: _NotRequired[tuple[int | str, ...]]
+    """
+    Like `loc` but using field names rather than aliases, only included with `errors(include_field_loc=True)`.
+    """
     msg: str
     """A human readable error message."""
     input: _Any
@@ -89,6 +95,17 @@ class ErrorDetails(_TypedDict):
     Values which are required to render the error message, and could hence be useful in rendering custom error messages.
     Also useful for passing custom error data forward.
     """
+    offset: _NotRequired[int]
+    """
+    Byte offset of the value which caused the error in the JSON input, starting at 0.
+    Only set by `validate_json(..., error_positions=True)`, as are `line`, `column` and `json_pointer`.
+    """
+    line: _NotRequired[int]
+    """Line of the value which caused the error in the JSON input, starting at 1."""
+    column: _NotRequired[int]
+    """Column of the value which caused the error in the JSON input, in bytes, starting at 1."""
+    json_pointer: _NotRequired[str]
+    """`loc` rendered as a [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901), e.g. `/foo/0/bar`."""
 
 
 class InitErrorDetails(_TypedDict):
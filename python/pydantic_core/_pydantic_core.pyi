@@ -17,7 +17,7 @@ if sys.version_info < (3, 11):
 else:
     from typing import Literal, LiteralString, Self, TypeAlias
 
-from _typeshed import SupportsAllComparisons
+from _typeshed import SupportsAllComparisons, SupportsWrite
 
 __all__ = [
     '__version__',
@@ -41,6 +41,7 @@ __all__ = [
     'PydanticUndefinedType',
     'Some',
     'to_json',
+    'to_json_stream',
     'from_json',
     'to_jsonable_python',
     'list_all_errors',
@@ -103,6 +104,8 @@ class SchemaValidator:
         from_attributes: bool | None = None,
         context: 'dict[str, Any] | None' = None,
         self_instance: Any | None = None,
+        fail_fast: bool | None = None,
+        loc_by_alias: bool | None = None,
     ) -> Any:
         """
         Validate a Python object against the schema and return the validated object.
@@ -117,6 +120,10 @@ class SchemaValidator:
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation, this is used when running
                 validation from the `__init__` method of a model.
+            fail_fast: Whether to stop validation at the first error rather than collecting all errors.
+                If `None`, the value of [`CoreConfig.fail_fast`][pydantic_core.core_schema.CoreConfig] is used.
+            loc_by_alias: Whether to use a field's alias rather than its name in error locations.
+                If `None`, the value of [`CoreConfig.loc_by_alias`][pydantic_core.core_schema.CoreConfig] is used.
 
         Raises:
             ValidationError: If validation fails.
@@ -133,6 +140,7 @@ class SchemaValidator:
         from_attributes: bool | None = None,
         context: 'dict[str, Any] | None' = None,
         self_instance: Any | None = None,
+        fail_fast: bool | None = None,
     ) -> bool:
         """
         Similar to [`validate_python()`][pydantic_core.SchemaValidator.validate_python] but returns a boolean.
@@ -150,6 +158,10 @@ class SchemaValidator:
         strict: bool | None = None,
         context: 'dict[str, Any] | None' = None,
         self_instance: Any | None = None,
+        fail_fast: bool | None = None,
+        loc_by_alias: bool | None = None,
+        allow_partial: bool = False,
+        error_positions: bool = False,
     ) -> Any:
         """
         Validate JSON data directly against the schema and return the validated Python object.
@@ -167,6 +179,16 @@ class SchemaValidator:
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation.
+            fail_fast: Whether to stop validation at the first error rather than collecting all errors.
+                If `None`, the value of [`CoreConfig.fail_fast`][pydantic_core.core_schema.CoreConfig] is used.
+            loc_by_alias: Whether to use a field's alias rather than its name in error locations.
+                If `None`, the value of [`CoreConfig.loc_by_alias`][pydantic_core.core_schema.CoreConfig] is used.
+            allow_partial: Whether to allow truncated JSON, e.g. from a streamed response. Incomplete JSON is
+                completed where possible, and errors in the last item of lists, sets, dicts, typed dicts and
+                model fields are ignored, with that item being omitted from the result.
+            error_positions: Whether to record where in the JSON data each error occurred, if `True` errors
+                include `offset`, `line` and `column` keys locating the offending value, and a `json_pointer`
+                rendering of `loc`.
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
@@ -176,7 +198,13 @@ class SchemaValidator:
             The validated Python object.
         """
     def validate_strings(
-        self, input: _StringInput, *, strict: bool | None = None, context: 'dict[str, Any] | None' = None
+        self,
+        input: _StringInput,
+        *,
+        strict: bool | None = None,
+        context: 'dict[str, Any] | None' = None,
+        fail_fast: bool | None = None,
+        loc_by_alias: bool | None = None,
     ) -> Any:
         """
         Validate a string against the schema and return the validated Python object.
@@ -190,6 +218,10 @@ class SchemaValidator:
                 If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            fail_fast: Whether to stop validation at the first error rather than collecting all errors.
+                If `None`, the value of [`CoreConfig.fail_fast`][pydantic_core.core_schema.CoreConfig] is used.
+            loc_by_alias: Whether to use a field's alias rather than its name in error locations.
+                If `None`, the value of [`CoreConfig.loc_by_alias`][pydantic_core.core_schema.CoreConfig] is used.
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
@@ -207,6 +239,7 @@ class SchemaValidator:
         strict: bool | None = None,
         from_attributes: bool | None = None,
         context: 'dict[str, Any] | None' = None,
+        fail_fast: bool | None = None,
     ) -> dict[str, Any] | tuple[dict[str, Any], dict[str, Any] | None, set[str]]:
         """
         Validate an assignment to a field on a model.
@@ -221,6 +254,8 @@ class SchemaValidator:
                 If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            fail_fast: Whether to stop validation at the first error rather than collecting all errors.
+                If `None`, the value of [`CoreConfig.fail_fast`][pydantic_core.core_schema.CoreConfig] is used.
 
         Raises:
             ValidationError: If validation fails.
@@ -276,8 +311,10 @@ class SchemaSerializer:
         exclude_defaults: bool = False,
         exclude_none: bool = False,
         round_trip: bool = False,
-        warnings: bool = True,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
         fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
     ) -> Any:
         """
         Serialize/marshal a Python object to a Python object including transforming and filtering data.
@@ -294,9 +331,14 @@ class SchemaSerializer:
             exclude_defaults: Whether to exclude fields that are equal to their default value.
             exclude_none: Whether to exclude fields that have a value of `None`.
             round_trip: Whether to enable serialization and validation round-trip support.
-            warnings: Whether to log warnings when invalid fields are encountered.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs a warning,
+                "error" raises a `PydanticSerializationUnexpectedValue`. Messages include the path to the value.
             fallback: A function to call when an unknown value is encountered,
                 if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize model and dataclass instances with their own
+                `__pydantic_serializer__`, so fields added by subclasses are included.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -316,8 +358,12 @@ class SchemaSerializer:
         exclude_defaults: bool = False,
         exclude_none: bool = False,
         round_trip: bool = False,
-        warnings: bool = True,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
         fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        sort_keys: bool = False,
+        canonical: bool = False,
+        context: Any | None = None,
     ) -> bytes:
         """
         Serialize a Python object to JSON including transforming and filtering data.
@@ -333,9 +379,20 @@ class SchemaSerializer:
             exclude_defaults: Whether to exclude fields that are equal to their default value.
             exclude_none: Whether to exclude fields that have a value of `None`.
             round_trip: Whether to enable serialization and validation round-trip support.
-            warnings: Whether to log warnings when invalid fields are encountered.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs a warning,
+                "error" raises a `PydanticSerializationUnexpectedValue`. Messages include the path to the value.
             fallback: A function to call when an unknown value is encountered,
                 if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize model and dataclass instances with their own
+                `__pydantic_serializer__`, so fields added by subclasses are included.
+            sort_keys: Whether to write object keys in sorted order and set members in a canonical order,
+                so the output is deterministic.
+            canonical: Whether to write [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON,
+                with keys sorted by UTF-16 code units, no whitespace and numbers formatted as in ECMAScript.
+                Implies `sort_keys`, can't be combined with `indent` and raises an error for `NaN`, `Infinity`
+                and integers which can't be represented exactly as a double.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -344,6 +401,64 @@ class SchemaSerializer:
            JSON bytes.
         """
 
+    def to_json_stream(
+        self,
+        value: Any,
+        fp: SupportsWrite[bytes],
+        *,
+        indent: int | None = None,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        sort_keys: bool = False,
+        canonical: bool = False,
+        context: Any | None = None,
+        buffer_size: int = 65536,
+    ) -> None:
+        """
+        Serialize a Python object to JSON, writing the output to a file-like object in chunks
+        rather than building the whole document in memory.
+
+        Arguments:
+            value: The Python object to serialize.
+            fp: An object with a `write` method which accepts `bytes`, e.g. a file opened in binary mode.
+            indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs a warning,
+                "error" raises a `PydanticSerializationUnexpectedValue`. Messages include the path to the value.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize model and dataclass instances with their own
+                `__pydantic_serializer__`, so fields added by subclasses are included.
+            sort_keys: Whether to write object keys in sorted order and set members in a canonical order,
+                so the output is deterministic.
+            canonical: Whether to write [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON,
+                with keys sorted by UTF-16 code units, no whitespace and numbers formatted as in ECMAScript.
+                Implies `sort_keys`, can't be combined with `indent` and raises an error for `NaN`, `Infinity`
+                and integers which can't be represented exactly as a double.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            buffer_size: The number of bytes to buffer before calling `fp.write`.
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+                Output written before the error is not removed from `fp`.
+        """
+
 def to_json(
     value: Any,
     *,
@@ -355,8 +470,13 @@ def to_json(
     round_trip: bool = False,
     timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
     bytes_mode: Literal['utf8', 'base64'] = 'utf8',
+    datetime_mode: Literal['iso8601', 'timestamp', 'timestamp_ms'] = 'iso8601',
+    timestamp_unit: Literal['s', 'ms', 'us', 'ns'] | None = None,
     serialize_unknown: bool = False,
     fallback: Callable[[Any], Any] | None = None,
+    sort_keys: bool = False,
+    canonical: bool = False,
+    context: Any | None = None,
 ) -> bytes:
     """
     Serialize a Python object to JSON including transforming and filtering data.
@@ -373,10 +493,22 @@ def to_json(
         round_trip: Whether to enable serialization and validation round-trip support.
         timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'` or `'float'`.
         bytes_mode: How to serialize `bytes` objects, either `'utf8'` or `'base64'`.
+        datetime_mode: How to serialize `datetime` objects, either `'iso8601'`, `'timestamp'` (float seconds)
+            or `'timestamp_ms'` (integer milliseconds).
+        timestamp_unit: With `datetime_mode='timestamp'`, serialize `datetime` objects as integer timestamps
+            in this unit rather than float seconds.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
             `"<Unserializable {value_type} object>"` will be used.
         fallback: A function to call when an unknown value is encountered,
             if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+        sort_keys: Whether to write object keys in sorted order and set members in a canonical order,
+            so the output is deterministic.
+        canonical: Whether to write [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON,
+            with keys sorted by UTF-16 code units, no whitespace and numbers formatted as in ECMAScript.
+            Implies `sort_keys`, can't be combined with `indent` and raises an error for `NaN`, `Infinity`
+            and integers which can't be represented exactly as a double.
+        context: The context to use for serialization, this is passed to functional serializers as
+            [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -385,6 +517,66 @@ def to_json(
        JSON bytes.
     """
 
+def to_json_stream(
+    value: Any,
+    fp: SupportsWrite[bytes],
+    *,
+    indent: int | None = None,
+    include: _IncEx = None,
+    exclude: _IncEx = None,
+    by_alias: bool = True,
+    exclude_none: bool = False,
+    round_trip: bool = False,
+    timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
+    bytes_mode: Literal['utf8', 'base64'] = 'utf8',
+    datetime_mode: Literal['iso8601', 'timestamp', 'timestamp_ms'] = 'iso8601',
+    timestamp_unit: Literal['s', 'ms', 'us', 'ns'] | None = None,
+    serialize_unknown: bool = False,
+    fallback: Callable[[Any], Any] | None = None,
+    sort_keys: bool = False,
+    canonical: bool = False,
+    context: Any | None = None,
+    buffer_size: int = 65536,
+) -> None:
+    """
+    Serialize a Python object to JSON, writing the output to a file-like object in chunks.
+
+    This is effectively a standalone version of
+    [`SchemaSerializer.to_json_stream`][pydantic_core.SchemaSerializer.to_json_stream].
+
+    Arguments:
+        value: The Python object to serialize.
+        fp: An object with a `write` method which accepts `bytes`, e.g. a file opened in binary mode.
+        indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
+        include: A set of fields to include, if `None` all fields are included.
+        exclude: A set of fields to exclude, if `None` no fields are excluded.
+        by_alias: Whether to use the alias names of fields.
+        exclude_none: Whether to exclude fields that have a value of `None`.
+        round_trip: Whether to enable serialization and validation round-trip support.
+        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'` or `'float'`.
+        bytes_mode: How to serialize `bytes` objects, either `'utf8'` or `'base64'`.
+        datetime_mode: How to serialize `datetime` objects, either `'iso8601'`, `'timestamp'` (float seconds)
+            or `'timestamp_ms'` (integer milliseconds).
+        timestamp_unit: With `datetime_mode='timestamp'`, serialize `datetime` objects as integer timestamps
+            in this unit rather than float seconds.
+        serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
+            `"<Unserializable {value_type} object>"` will be used.
+        fallback: A function to call when an unknown value is encountered,
+            if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+        sort_keys: Whether to write object keys in sorted order and set members in a canonical order,
+            so the output is deterministic.
+        canonical: Whether to write [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON,
+            with keys sorted by UTF-16 code units, no whitespace and numbers formatted as in ECMAScript.
+            Implies `sort_keys`, can't be combined with `indent` and raises an error for `NaN`, `Infinity`
+            and integers which can't be represented exactly as a double.
+        context: The context to use for serialization, this is passed to functional serializers as
+            [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        buffer_size: The number of bytes to buffer before calling `fp.write`.
+
+    Raises:
+        PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+    """
+
 def from_json(data: str | bytes | bytearray, *, allow_inf_nan: bool = True, cache_strings: bool = True) -> Any:
     """
     Deserialize JSON data to a Python object.
@@ -414,8 +606,11 @@ def to_jsonable_python(
     round_trip: bool = False,
     timedelta_mode: Literal['iso8601', 'float'] = 'iso8601',
     bytes_mode: Literal['utf8', 'base64'] = 'utf8',
+    datetime_mode: Literal['iso8601', 'timestamp', 'timestamp_ms'] = 'iso8601',
+    timestamp_unit: Literal['s', 'ms', 'us', 'ns'] | None = None,
     serialize_unknown: bool = False,
     fallback: Callable[[Any], Any] | None = None,
+    context: Any | None = None,
 ) -> Any:
     """
     Serialize/marshal a Python object to a JSON-serializable Python object including transforming and filtering data.
@@ -432,10 +627,16 @@ def to_jsonable_python(
         round_trip: Whether to enable serialization and validation round-trip support.
         timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'` or `'float'`.
         bytes_mode: How to serialize `bytes` objects, either `'utf8'` or `'base64'`.
+        datetime_mode: How to serialize `datetime` objects, either `'iso8601'`, `'timestamp'` (float seconds)
+            or `'timestamp_ms'` (integer milliseconds).
+        timestamp_unit: With `datetime_mode='timestamp'`, serialize `datetime` objects as integer timestamps
+            in this unit rather than float seconds.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
             `"<Unserializable {value_type} object>"` will be used.
         fallback: A function to call when an unknown value is encountered,
             if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+        context: The context to use for serialization, this is passed to functional serializers as
+            [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -755,7 +956,12 @@ class ValidationError(ValueError):
             The number of errors in the validation error.
         """
     def errors(
-        self, *, include_url: bool = True, include_context: bool = True, include_input: bool = True
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        include_field_loc: bool = False,
     ) -> list[ErrorDetails]:
         """
         Details about each error in the validation error.
@@ -764,6 +970,8 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            include_field_loc: Whether to include `field_loc`, the location of each error using field names
+                rather than aliases.
 
         Returns:
             A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the validation error.
@@ -775,6 +983,7 @@ class ValidationError(ValueError):
         include_url: bool = True,
         include_context: bool = True,
         include_input: bool = True,
+        include_field_loc: bool = False,
     ) -> str:
         """
         Same as [`errors()`][pydantic_core.ValidationError.errors] but returns a JSON string.
@@ -784,6 +993,8 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            include_field_loc: Whether to include `field_loc`, the location of each error using field names
+                rather than aliases.
 
         Returns:
             a JSON string.
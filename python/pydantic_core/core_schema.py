@@ -68,13 +68,27 @@ class CoreConfig(TypedDict, total=False):
         allow_inf_nan: Whether to allow infinity and NaN values for float fields. Default is `True`.
         ser_json_timedelta: The serialization option for `timedelta` values. Default is 'iso8601'.
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
+        ser_json_datetime: The serialization option for `datetime` values, 'timestamp' gives float seconds unless
+            `ser_json_timestamp_unit` is set and 'timestamp_ms' gives integer milliseconds. Default is 'iso8601'.
         ser_json_inf_nan: The serialization option for infinity and NaN values
             in float fields. Default is 'null'.
+        val_json_bytes: How strings are decoded when validating `bytes` from JSON. Default is 'utf8'.
+        timestamp_unit: The unit of unix timestamps when validating `datetime`, `date` and `time` values.
+            Default is 'auto', which infers seconds or milliseconds from the magnitude of the value.
+        ser_json_timestamp_unit: When set, `datetime` values are serialized to JSON as integer unix timestamps
+            in this unit, implies `ser_json_datetime='timestamp'`.
+        duration_unit: The unit of numeric inputs when validating `timedelta` values. Default is 's'.
+        allow_human_durations: Whether `timedelta` validation accepts strings like `'1h30m'` or `'2 days'`.
+        allow_calendar_units: Whether `timedelta` validation accepts months and years, which have no fixed length.
+            Default is `True`.
+        serialize_as_any: Whether model and dataclass instances are serialized with the serializer of their own
+            class rather than the schema's, so fields added by subclasses are included. Default is `False`.
         hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
         validation_error_cause: Whether to add user-python excs to the __cause__ of a ValidationError.
             Requires exceptiongroup backport pre Python 3.11.
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
         regex_engine: The regex engine to use for regex pattern validation. Default is 'rust-regex'. See `StringSchema`.
+        fail_fast: Whether to stop validation at the first error rather than collecting all errors. Default is `False`.
     """
 
     title: str
@@ -104,12 +118,25 @@ class CoreConfig(TypedDict, total=False):
     # the config options are used to customise serialization to JSON
     ser_json_timedelta: Literal['iso8601', 'float']  # default: 'iso8601'
     ser_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
+    ser_json_datetime: Literal['iso8601', 'timestamp', 'timestamp_ms']  # default: 'iso8601'
     ser_json_inf_nan: Literal['null', 'constants']  # default: 'null'
+    # decoding of JSON strings into `bytes` during validation, the counterpart of `ser_json_bytes`
+    val_json_bytes: Literal['utf8', 'base64', 'base64url', 'hex']  # default: 'utf8'
+    # unit of unix timestamps in datetime, date and time validation, and in datetime JSON serialization
+    timestamp_unit: Literal['auto', 's', 'ms', 'us', 'ns']  # default: 'auto'
+    ser_json_timestamp_unit: Literal['s', 'ms', 'us', 'ns']
+    # parsing of durations in timedelta validation
+    duration_unit: Literal['ms', 's', 'min']  # default: 's'
+    allow_human_durations: bool  # default: False
+    allow_calendar_units: bool  # default: True
+    # serialize subclass instances of models and dataclasses with their own `__pydantic_serializer__`
+    serialize_as_any: bool  # default: False
     # used to hide input data from ValidationError repr
     hide_input_in_errors: bool
     validation_error_cause: bool  # default: False
     coerce_numbers_to_str: bool  # default: False
     regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
+    fail_fast: bool  # default: False
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -124,6 +151,11 @@ class SerializationInfo(Protocol):
     def exclude(self) -> IncExCall:
         ...
 
+    @property
+    def context(self) -> Any | None:
+        """Current serialization context."""
+        ...
+
     @property
     def mode(self) -> str:
         ...
@@ -220,6 +252,8 @@ ExpectedSerializationTypes = Literal[
     'multi-host-url',
     'json',
     'uuid',
+    'complex',
+    'timezone',
 ]
 
 
@@ -746,6 +780,43 @@ def decimal_schema(
     )
 
 
+class ComplexSchema(TypedDict, total=False):
+    type: Required[Literal['complex']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def complex_schema(
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> ComplexSchema:
+    """
+    Returns a schema that matches a complex value, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.complex_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('1+2j') == complex(1, 2)
+    assert v.validate_json('[1, 2]') == complex(1, 2)
+    ```
+
+    Args:
+        strict: Whether the value should be a complex object instance or a value that can be converted to
+            a complex object
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='complex', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
 class StringSchema(TypedDict, total=False):
     type: Required[Literal['str']]
     pattern: str
@@ -825,6 +896,7 @@ class BytesSchema(TypedDict, total=False):
     max_length: int
     min_length: int
     strict: bool
+    val_json_bytes: Literal['utf8', 'base64', 'base64url', 'hex']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -835,6 +907,7 @@ def bytes_schema(
     max_length: int | None = None,
     min_length: int | None = None,
     strict: bool | None = None,
+    val_json_bytes: Literal['utf8', 'base64', 'base64url', 'hex'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -854,6 +927,7 @@ def bytes_schema(
         max_length: The value must be at most this length
         min_length: The value must be at least this length
         strict: Whether the value should be a bytes or a value that can be converted to a bytes
+        val_json_bytes: How strings from JSON are decoded into bytes, overrides the `val_json_bytes` config
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -863,6 +937,7 @@ def bytes_schema(
         max_length=max_length,
         min_length=min_length,
         strict=strict,
+        val_json_bytes=val_json_bytes,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -880,6 +955,10 @@ class DateSchema(TypedDict, total=False):
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
+    now_delta: timedelta
+    now_context_key: str
+    formats: List[str]
+    timestamp_unit: Literal['auto', 's', 'ms', 'us', 'ns']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -894,6 +973,10 @@ def date_schema(
     gt: date | None = None,
     now_op: Literal['past', 'future'] | None = None,
     now_utc_offset: int | None = None,
+    now_delta: timedelta | None = None,
+    now_context_key: str | None = None,
+    formats: list[str] | None = None,
+    timestamp_unit: Literal['auto', 's', 'ms', 'us', 'ns'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -918,6 +1001,12 @@ def date_schema(
         gt: The value must be strictly greater than this date
         now_op: The value must be in the past or future relative to the current date
         now_utc_offset: The value must be in the past or future relative to the current date with this utc offset
+        now_delta: Move the date `now_op` compares against, e.g. `now_op='future'` with `timedelta(days=-30)`
+            means within the last 30 days
+        now_context_key: Read a frozen current date or datetime from this key of the validation context
+            rather than using today's date, e.g. for deterministic tests
+        formats: strftime-style formats, e.g. `'%d/%m/%Y'`, tried in order when the input isn't an ISO 8601 date
+        timestamp_unit: The unit of numeric inputs, overrides the `timestamp_unit` config
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -931,6 +1020,10 @@ def date_schema(
         gt=gt,
         now_op=now_op,
         now_utc_offset=now_utc_offset,
+        now_delta=now_delta,
+        now_context_key=now_context_key,
+        formats=formats,
+        timestamp_unit=timestamp_unit,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -945,7 +1038,11 @@ class TimeSchema(TypedDict, total=False):
     lt: time
     gt: time
     tz_constraint: Union[Literal['aware', 'naive'], int]
+    to_tz: Union[Literal['utc'], int]
+    naive_tz: Union[Literal['utc'], int]
     microseconds_precision: Literal['truncate', 'error']
+    formats: List[str]
+    timestamp_unit: Literal['auto', 's', 'ms', 'us', 'ns']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -959,7 +1056,11 @@ def time_schema(
     lt: time | None = None,
     gt: time | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
+    to_tz: Literal['utc'] | int | None = None,
+    naive_tz: Literal['utc'] | int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    formats: list[str] | None = None,
+    timestamp_unit: Literal['auto', 's', 'ms', 'us', 'ns'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -983,7 +1084,11 @@ def time_schema(
         lt: The value must be strictly less than this time
         gt: The value must be strictly greater than this time
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
+        to_tz: Convert aware times to UTC or to this fixed offset in seconds, wrapping around midnight
+        naive_tz: Interpret naive times as being in UTC or this fixed offset in seconds, applied before `to_tz`
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        formats: strftime-style formats, e.g. `'%H.%M'`, tried in order when the input isn't an ISO 8601 time
+        timestamp_unit: The unit of numeric inputs, overrides the `timestamp_unit` config
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -996,7 +1101,11 @@ def time_schema(
         lt=lt,
         gt=gt,
         tz_constraint=tz_constraint,
+        to_tz=to_tz,
+        naive_tz=naive_tz,
         microseconds_precision=microseconds_precision,
+        formats=formats,
+        timestamp_unit=timestamp_unit,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1012,10 +1121,17 @@ class DatetimeSchema(TypedDict, total=False):
     gt: datetime
     now_op: Literal['past', 'future']
     tz_constraint: Union[Literal['aware', 'naive'], int]
+    to_tz: Union[Literal['utc'], int]
+    naive_tz: Union[Literal['utc'], int]
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
+    now_delta: timedelta
+    now_context_key: str
     microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
+    formats: List[str]
+    timestamp_unit: Literal['auto', 's', 'ms', 'us', 'ns']
+    ser_json_timestamp_unit: Literal['s', 'ms', 'us', 'ns']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1030,8 +1146,15 @@ def datetime_schema(
     gt: datetime | None = None,
     now_op: Literal['past', 'future'] | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
+    to_tz: Literal['utc'] | int | None = None,
+    naive_tz: Literal['utc'] | int | None = None,
     now_utc_offset: int | None = None,
+    now_delta: timedelta | None = None,
+    now_context_key: str | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    formats: list[str] | None = None,
+    timestamp_unit: Literal['auto', 's', 'ms', 'us', 'ns'] | None = None,
+    ser_json_timestamp_unit: Literal['s', 'ms', 'us', 'ns'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1058,8 +1181,19 @@ def datetime_schema(
         now_op: The value must be in the past or future relative to the current datetime
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
             TODO: use of a tzinfo where offset changes based on the datetime is not yet supported
+        to_tz: Convert aware datetimes to UTC or to this fixed offset in seconds
+        naive_tz: Interpret naive datetimes as being in UTC or this fixed offset in seconds, applied before `to_tz`
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
+        now_delta: Move the datetime `now_op` compares against, e.g. `now_op='future'` with `timedelta(hours=1)`
+            means at least an hour in the future
+        now_context_key: Read a frozen current datetime from this key of the validation context rather than
+            using the current time, e.g. for deterministic tests
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        formats: strftime-style formats, e.g. `'%d/%m/%Y %H:%M'`, tried in order when the input isn't an
+            ISO 8601 datetime
+        timestamp_unit: The unit of numeric inputs, overrides the `timestamp_unit` config
+        ser_json_timestamp_unit: Serialize to JSON as an integer timestamp in this unit, overrides the
+            `ser_json_timestamp_unit` config
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1073,8 +1207,15 @@ def datetime_schema(
         gt=gt,
         now_op=now_op,
         tz_constraint=tz_constraint,
+        to_tz=to_tz,
+        naive_tz=naive_tz,
         now_utc_offset=now_utc_offset,
+        now_delta=now_delta,
+        now_context_key=now_context_key,
         microseconds_precision=microseconds_precision,
+        formats=formats,
+        timestamp_unit=timestamp_unit,
+        ser_json_timestamp_unit=ser_json_timestamp_unit,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1089,6 +1230,9 @@ class TimedeltaSchema(TypedDict, total=False):
     lt: timedelta
     gt: timedelta
     microseconds_precision: Literal['truncate', 'error']
+    duration_unit: Literal['ms', 's', 'min']
+    allow_human_durations: bool
+    allow_calendar_units: bool
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1102,6 +1246,9 @@ def timedelta_schema(
     lt: timedelta | None = None,
     gt: timedelta | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    duration_unit: Literal['ms', 's', 'min'] | None = None,
+    allow_human_durations: bool | None = None,
+    allow_calendar_units: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1125,6 +1272,9 @@ def timedelta_schema(
         lt: The value must be strictly less than this timedelta
         gt: The value must be strictly greater than this timedelta
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        duration_unit: The unit of numeric inputs, overrides the `duration_unit` config
+        allow_human_durations: Whether to accept strings like `'1h30m'`, `'2 days'` or `'1.5 hours'`
+        allow_calendar_units: Whether to accept months and years, e.g. `'P1M'` or `'P1Y'`, which have no fixed length
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1137,12 +1287,51 @@ def timedelta_schema(
         lt=lt,
         gt=gt,
         microseconds_precision=microseconds_precision,
+        duration_unit=duration_unit,
+        allow_human_durations=allow_human_durations,
+        allow_calendar_units=allow_calendar_units,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
     )
 
 
+class TimezoneSchema(TypedDict, total=False):
+    type: Required[Literal['timezone']]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def timezone_schema(
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> TimezoneSchema:
+    """
+    Returns a schema that matches an IANA timezone name and returns a `zoneinfo.ZoneInfo`, e.g.:
+
+    ```py
+    from zoneinfo import ZoneInfo
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.timezone_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('America/New_York') == ZoneInfo('America/New_York')
+    ```
+
+    Args:
+        strict: Whether the value should be a `ZoneInfo` instance or a timezone name which can be converted to one
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='timezone', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
 class LiteralSchema(TypedDict, total=False):
     type: Required[Literal['literal']]
     expected: Required[List[Any]]
@@ -1174,6 +1363,69 @@ def literal_schema(
     return _dict_not_none(type='literal', expected=expected, ref=ref, metadata=metadata, serialization=serialization)
 
 
+class EnumSchema(TypedDict, total=False):
+    type: Required[Literal['enum']]
+    cls: Required[Any]
+    members: Required[List[Any]]
+    sub_type: Literal['str', 'int', 'float']
+    missing: Callable[[Any], Any]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def enum_schema(
+    cls: Any,
+    members: list[Any],
+    *,
+    sub_type: Literal['str', 'int', 'float'] | None = None,
+    missing: Callable[[Any], Any] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> EnumSchema:
+    """
+    Returns a schema that matches an enum value, e.g.:
+
+    ```py
+    from enum import Enum
+    from pydantic_core import SchemaValidator, core_schema
+
+    class Color(Enum):
+        RED = 1
+        GREEN = 2
+        BLUE = 3
+
+    schema = core_schema.enum_schema(Color, list(Color.__members__.values()))
+    v = SchemaValidator(schema)
+    assert v.validate_python(2) is Color.GREEN
+    ```
+
+    Args:
+        cls: The enum class
+        members: The members of the enum, generally `list(MyEnum.__members__.values())`
+        sub_type: The type of the enum, either 'str', 'int' or 'float', or None for plain enums
+        missing: A function to use when the value is not found in the enum, from `_missing_`
+        strict: Whether to use strict mode, defaults to False
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='enum',
+        cls=cls,
+        members=members,
+        sub_type=sub_type,
+        missing=missing,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
 # must match input/parse_json.rs::JsonType::try_from
 JsonType = Literal['null', 'bool', 'int', 'float', 'str', 'list', 'dict']
 
@@ -1337,6 +1589,7 @@ class ListSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    fail_fast: bool
     ref: str
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
@@ -1348,6 +1601,7 @@ def list_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1368,6 +1622,7 @@ def list_schema(
         min_length: The value must be a list with at least this many items
         max_length: The value must be a list with at most this many items
         strict: The value must be a list with exactly this many items
+        fail_fast: Stop validating the list at the first error instead of collecting all errors
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1378,31 +1633,88 @@ def list_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        fail_fast=fail_fast,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
     )
 
 
-class TuplePositionalSchema(TypedDict, total=False):
-    type: Required[Literal['tuple-positional']]
+class TupleSchema(TypedDict, total=False):
+    type: Required[Literal['tuple']]
     items_schema: Required[List[CoreSchema]]
-    extras_schema: CoreSchema
+    variadic_item_index: int
+    min_length: int
+    max_length: int
+    fail_fast: bool
     strict: bool
     ref: str
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
 
 
+def tuple_schema(
+    items_schema: list[CoreSchema],
+    *,
+    variadic_item_index: int | None = None,
+    min_length: int | None = None,
+    max_length: int | None = None,
+    fail_fast: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: IncExSeqOrElseSerSchema | None = None,
+) -> TupleSchema:
+    """
+    Returns a schema that matches a tuple of schemas, with an optional variadic item, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.tuple_schema(
+        [core_schema.int_schema(), core_schema.str_schema(), core_schema.float_schema()],
+        variadic_item_index=1,
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python((1, 'hello', 'world', 1.5)) == (1, 'hello', 'world', 1.5)
+    ```
+
+    Args:
+        items_schema: The value must be a tuple with items that match these schemas
+        variadic_item_index: The index of the schema in `items_schema` to be treated as variadic (following PEP 646),
+            any number of items (including none) matching this schema can appear at this position
+        min_length: The value must be a tuple with at least this many items
+        max_length: The value must be a tuple with at most this many items
+        fail_fast: Stop validating the tuple at the first error instead of collecting all errors
+        strict: The value must be a tuple with exactly this many items
+        ref: Optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='tuple',
+        items_schema=items_schema,
+        variadic_item_index=variadic_item_index,
+        min_length=min_length,
+        max_length=max_length,
+        fail_fast=fail_fast,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
 def tuple_positional_schema(
     items_schema: list[CoreSchema],
     *,
     extras_schema: CoreSchema | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
-) -> TuplePositionalSchema:
+) -> TupleSchema:
     """
     Returns a schema that matches a tuple of schemas, e.g.:
 
@@ -1416,6 +1728,8 @@ def tuple_positional_schema(
     assert v.validate_python((1, 'hello')) == (1, 'hello')
     ```
 
+    This is equivalent to `tuple_schema` with `extras_schema` as the variadic last item, prefer `tuple_schema`.
+
     Args:
         items_schema: The value must be a tuple with items that match these schemas
         extras_schema: The value must be a tuple with items that match this schema
@@ -1423,42 +1737,38 @@ def tuple_positional_schema(
             In python's `typing.Tuple`, you can't specify a type for "extra" items -- they must all be the same type
             if the length is variable. So this field won't be set from a `typing.Tuple` annotation on a pydantic model.
         strict: The value must be a tuple with exactly this many items
+        fail_fast: Stop validating the tuple at the first error instead of collecting all errors
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(
-        type='tuple-positional',
+    if extras_schema is not None:
+        variadic_item_index = len(items_schema)
+        items_schema = items_schema + [extras_schema]
+    else:
+        variadic_item_index = None
+    return tuple_schema(
         items_schema=items_schema,
-        extras_schema=extras_schema,
+        variadic_item_index=variadic_item_index,
         strict=strict,
+        fail_fast=fail_fast,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
     )
 
 
-class TupleVariableSchema(TypedDict, total=False):
-    type: Required[Literal['tuple-variable']]
-    items_schema: CoreSchema
-    min_length: int
-    max_length: int
-    strict: bool
-    ref: str
-    metadata: Any
-    serialization: IncExSeqOrElseSerSchema
-
-
 def tuple_variable_schema(
     items_schema: CoreSchema | None = None,
     *,
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
-) -> TupleVariableSchema:
+) -> TupleSchema:
     """
     Returns a schema that matches a tuple of a given schema, e.g.:
 
@@ -1472,21 +1782,25 @@ def tuple_variable_schema(
     assert v.validate_python(('1', 2, 3)) == (1, 2, 3)
     ```
 
+    This is equivalent to `tuple_schema` with a single variadic item, prefer `tuple_schema`.
+
     Args:
         items_schema: The value must be a tuple with items that match this schema
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
         strict: The value must be a tuple with exactly this many items
-        ref: optional unique identifier of the schema, used to reference the schema in other places
+        fail_fast: Stop validating the tuple at the first error instead of collecting all errors
+        ref: Optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(
-        type='tuple-variable',
-        items_schema=items_schema,
+    return tuple_schema(
+        items_schema=[items_schema or any_schema()],
+        variadic_item_index=0,
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        fail_fast=fail_fast,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1499,6 +1813,7 @@ class SetSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    fail_fast: bool
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1510,6 +1825,7 @@ def set_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1532,6 +1848,7 @@ def set_schema(
         min_length: The value must be a set with at least this many items
         max_length: The value must be a set with at most this many items
         strict: The value must be a set with exactly this many items
+        fail_fast: Stop validating the set at the first error instead of collecting all errors
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1542,6 +1859,7 @@ def set_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        fail_fast=fail_fast,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1554,6 +1872,7 @@ class FrozenSetSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    fail_fast: bool
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1565,6 +1884,7 @@ def frozenset_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1587,6 +1907,7 @@ def frozenset_schema(
         min_length: The value must be a frozenset with at least this many items
         max_length: The value must be a frozenset with at most this many items
         strict: The value must be a frozenset with exactly this many items
+        fail_fast: Stop validating the frozenset at the first error instead of collecting all errors
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1597,6 +1918,7 @@ def frozenset_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        fail_fast=fail_fast,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1683,6 +2005,7 @@ class DictSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    fail_fast: bool
     ref: str
     metadata: Any
     serialization: IncExDictOrElseSerSchema
@@ -1695,6 +2018,7 @@ def dict_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    fail_fast: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1718,6 +2042,7 @@ def dict_schema(
         min_length: The value must be a dict with at least this many items
         max_length: The value must be a dict with at most this many items
         strict: Whether the keys and values should be validated with strict mode
+        fail_fast: Stop validating the dict at the first error instead of collecting all errors
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1729,6 +2054,7 @@ def dict_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        fail_fast=fail_fast,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2599,6 +2925,11 @@ def json_or_python_schema(
     )
 
 
+# predicate deciding whether a field value is omitted when serializing: a callable taking the value, or one of
+# the built-in checks for `None`, falsy values, values equal to the field default and empty collections
+ExcludeIf = Union[Callable[[Any], bool], Literal['none', 'falsy', 'default', 'empty']]
+
+
 class TypedDictField(TypedDict, total=False):
     type: Required[Literal['typed-dict-field']]
     schema: Required[CoreSchema]
@@ -2606,6 +2937,7 @@ class TypedDictField(TypedDict, total=False):
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_exclude_if: ExcludeIf
     metadata: Any
 
 
@@ -2616,6 +2948,7 @@ def typed_dict_field(
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_exclude_if: ExcludeIf | None = None,
     metadata: Any = None,
 ) -> TypedDictField:
     """
@@ -2633,6 +2966,8 @@ def typed_dict_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_exclude_if: Exclude the field when serializing if this predicate holds for its value,
+            either a callable or one of `'none'`, `'falsy'`, `'default'` or `'empty'`
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -2642,6 +2977,7 @@ def typed_dict_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_exclude_if=serialization_exclude_if,
         metadata=metadata,
     )
 
@@ -2723,6 +3059,7 @@ class ModelField(TypedDict, total=False):
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_exclude_if: ExcludeIf
     frozen: bool
     metadata: Any
 
@@ -2733,6 +3070,7 @@ def model_field(
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_exclude_if: ExcludeIf | None = None,
     frozen: bool | None = None,
     metadata: Any = None,
 ) -> ModelField:
@@ -2750,6 +3088,8 @@ def model_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_exclude_if: Exclude the field when serializing if this predicate holds for its value,
+            either a callable or one of `'none'`, `'falsy'`, `'default'` or `'empty'`
         frozen: Whether the field is frozen
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
@@ -2759,6 +3099,7 @@ def model_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_exclude_if=serialization_exclude_if,
         frozen=frozen,
         metadata=metadata,
     )
@@ -2848,6 +3189,7 @@ class ModelSchema(TypedDict, total=False):
     strict: bool
     frozen: bool
     extra_behavior: ExtraBehavior
+    serialize_as_any: bool  # default: False
     config: CoreConfig
     ref: str
     metadata: Any
@@ -2865,6 +3207,7 @@ def model_schema(
     strict: bool | None = None,
     frozen: bool | None = None,
     extra_behavior: ExtraBehavior | None = None,
+    serialize_as_any: bool | None = None,
     config: CoreConfig | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -2912,6 +3255,8 @@ def model_schema(
         strict: Whether the model is strict
         frozen: Whether the model is frozen
         extra_behavior: The extra behavior to use for the model, used in serialization
+        serialize_as_any: Whether subclass instances are serialized with their own `__pydantic_serializer__`,
+            defaults to config.serialize_as_any, else False
         config: The config to use for the model
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -2928,6 +3273,7 @@ def model_schema(
         strict=strict,
         frozen=frozen,
         extra_behavior=extra_behavior,
+        serialize_as_any=serialize_as_any,
         config=config,
         ref=ref,
         metadata=metadata,
@@ -2945,6 +3291,7 @@ class DataclassField(TypedDict, total=False):
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
     serialization_alias: str
     serialization_exclude: bool  # default: False
+    serialization_exclude_if: ExcludeIf
     metadata: Any
 
 
@@ -2957,6 +3304,7 @@ def dataclass_field(
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
     serialization_alias: str | None = None,
     serialization_exclude: bool | None = None,
+    serialization_exclude_if: ExcludeIf | None = None,
     metadata: Any = None,
     frozen: bool | None = None,
 ) -> DataclassField:
@@ -2982,6 +3330,8 @@ def dataclass_field(
         validation_alias: The alias(es) to use to find the field in the validation data
         serialization_alias: The alias to use as a key when serializing
         serialization_exclude: Whether to exclude the field when serializing
+        serialization_exclude_if: Exclude the field when serializing if this predicate holds for its value,
+            either a callable or one of `'none'`, `'falsy'`, `'default'` or `'empty'`
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         frozen: Whether the field is frozen
     """
@@ -2994,6 +3344,7 @@ def dataclass_field(
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
         serialization_exclude=serialization_exclude,
+        serialization_exclude_if=serialization_exclude_if,
         metadata=metadata,
         frozen=frozen,
     )
@@ -3080,6 +3431,7 @@ class DataclassSchema(TypedDict, total=False):
     metadata: Any
     serialization: SerSchema
     slots: bool
+    serialize_as_any: bool  # default: False
     config: CoreConfig
 
 
@@ -3097,6 +3449,7 @@ def dataclass_schema(
     serialization: SerSchema | None = None,
     frozen: bool | None = None,
     slots: bool | None = None,
+    serialize_as_any: bool | None = None,
     config: CoreConfig | None = None,
 ) -> DataclassSchema:
     """
@@ -3119,6 +3472,8 @@ def dataclass_schema(
         frozen: Whether the dataclass is frozen
         slots: Whether `slots=True` on the dataclass, means each field is assigned independently, rather than
             simply setting `__dict__`, default false
+        serialize_as_any: Whether subclass instances are serialized with their own `__pydantic_serializer__`,
+            defaults to config.serialize_as_any, else False
     """
     return _dict_not_none(
         type='dataclass',
@@ -3134,6 +3489,7 @@ def dataclass_schema(
         serialization=serialization,
         frozen=frozen,
         slots=slots,
+        serialize_as_any=serialize_as_any,
         config=config,
     )
 
@@ -3620,19 +3976,21 @@ if not MYPY:
         IntSchema,
         FloatSchema,
         DecimalSchema,
+        ComplexSchema,
         StringSchema,
         BytesSchema,
         DateSchema,
         TimeSchema,
         DatetimeSchema,
         TimedeltaSchema,
+        TimezoneSchema,
         LiteralSchema,
+        EnumSchema,
         IsInstanceSchema,
         IsSubclassSchema,
         CallableSchema,
         ListSchema,
-        TuplePositionalSchema,
-        TupleVariableSchema,
+        TupleSchema,
         SetSchema,
         FrozenSetSchema,
         GeneratorSchema,
@@ -3675,19 +4033,21 @@ CoreSchemaType = Literal[
     'int',
     'float',
     'decimal',
+    'complex',
     'str',
     'bytes',
     'date',
     'time',
     'datetime',
     'timedelta',
+    'timezone',
     'literal',
+    'enum',
     'is-instance',
     'is-subclass',
     'callable',
     'list',
-    'tuple-positional',
-    'tuple-variable',
+    'tuple',
     'set',
     'frozenset',
     'generator',
@@ -3773,6 +4133,7 @@ ErrorType = Literal[
     'bytes_type',
     'bytes_too_short',
     'bytes_too_long',
+    'bytes_invalid_encoding',
     'value_error',
     'assertion_error',
     'literal_error',
@@ -3782,6 +4143,8 @@ ErrorType = Literal[
     'date_from_datetime_inexact',
     'date_past',
     'date_future',
+    'date_past_bound',
+    'date_future_bound',
     'time_type',
     'time_parsing',
     'datetime_type',
@@ -3789,9 +4152,13 @@ ErrorType = Literal[
     'datetime_object_invalid',
     'datetime_past',
     'datetime_future',
+    'datetime_past_bound',
+    'datetime_future_bound',
     'timezone_naive',
     'timezone_aware',
     'timezone_offset',
+    'timezone_type',
+    'timezone_name',
     'time_delta_type',
     'time_delta_parsing',
     'frozen_set_type',
@@ -3820,6 +4187,8 @@ ErrorType = Literal[
     'decimal_max_digits',
     'decimal_max_places',
     'decimal_whole_digits',
+    'complex_type',
+    'complex_str_parsing',
 ]
 
 
@@ -0,0 +1,124 @@
+use jiter::{Jiter, JsonValue, Peak};
+
+use super::location::{LocItem, Location};
+use super::types::ErrorType;
+
+/// Where in the JSON input the value which caused an error starts.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonPosition {
+    /// byte offset from the start of the input, starting at 0
+    pub offset: usize,
+    /// line number, starting at 1
+    pub line: usize,
+    /// column number in bytes, starting at 1
+    pub column: usize,
+    /// [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901) to the value in the input, e.g. `/foo/0/bar`
+    pub pointer: String,
+}
+
+impl JsonPosition {
+    fn from_offset(json_data: &[u8], offset: usize) -> Self {
+        let offset = offset.min(json_data.len());
+        let before = &json_data[..offset];
+        let line_start = before.iter().rposition(|b| *b == b'\n').map_or(0, |index| index + 1);
+        Self {
+            offset,
+            line: before.split(|b| *b == b'\n').count(),
+            column: offset - line_start + 1,
+            pointer: String::new(),
+        }
+    }
+
+    /// Find the position of the value an error refers to by walking `json_data` along the path of keys and
+    /// indexes the error's value was found at in the input, see `ValLineError::input_location`.
+    ///
+    /// Items which don't match the JSON, e.g. missing fields, are skipped, so the position is that of the
+    /// closest value which does exist.
+    pub fn locate(json_data: &[u8], error_type: &ErrorType, location: &Location) -> Option<Self> {
+        let loc_items: &[LocItem] = match location {
+            Location::List(loc) => loc,
+            // invalid JSON is reported for the whole input, use the position the parser gave up at
+            Location::Empty if matches!(error_type, ErrorType::JsonInvalid { .. }) => {
+                let error = JsonValue::parse(json_data, true).err()?;
+                return Some(Self::from_offset(json_data, error.index));
+            }
+            Location::Empty => &[],
+        };
+
+        let mut jiter = Jiter::new(json_data, true);
+        let mut peak = jiter.peak().ok()?;
+        // location is stored in reverse, so iterate from the outermost item
+        for loc_item in loc_items.iter().rev() {
+            let found = match (loc_item, &peak) {
+                (LocItem::S(key), Peak::Object) => find_key(&jiter, key),
+                (LocItem::I(index), Peak::Array) => usize::try_from(*index).ok().and_then(|i| find_index(&jiter, i)),
+                _ => None,
+            };
+            if let Some((value_jiter, value_peak)) = found {
+                jiter = value_jiter;
+                peak = value_peak;
+            }
+        }
+
+        let line_position = jiter.current_position();
+        let line_start = if line_position.line == 1 {
+            0
+        } else {
+            json_data
+                .iter()
+                .enumerate()
+                .filter(|(_, b)| **b == b'\n')
+                .nth(line_position.line - 2)
+                .map(|(index, _)| index + 1)?
+        };
+        Some(Self {
+            offset: line_start + line_position.column - 1,
+            line: line_position.line,
+            column: line_position.column,
+            pointer: json_pointer(location),
+        })
+    }
+}
+
+/// Find the value of `key` in the object `jiter` is positioned at, if the key is repeated the last
+/// value is used, matching how the object is validated.
+fn find_key<'j>(jiter: &Jiter<'j>, key: &str) -> Option<(Jiter<'j>, Peak)> {
+    let mut scan = jiter.clone();
+    let mut found = None;
+    let mut key_matches = scan.known_object().ok()?.map(|k| k == key);
+    while let Some(matches) = key_matches {
+        let value_peak = scan.peak().ok()?;
+        if matches {
+            found = Some((scan.clone(), value_peak.clone()));
+        }
+        scan.known_value(value_peak).ok()?;
+        key_matches = scan.next_key().ok()?.map(|k| k == key);
+    }
+    found
+}
+
+/// Find the item at `index` in the array `jiter` is positioned at.
+fn find_index<'j>(jiter: &Jiter<'j>, index: usize) -> Option<(Jiter<'j>, Peak)> {
+    let mut scan = jiter.clone();
+    let mut item_peak = scan.known_array().ok()??;
+    for _ in 0..index {
+        scan.known_value(item_peak).ok()?;
+        item_peak = scan.array_step().ok()??;
+    }
+    Some((scan, item_peak))
+}
+
+/// Render a location as a [JSON Pointer](https://datatracker.ietf.org/doc/html/rfc6901), e.g. `/foo/0/bar`.
+pub fn json_pointer(location: &Location) -> String {
+    let mut pointer = String::new();
+    if let Location::List(loc) = location {
+        for loc_item in loc.iter().rev() {
+            pointer.push('/');
+            match loc_item {
+                LocItem::S(s) => pointer.push_str(&s.replace('~', "~0").replace('/', "~1")),
+                LocItem::I(i) => pointer.push_str(&i.to_string()),
+            }
+        }
+    }
+    pointer
+}
@@ -63,12 +63,25 @@ impl ValError {
     /// helper function to call with_outer on line items if applicable
     pub fn with_outer_location(self, loc_item: LocItem) -> Self {
         match self {
-            Self::LineErrors(mut line_errors) => {
-                for line_error in &mut line_errors {
-                    line_error.location.with_outer(loc_item.clone());
-                }
-                Self::LineErrors(line_errors)
-            }
+            Self::LineErrors(line_errors) => Self::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|line_error| line_error.with_outer_location(loc_item.clone()))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
+
+    /// helper function to call with_outer_label on line items if applicable
+    pub fn with_outer_label(self, loc_item: LocItem) -> Self {
+        match self {
+            Self::LineErrors(line_errors) => Self::LineErrors(
+                line_errors
+                    .into_iter()
+                    .map(|line_error| line_error.with_outer_label(loc_item.clone()))
+                    .collect(),
+            ),
             other => other,
         }
     }
@@ -82,6 +95,11 @@ pub struct ValLineError {
     pub error_type: ErrorType,
     // location is reversed so that adding an "outer" location item is pushing, it's reversed before showing to the user
     pub location: Location,
+    // location using field names rather than aliases, only set where an alias was used in `location`
+    pub field_location: Option<Location>,
+    // location of the value in the input, only set where it differs from `location`, e.g. fields located by name
+    // rather than alias, or labels like union member names which aren't keys in the input
+    pub input_location: Option<Location>,
     pub input_value: InputValue,
 }
 
@@ -91,6 +109,8 @@ impl ValLineError {
             error_type,
             input_value: input.as_error_value(),
             location: Location::default(),
+            field_location: None,
+            input_location: None,
         }
     }
 
@@ -99,6 +119,8 @@ impl ValLineError {
             error_type,
             input_value: input.as_error_value(),
             location: Location::new_some(loc.into()),
+            field_location: None,
+            input_location: None,
         }
     }
 
@@ -107,6 +129,8 @@ impl ValLineError {
             error_type,
             input_value: input.as_error_value(),
             location,
+            field_location: None,
+            input_location: None,
         }
     }
 
@@ -115,16 +139,46 @@ impl ValLineError {
             error_type,
             input_value,
             location: Location::default(),
+            field_location: None,
+            input_location: None,
         }
     }
 
     /// location is stored reversed so it's quicker to add "outer" items as that's what we always do
     /// hence `push` here instead of `insert`
     pub fn with_outer_location(mut self, loc_item: LocItem) -> Self {
+        if let Some(ref mut field_location) = self.field_location {
+            field_location.with_outer(loc_item.clone());
+        }
+        if let Some(ref mut input_location) = self.input_location {
+            input_location.with_outer(loc_item.clone());
+        }
+        self.location.with_outer(loc_item);
+        self
+    }
+
+    /// like `with_outer_location`, but for items which don't correspond to a key or index in the input,
+    /// e.g. union member names, so they're left out of `input_location`
+    pub fn with_outer_label(mut self, loc_item: LocItem) -> Self {
+        if self.input_location.is_none() {
+            self.input_location = Some(self.location.clone());
+        }
+        if let Some(ref mut field_location) = self.field_location {
+            field_location.with_outer(loc_item.clone());
+        }
         self.location.with_outer(loc_item);
         self
     }
 
+    /// like `with_outer_location`, but only adds to `input_location`, used where `location` refers to a field by
+    /// name while the input was looked up by alias
+    pub fn with_outer_input_location(mut self, loc_item: LocItem) -> Self {
+        self.input_location
+            .get_or_insert_with(|| self.location.clone())
+            .with_outer(loc_item);
+        self
+    }
+
     // change the error_type on a error in place
     pub fn with_type(mut self, error_type: ErrorType) -> Self {
         self.error_type = error_type;
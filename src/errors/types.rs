@@ -292,6 +292,10 @@ error_types! {
     BytesTooLong {
         max_length: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    BytesInvalidEncoding {
+        encoding: {ctx_type: String, ctx_fn: field_from_context},
+        encoding_error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // python errors from functions
     ValueError {
@@ -323,6 +327,12 @@ error_types! {
     DateFromDatetimeInexact {},
     DatePast {},
     DateFuture {},
+    DatePastBound {
+        bound: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    DateFutureBound {
+        bound: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // date errors
     TimeType {},
@@ -340,6 +350,12 @@ error_types! {
     },
     DatetimePast {},
     DatetimeFuture {},
+    DatetimePastBound {
+        bound: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    DatetimeFutureBound {
+        bound: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // timezone errors
     TimezoneNaive {},
@@ -348,6 +364,8 @@ error_types! {
         tz_expected: {ctx_type: i32, ctx_fn: field_from_context},
         tz_actual: {ctx_type: i32, ctx_fn: field_from_context},
     },
+    TimezoneType {},
+    TimezoneName {},
     // ---------------------
     // timedelta errors
     TimeDeltaType {},
@@ -421,6 +439,9 @@ error_types! {
     DecimalWholeDigits {
         whole_digits: {ctx_type: u64, ctx_fn: field_from_context},
     },
+    // Complex errors
+    ComplexType {},
+    ComplexStrParsing {},
 }
 
 macro_rules! render {
@@ -514,6 +535,7 @@ impl ErrorType {
             Self::BytesType {..} => "Input should be a valid bytes",
             Self::BytesTooShort {..} => "Data should have at least {min_length} byte{expected_plural}",
             Self::BytesTooLong {..} => "Data should have at most {max_length} byte{expected_plural}",
+            Self::BytesInvalidEncoding {..} => "Data should be valid {encoding}: {encoding_error}",
             Self::ValueError {..} => "Value error, {error}",
             Self::AssertionError {..} => "Assertion failed, {error}",
             Self::CustomError {..} => "",  // custom errors are handled separately
@@ -524,6 +546,8 @@ impl ErrorType {
             Self::DateFromDatetimeInexact {..} => "Datetimes provided to dates should have zero time - e.g. be exact dates",
             Self::DatePast {..} => "Date should be in the past",
             Self::DateFuture {..} => "Date should be in the future",
+            Self::DatePastBound {..} => "Date should be before {bound}",
+            Self::DateFutureBound {..} => "Date should be after {bound}",
             Self::TimeType {..} => "Input should be a valid time",
             Self::TimeParsing {..} => "Input should be in a valid time format, {error}",
             Self::DatetimeType {..} => "Input should be a valid datetime",
@@ -531,9 +555,13 @@ impl ErrorType {
             Self::DatetimeObjectInvalid {..} => "Invalid datetime object, got {error}",
             Self::DatetimePast {..} => "Input should be in the past",
             Self::DatetimeFuture {..} => "Input should be in the future",
+            Self::DatetimePastBound {..} => "Input should be before {bound}",
+            Self::DatetimeFutureBound {..} => "Input should be after {bound}",
             Self::TimezoneNaive {..} => "Input should not have timezone info",
             Self::TimezoneAware {..} => "Input should have timezone info",
             Self::TimezoneOffset {..} => "Timezone offset of {tz_expected} required, got {tz_actual}",
+            Self::TimezoneType {..} => "Input should be a string or ZoneInfo object",
+            Self::TimezoneName {..} => "Input should be a valid IANA timezone name",
             Self::TimeDeltaType {..} => "Input should be a valid timedelta",
             Self::TimeDeltaParsing {..} => "Input should be a valid timedelta, {error}",
             Self::FrozenSetType {..} => "Input should be a valid frozenset",
@@ -562,6 +590,8 @@ impl ErrorType {
             Self::DecimalMaxDigits {..} => "Decimal input should have no more than {max_digits} digit{expected_plural} in total",
             Self::DecimalMaxPlaces {..} => "Decimal input should have no more than {decimal_places} decimal place{expected_plural}",
             Self::DecimalWholeDigits {..} => "Decimal input should have no more than {whole_digits} digit{expected_plural} before the decimal point",
+            Self::ComplexType {..} => "Input should be a valid python complex object, a number, or a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex",
+            Self::ComplexStrParsing {..} => "Input should be a valid complex string following the rules at https://docs.python.org/3/library/functions.html#complex",
         }
     }
 
@@ -580,6 +610,9 @@ impl ErrorType {
             Self::TimeDeltaType { .. } => "Input should be a valid duration",
             Self::TimeDeltaParsing { .. } => "Input should be a valid duration, {error}",
             Self::ArgumentsType { .. } => "Arguments must be an array or an object",
+            Self::ComplexType { .. } => {
+                "Input should be a valid complex string, an array of [real, imag] or an object with \"real\" and \"imag\" keys"
+            }
             _ => self.message_template_python(),
         }
     }
@@ -662,6 +695,11 @@ impl ErrorType {
                 let expected_plural = plural_s(*max_length);
                 to_string_render!(tmpl, max_length, expected_plural)
             }
+            Self::BytesInvalidEncoding {
+                encoding,
+                encoding_error,
+                ..
+            } => render!(tmpl, encoding, encoding_error),
             Self::ValueError { error, .. } => {
                 let error = &error
                     .as_ref()
@@ -685,6 +723,10 @@ impl ErrorType {
             Self::TimeParsing { error, .. } => render!(tmpl, error),
             Self::DatetimeParsing { error, .. } => render!(tmpl, error),
             Self::DatetimeObjectInvalid { error, .. } => render!(tmpl, error),
+            Self::DatePastBound { bound, .. } => render!(tmpl, bound),
+            Self::DateFutureBound { bound, .. } => render!(tmpl, bound),
+            Self::DatetimePastBound { bound, .. } => render!(tmpl, bound),
+            Self::DatetimeFutureBound { bound, .. } => render!(tmpl, bound),
             Self::TimezoneOffset {
                 tz_expected, tz_actual, ..
             } => to_string_render!(tmpl, tz_expected, tz_actual),
@@ -20,6 +20,7 @@ use crate::input::InputType;
 use crate::serializers::{SerMode, SerializationState};
 use crate::tools::{safe_repr, SchemaDict};
 
+use super::json_position::{json_pointer, JsonPosition};
 use super::line_error::ValLineError;
 use super::location::Location;
 use super::types::ErrorType;
@@ -64,19 +65,7 @@ impl ValidationError {
                         .collect(),
                     None => raw_errors.into_iter().map(|e| e.into_py(py)).collect(),
                 };
-                let validation_error = Self::new(line_errors, title, input_type, hide_input);
-                match Py::new(py, validation_error) {
-                    Ok(err) => {
-                        if validation_error_cause {
-                            // Will return an import error if the backport was needed and not installed:
-                            if let Some(cause_problem) = ValidationError::maybe_add_cause(err.borrow(py), py) {
-                                return cause_problem;
-                            }
-                        }
-                        PyErr::from_value(err.as_ref(py))
-                    }
-                    Err(err) => err,
-                }
+                Self::from_line_errors(py, line_errors, title, input_type, hide_input, validation_error_cause)
             }
             ValError::InternalErr(err) => err,
             ValError::Omit => Self::omit_error(),
@@ -84,6 +73,71 @@ impl ValidationError {
         }
     }
 
+    /// Like `from_val_error`, but records where in `json_data` each error occurred.
+    pub fn from_json_val_error(
+        py: Python,
+        title: PyObject,
+        error: ValError,
+        json_data: &[u8],
+        hide_input: bool,
+        validation_error_cause: bool,
+    ) -> PyErr {
+        match error {
+            ValError::LineErrors(raw_errors) => {
+                let line_errors: Vec<PyLineError> = raw_errors
+                    .into_iter()
+                    .map(|e| {
+                        let input_location = e.input_location.as_ref().unwrap_or(&e.location);
+                        let position = JsonPosition::locate(json_data, &e.error_type, input_location);
+                        let mut line_error: PyLineError = e.into_py(py);
+                        line_error.position = position;
+                        line_error
+                    })
+                    .collect();
+                Self::from_line_errors(
+                    py,
+                    line_errors,
+                    title,
+                    InputType::Json,
+                    hide_input,
+                    validation_error_cause,
+                )
+            }
+            error => Self::from_val_error(
+                py,
+                title,
+                InputType::Json,
+                error,
+                None,
+                hide_input,
+                validation_error_cause,
+            ),
+        }
+    }
+
+    fn from_line_errors(
+        py: Python,
+        line_errors: Vec<PyLineError>,
+        title: PyObject,
+        input_type: InputType,
+        hide_input: bool,
+        validation_error_cause: bool,
+    ) -> PyErr {
+        let validation_error = Self::new(line_errors, title, input_type, hide_input);
+        match Py::new(py, validation_error) {
+            Ok(err) => {
+                if validation_error_cause {
+                    // Will return an import error if the backport was needed and not installed:
+                    if let Some(cause_problem) = ValidationError::maybe_add_cause(err.borrow(py), py) {
+                        return cause_problem;
+                    }
+                }
+                PyErr::from_value(err.as_ref(py))
+            }
+            Err(err) => err,
+        }
+    }
+
     pub fn display(&self, py: Python, prefix_override: Option<&'static str>, hide_input: bool) -> String {
         let url_prefix = get_url_prefix(py, include_url_env(py));
         let line_errors = pretty_py_line_errors(py, self.input_type, self.line_errors.iter(), url_prefix, hide_input);
@@ -261,13 +315,14 @@ impl ValidationError {
         self.line_errors.len()
     }
 
-    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true, include_field_loc = false))]
     pub fn errors(
         &self,
         py: Python,
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        include_field_loc: bool,
     ) -> PyResult<Py<PyList>> {
         let url_prefix = get_url_prefix(py, include_url);
         let mut iteration_error = None;
@@ -280,11 +335,18 @@ impl ValidationError {
                 if iteration_error.is_some() {
                     return py.None();
                 }
-                e.as_dict(py, url_prefix, include_context, self.input_type, include_input)
-                    .unwrap_or_else(|err| {
-                        iteration_error = Some(err);
-                        py.None()
-                    })
+                e.as_dict(
+                    py,
+                    url_prefix,
+                    include_context,
+                    self.input_type,
+                    include_input,
+                    include_field_loc,
+                )
+                .unwrap_or_else(|err| {
+                    iteration_error = Some(err);
+                    py.None()
+                })
             }),
         );
         if let Some(err) = iteration_error {
@@ -294,7 +356,7 @@ impl ValidationError {
         }
     }
 
-    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true, include_field_loc = false))]
     pub fn json<'py>(
         &self,
         py: Python<'py>,
@@ -302,15 +364,17 @@ impl ValidationError {
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        include_field_loc: bool,
     ) -> PyResult<&'py PyString> {
-        let state = SerializationState::new("iso8601", "utf8")?;
-        let extra = state.extra(py, &SerMode::Json, true, false, false, true, None);
+        let state = SerializationState::new("iso8601", "utf8", "iso8601", None)?;
+        let extra = state.extra(py, &SerMode::Json, true, false, false, true, None, false, false, None);
         let serializer = ValidationErrorSerializer {
             py,
             line_errors: &self.line_errors,
             url_prefix: get_url_prefix(py, include_url),
             include_context,
             include_input,
+            include_field_loc,
             extra: &extra,
             input_type: &self.input_type,
         };
@@ -348,7 +412,7 @@ impl ValidationError {
         let borrow = slf.try_borrow()?;
         let args = (
             borrow.title.as_ref(py),
-            borrow.errors(py, include_url_env(py), true, true)?,
+            borrow.errors(py, include_url_env(py), true, true, true)?,
             borrow.input_type.into_py(py),
             borrow.hide_input,
         )
@@ -423,7 +487,14 @@ pub fn pretty_py_line_errors<'a>(
 pub struct PyLineError {
     error_type: ErrorType,
     location: Location,
+    /// location using field names rather than aliases, if it differs from `location`
+    field_location: Option<Location>,
+    /// location of the value in the input, if it differs from `location`, kept so `position` can be found
+    /// for errors re-raised by wrap validators
+    input_location: Option<Location>,
     input_value: PyObject,
+    /// where the error occurred in the JSON input, only set by `validate_json(..., error_positions=True)`
+    position: Option<JsonPosition>,
 }
 
 impl IntoPy<PyLineError> for ValLineError {
@@ -431,7 +502,10 @@ impl IntoPy<PyLineError> for ValLineError {
         PyLineError {
             error_type: self.error_type,
             location: self.location,
+            field_location: self.field_location,
+            input_location: self.input_location,
             input_value: self.input_value.to_object(py),
+            position: None,
         }
     }
 }
@@ -442,6 +516,8 @@ impl From<PyLineError> for ValLineError {
         ValLineError {
             error_type: other.error_type,
             location: other.location,
+            field_location: other.field_location,
+            input_location: other.input_location,
             input_value: InputValue::Python(other.input_value),
         }
     }
@@ -470,21 +546,48 @@ impl TryFrom<&PyAny> for PyLineError {
         };
 
         let location = Location::try_from(dict.get_item("loc")?)?;
+        let field_location = match dict.get_item(intern!(py, "field_loc"))? {
+            Some(field_loc) => Some(Location::try_from(Some(field_loc))?),
+            None => None,
+        };
 
         let input_value = match dict.get_item("input")? {
             Some(i) => i.into_py(py),
             None => py.None(),
         };
 
+        let offset: Option<usize> = dict.get_as(intern!(py, "offset"))?;
+        let line: Option<usize> = dict.get_as(intern!(py, "line"))?;
+        let column: Option<usize> = dict.get_as(intern!(py, "column"))?;
+        let position = match (offset, line, column) {
+            (Some(offset), Some(line), Some(column)) => {
+                let pointer: Option<String> = dict.get_as(intern!(py, "json_pointer"))?;
+                Some(JsonPosition {
+                    offset,
+                    line,
+                    column,
+                    pointer: pointer.unwrap_or_else(|| json_pointer(&location)),
+                })
+            }
+            _ => None,
+        };
+
         Ok(Self {
             error_type,
             location,
+            field_location,
+            input_location: None,
             input_value,
+            position,
         })
     }
 }
 
 impl PyLineError {
+    fn field_location(&self) -> &Location {
+        self.field_location.as_ref().unwrap_or(&self.location)
+    }
+
     fn get_error_url(&self, url_prefix: &str) -> String {
         format!("{url_prefix}{}", self.error_type.type_string())
     }
@@ -496,10 +599,14 @@ impl PyLineError {
         include_context: bool,
         input_type: InputType,
         include_input: bool,
+        include_field_loc: bool,
     ) -> PyResult<PyObject> {
         let dict = PyDict::new(py);
         dict.set_item("type", self.error_type.type_string())?;
         dict.set_item("loc", self.location.to_object(py))?;
+        if include_field_loc {
+            dict.set_item("field_loc", self.field_location().to_object(py))?;
+        }
         dict.set_item("msg", self.error_type.render_message(py, input_type)?)?;
         if include_input {
             dict.set_item("input", &self.input_value)?;
@@ -519,6 +626,12 @@ impl PyLineError {
                 }
             }
         }
+        if let Some(ref position) = self.position {
+            dict.set_item("offset", position.offset)?;
+            dict.set_item("line", position.line)?;
+            dict.set_item("column", position.column)?;
+            dict.set_item("json_pointer", &position.pointer)?;
+        }
         Ok(dict.into_py(py))
     }
 
@@ -585,6 +698,7 @@ struct ValidationErrorSerializer<'py> {
     url_prefix: Option<&'py str>,
     include_context: bool,
     include_input: bool,
+    include_field_loc: bool,
     extra: &'py crate::serializers::Extra<'py>,
     input_type: &'py InputType,
 }
@@ -602,6 +716,7 @@ impl<'py> Serialize for ValidationErrorSerializer<'py> {
                 url_prefix: self.url_prefix,
                 include_context: self.include_context,
                 include_input: self.include_input,
+                include_field_loc: self.include_field_loc,
                 extra: self.extra,
                 input_type: self.input_type,
             };
@@ -617,6 +732,7 @@ struct PyLineErrorSerializer<'py> {
     url_prefix: Option<&'py str>,
     include_context: bool,
     include_input: bool,
+    include_field_loc: bool,
     extra: &'py crate::serializers::Extra<'py>,
     input_type: &'py InputType,
 }
@@ -627,15 +743,26 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
         S: Serializer,
     {
         let py = self.py;
-        let size = 3 + [self.url_prefix.is_some(), self.include_context, self.include_input]
-            .into_iter()
-            .filter(|b| *b)
-            .count();
+        let mut size = 3 + [
+            self.url_prefix.is_some(),
+            self.include_context,
+            self.include_input,
+            self.include_field_loc,
+        ]
+        .into_iter()
+        .filter(|b| *b)
+        .count();
+        if self.line_error.position.is_some() {
+            size += 4;
+        }
         let mut map = serializer.serialize_map(Some(size))?;
 
         map.serialize_entry("type", &self.line_error.error_type.type_string())?;
 
         map.serialize_entry("loc", &self.line_error.location)?;
+        if self.include_field_loc {
+            map.serialize_entry("field_loc", self.line_error.field_location())?;
+        }
 
         let msg = self
             .line_error
@@ -659,6 +786,12 @@ impl<'py> Serialize for PyLineErrorSerializer<'py> {
         if let Some(url_prefix) = self.url_prefix {
             map.serialize_entry("url", &self.line_error.get_error_url(url_prefix))?;
         }
+        if let Some(ref position) = self.line_error.position {
+            map.serialize_entry("offset", &position.offset)?;
+            map.serialize_entry("line", &position.line)?;
+            map.serialize_entry("column", &position.column)?;
+            map.serialize_entry("json_pointer", &position.pointer)?;
+        }
         map.end()
     }
 }
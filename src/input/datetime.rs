@@ -3,17 +3,20 @@ use pyo3::prelude::*;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::pyclass::CompareOp;
-use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyTime, PyTzInfo};
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyTime, PyType, PyTzInfo};
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 use speedate::{Date, DateTime, Duration, ParseError, Time, TimeConfig};
 use std::borrow::Cow;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::Hash;
 use std::hash::Hasher;
+use std::str::FromStr;
 
 use strum::EnumMessage;
 
 use super::Input;
+use crate::build_tools::py_schema_err;
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::tools::py_err;
 
@@ -234,10 +237,52 @@ fn time_as_tzinfo<'py>(py: Python<'py>, time: &Time) -> PyResult<Option<&'py PyT
     }
 }
 
+static ZONE_INFO_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+pub fn get_zoneinfo_type(py: Python<'_>) -> PyResult<&PyType> {
+    Ok(ZONE_INFO_TYPE
+        .get_or_try_init(py, || py.import("zoneinfo")?.getattr("ZoneInfo")?.extract())?
+        .as_ref(py))
+}
+
+/// Create a `zoneinfo.ZoneInfo` from an IANA timezone name like `Europe/Paris`, `ZoneInfo` caches instances
+/// so this is cheap for names which have been seen before.
+pub fn get_zoneinfo<'py>(py: Python<'py>, name: &str) -> PyResult<&'py PyTzInfo> {
+    Ok(get_zoneinfo_type(py)?.call1((name,))?.downcast()?)
+}
+
+fn zoned_as_pydatetime<'py>(py: Python<'py>, datetime: &DateTime, zone: &str) -> PyResult<&'py PyDateTime> {
+    let zoneinfo = get_zoneinfo(py, zone)?;
+    let tz_info = match datetime.time.tz_offset {
+        // the local time in the zone
+        None => Some(zoneinfo),
+        // the instant given by the offset, converted into the zone below
+        Some(_) => time_as_tzinfo(py, &datetime.time)?,
+    };
+    let py_dt = PyDateTime::new(
+        py,
+        datetime.date.year.into(),
+        datetime.date.month,
+        datetime.date.day,
+        datetime.time.hour,
+        datetime.time.minute,
+        datetime.time.second,
+        datetime.time.microsecond,
+        tz_info,
+    )?;
+    if datetime.time.tz_offset.is_some() {
+        Ok(py_dt.call_method1(intern!(py, "astimezone"), (zoneinfo,))?.downcast()?)
+    } else {
+        Ok(py_dt)
+    }
+}
+
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum EitherDateTime<'a> {
     Raw(DateTime),
     Py(&'a PyDateTime),
+    /// a datetime with an IANA timezone name from an RFC 9557 suffix like `[Europe/Paris]`
+    Zoned(DateTime, String),
 }
 
 impl<'a> From<DateTime> for EitherDateTime<'a> {
@@ -264,6 +309,7 @@ impl<'a> EitherDateTime<'a> {
         match self {
             Self::Raw(dt) => Ok(dt.clone()),
             Self::Py(py_dt) => pydatetime_as_datetime(py_dt),
+            Self::Zoned(dt, zone) => Python::with_gil(|py| pydatetime_as_datetime(zoned_as_pydatetime(py, dt, zone)?)),
         }
     }
 
@@ -281,29 +327,175 @@ impl<'a> EitherDateTime<'a> {
                 time_as_tzinfo(py, &datetime.time)?,
             )?,
             Self::Py(dt) => dt,
+            Self::Zoned(datetime, zone) => zoned_as_pydatetime(py, &datetime, &zone)?,
         };
         Ok(dt.into_py(py))
     }
 }
 
-pub fn bytes_as_date<'a>(input: &'a impl Input<'a>, bytes: &[u8]) -> ValResult<EitherDate<'a>> {
+/// The unit of durations given as numbers.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DurationUnit {
+    Milliseconds,
+    #[default]
+    Seconds,
+    Minutes,
+}
+
+impl FromStr for DurationUnit {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "ms" => Ok(Self::Milliseconds),
+            "s" => Ok(Self::Seconds),
+            "min" => Ok(Self::Minutes),
+            s => py_schema_err!("Invalid duration unit: `{}`, expected `ms`, `s` or `min`", s),
+        }
+    }
+}
+
+impl DurationUnit {
+    fn micros(self) -> i64 {
+        match self {
+            Self::Milliseconds => 1_000,
+            Self::Seconds => 1_000_000,
+            Self::Minutes => 60_000_000,
+        }
+    }
+}
+
+/// How durations are parsed beyond speedate's ISO 8601 and `[-][DD]D[,][HH:MM:]SS[.ffffff]` formats.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DurationParsing {
+    /// the unit of numeric inputs
+    pub unit: DurationUnit,
+    /// whether to accept strings like `1h30m` or `2 days`
+    pub human_strings: bool,
+    /// whether to reject months and years, which have no fixed length
+    pub forbid_calendar_units: bool,
+}
+
+/// The unit of unix timestamps given as numbers or numeric strings.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampUnit {
+    /// speedate's heuristic: values larger than `2e10` are milliseconds, otherwise seconds
+    #[default]
+    Auto,
+    Seconds,
+    Milliseconds,
+    Microseconds,
+    Nanoseconds,
+}
+
+impl FromStr for TimestampUnit {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "auto" => Ok(Self::Auto),
+            "s" => Ok(Self::Seconds),
+            "ms" => Ok(Self::Milliseconds),
+            "us" => Ok(Self::Microseconds),
+            "ns" => Ok(Self::Nanoseconds),
+            s => py_schema_err!(
+                "Invalid timestamp unit: `{}`, expected `auto`, `s`, `ms`, `us` or `ns`",
+                s
+            ),
+        }
+    }
+}
+
+impl TimestampUnit {
+    pub fn per_second(self) -> i64 {
+        match self {
+            Self::Auto | Self::Seconds => 1,
+            Self::Milliseconds => 1_000,
+            Self::Microseconds => 1_000_000,
+            Self::Nanoseconds => 1_000_000_000,
+        }
+    }
+
+    /// Split an integer timestamp in this unit into whole seconds and microseconds,
+    /// sub-microsecond precision is truncated.
+    fn split_int(self, timestamp: i64) -> (i64, u32) {
+        let per_second = self.per_second();
+        let fraction = timestamp.rem_euclid(per_second);
+        let microseconds = match self {
+            Self::Nanoseconds => fraction / 1_000,
+            _ => fraction * (1_000_000 / per_second),
+        };
+        (timestamp.div_euclid(per_second), microseconds as u32)
+    }
+
+    /// Split a float timestamp in this unit into whole seconds and microseconds
+    fn split_float(self, timestamp: f64) -> (i64, u32) {
+        let seconds = timestamp / self.per_second() as f64;
+        // checking for extra digits in microseconds is unreliable with large floats,
+        // so we just round to the nearest microsecond
+        let microseconds = seconds.fract().abs() * 1_000_000.0;
+        (seconds.floor() as i64, microseconds.round() as u32)
+    }
+
+    /// With an explicit unit, strings which are numbers are timestamps in that unit rather than being
+    /// left to speedate, which guesses the unit.
+    fn parse_numeric(self, bytes: &[u8]) -> Option<Result<i64, f64>> {
+        if self == Self::Auto || !bytes.iter().all(|b| b.is_ascii_digit() || matches!(b, b'-' | b'.')) {
+            return None;
+        }
+        let s = std::str::from_utf8(bytes).ok()?;
+        match s.parse::<i64>() {
+            Ok(int) => Some(Ok(int)),
+            Err(_) => s.parse::<f64>().ok().map(Err),
+        }
+    }
+}
+
+pub fn bytes_as_date<'a>(
+    input: &'a impl Input<'a>,
+    bytes: &[u8],
+    timestamp_unit: TimestampUnit,
+) -> ValResult<EitherDate<'a>> {
+    if let Some(timestamp) = timestamp_unit.parse_numeric(bytes) {
+        let (seconds, microseconds) = match timestamp {
+            Ok(int) => timestamp_unit.split_int(int),
+            Err(float) => timestamp_unit.split_float(float),
+        };
+        return match timestamp_as_datetime(seconds, microseconds, timestamp_unit) {
+            Ok(dt) if dt.time.hour == 0 && dt.time.minute == 0 && dt.time.second == 0 && dt.time.microsecond == 0 => {
+                Ok(dt.date.into())
+            }
+            Ok(_) => Err(date_parsing_error(input, ParseError::DateNotExact)),
+            Err(err) => Err(date_parsing_error(input, err)),
+        };
+    }
     match Date::parse_bytes(bytes) {
         Ok(date) => Ok(date.into()),
-        Err(err) => Err(ValError::new(
-            ErrorType::DateParsing {
-                error: Cow::Borrowed(err.get_documentation().unwrap_or_default()),
-                context: None,
-            },
-            input,
-        )),
+        Err(err) => Err(date_parsing_error(input, err)),
     }
 }
 
+fn date_parsing_error<'a>(input: &'a impl Input<'a>, err: ParseError) -> ValError {
+    ValError::new(
+        ErrorType::DateParsing {
+            error: Cow::Borrowed(err.get_documentation().unwrap_or_default()),
+            context: None,
+        },
+        input,
+    )
+}
+
 pub fn bytes_as_time<'a>(
     input: &'a impl Input<'a>,
     bytes: &[u8],
     microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    timestamp_unit: TimestampUnit,
 ) -> ValResult<EitherTime<'a>> {
+    match timestamp_unit.parse_numeric(bytes) {
+        Some(Ok(int)) => return int_as_time(input, int, timestamp_unit),
+        Some(Err(float)) => return float_as_time(input, float, timestamp_unit),
+        None => (),
+    }
     match Time::parse_bytes_with_config(
         bytes,
         &TimeConfig {
@@ -326,7 +518,16 @@ pub fn bytes_as_datetime<'a, 'b>(
     input: &'a impl Input<'a>,
     bytes: &'b [u8],
     microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    timestamp_unit: TimestampUnit,
 ) -> ValResult<EitherDateTime<'a>> {
+    let (bytes, zone) = split_annotations(bytes);
+    if zone.is_none() {
+        match timestamp_unit.parse_numeric(bytes) {
+            Some(Ok(int)) => return int_as_datetime(input, int, timestamp_unit),
+            Some(Err(float)) => return float_as_datetime(input, float, timestamp_unit),
+            None => (),
+        }
+    }
     match DateTime::parse_bytes_with_config(
         bytes,
         &TimeConfig {
@@ -334,7 +535,27 @@ pub fn bytes_as_datetime<'a, 'b>(
             unix_timestamp_offset: Some(0),
         },
     ) {
-        Ok(dt) => Ok(dt.into()),
+        Ok(dt) => match zone {
+            Some(zone) => {
+                let zone_error: Option<Cow<'static, str>> = Python::with_gil(|py| {
+                    if get_zoneinfo_type(py).is_err() {
+                        Some("timezone names require the `zoneinfo` module, available from Python 3.9".into())
+                    } else if get_zoneinfo(py, zone).is_err() {
+                        Some(format!("unknown timezone `{zone}`").into())
+                    } else {
+                        None
+                    }
+                });
+                if let Some(error) = zone_error {
+                    return Err(ValError::new(
+                        ErrorType::DatetimeParsing { error, context: None },
+                        input,
+                    ));
+                }
+                Ok(EitherDateTime::Zoned(dt, zone.to_string()))
+            }
+            None => Ok(dt.into()),
+        },
         Err(err) => Err(ValError::new(
             ErrorType::DatetimeParsing {
                 error: Cow::Borrowed(err.get_documentation().unwrap_or_default()),
@@ -345,19 +566,78 @@ pub fn bytes_as_datetime<'a, 'b>(
     }
 }
 
+/// Split RFC 9557 annotations from the end of a datetime, returning the timezone from a suffix like
+/// `[Europe/Paris]` or `[!Europe/Paris]`.
+///
+/// Key-value tags like `[u-ca=gregory]`, which follow the timezone, are ignored unless they're marked critical
+/// with `!`, in which case the annotations are left in place so the datetime fails to parse.
+fn split_annotations(bytes: &[u8]) -> (&[u8], Option<&str>) {
+    let mut rest = bytes;
+    while let Some(without_bracket) = rest.strip_suffix(b"]") {
+        let Some(start) = without_bracket.iter().rposition(|b| *b == b'[') else {
+            return (bytes, None);
+        };
+        let annotation = &without_bracket[start + 1..];
+        let (critical, annotation) = match annotation.strip_prefix(b"!") {
+            Some(annotation) => (true, annotation),
+            None => (false, annotation),
+        };
+        rest = &without_bracket[..start];
+        if annotation.contains(&b'=') {
+            if critical {
+                return (bytes, None);
+            }
+            continue;
+        }
+        return match std::str::from_utf8(annotation) {
+            Ok(zone) if !zone.is_empty() => (rest, Some(zone)),
+            _ => (bytes, None),
+        };
+    }
+    (rest, None)
+}
+
 pub fn int_as_datetime<'a>(
     input: &'a impl Input<'a>,
     timestamp: i64,
-    timestamp_microseconds: u32,
+    timestamp_unit: TimestampUnit,
 ) -> ValResult<EitherDateTime> {
-    match DateTime::from_timestamp_with_config(
+    let (seconds, microseconds) = timestamp_unit.split_int(timestamp);
+    seconds_as_datetime(input, seconds, microseconds, timestamp_unit)
+}
+
+fn timestamp_as_datetime(
+    seconds: i64,
+    microseconds: u32,
+    timestamp_unit: TimestampUnit,
+) -> Result<DateTime, ParseError> {
+    // speedate treats timestamps above `2e10` as milliseconds, so when we know the value is in seconds
+    // large values are passed as milliseconds to get the right result
+    let (timestamp, timestamp_microseconds) = match timestamp_unit {
+        TimestampUnit::Auto => (seconds, microseconds),
+        _ if seconds.unsigned_abs() <= 20_000_000_000 => (seconds, microseconds),
+        _ => match seconds.checked_mul(1_000) {
+            Some(ms) => (ms + i64::from(microseconds / 1_000), microseconds % 1_000),
+            None => (seconds, microseconds),
+        },
+    };
+    DateTime::from_timestamp_with_config(
         timestamp,
         timestamp_microseconds,
         &TimeConfig {
             unix_timestamp_offset: Some(0),
             ..Default::default()
         },
-    ) {
+    )
+}
+
+fn seconds_as_datetime<'a>(
+    input: &'a impl Input<'a>,
+    seconds: i64,
+    microseconds: u32,
+    timestamp_unit: TimestampUnit,
+) -> ValResult<EitherDateTime> {
+    match timestamp_as_datetime(seconds, microseconds, timestamp_unit) {
         Ok(dt) => Ok(dt.into()),
         Err(err) => Err(ValError::new(
             ErrorType::DatetimeParsing {
@@ -383,12 +663,14 @@ macro_rules! nan_check {
     };
 }
 
-pub fn float_as_datetime<'a>(input: &'a impl Input<'a>, timestamp: f64) -> ValResult<EitherDateTime> {
+pub fn float_as_datetime<'a>(
+    input: &'a impl Input<'a>,
+    timestamp: f64,
+    timestamp_unit: TimestampUnit,
+) -> ValResult<EitherDateTime> {
     nan_check!(input, timestamp, DatetimeParsing);
-    let microseconds = timestamp.fract().abs() * 1_000_000.0;
-    // checking for extra digits in microseconds is unreliable with large floats,
-    // so we just round to the nearest microsecond
-    int_as_datetime(input, timestamp.floor() as i64, microseconds.round() as u32)
+    let (seconds, microseconds) = timestamp_unit.split_float(timestamp);
+    seconds_as_datetime(input, seconds, microseconds, timestamp_unit)
 }
 
 pub fn date_as_datetime(date: &PyDate) -> PyResult<EitherDateTime> {
@@ -410,6 +692,15 @@ pub fn date_as_datetime(date: &PyDate) -> PyResult<EitherDateTime> {
 const MAX_U32: i64 = u32::MAX as i64;
 
 pub fn int_as_time<'a>(
+    input: &'a impl Input<'a>,
+    timestamp: i64,
+    timestamp_unit: TimestampUnit,
+) -> ValResult<EitherTime> {
+    let (seconds, microseconds) = timestamp_unit.split_int(timestamp);
+    seconds_as_time(input, seconds, microseconds)
+}
+
+fn seconds_as_time<'a>(
     input: &'a impl Input<'a>,
     timestamp: i64,
     timestamp_microseconds: u32,
@@ -448,11 +739,14 @@ pub fn int_as_time<'a>(
     }
 }
 
-pub fn float_as_time<'a>(input: &'a impl Input<'a>, timestamp: f64) -> ValResult<EitherTime> {
+pub fn float_as_time<'a>(
+    input: &'a impl Input<'a>,
+    timestamp: f64,
+    timestamp_unit: TimestampUnit,
+) -> ValResult<EitherTime> {
     nan_check!(input, timestamp, TimeParsing);
-    let microseconds = timestamp.fract().abs() * 1_000_000.0;
-    // round for same reason as above
-    int_as_time(input, timestamp.floor() as i64, microseconds.round() as u32)
+    let (seconds, microseconds) = timestamp_unit.split_float(timestamp);
+    seconds_as_time(input, seconds, microseconds)
 }
 
 fn map_timedelta_err<'a>(input: &'a impl Input<'a>, err: ParseError) -> ValError {
@@ -465,11 +759,27 @@ fn map_timedelta_err<'a>(input: &'a impl Input<'a>, err: ParseError) -> ValError
     )
 }
 
+fn calendar_unit_err<'a>(input: &'a impl Input<'a>, unit: &str) -> ValError {
+    ValError::new(
+        ErrorType::TimeDeltaParsing {
+            error: format!("{unit} have no fixed duration, use days or weeks instead").into(),
+            context: None,
+        },
+        input,
+    )
+}
+
 pub fn bytes_as_timedelta<'a, 'b>(
     input: &'a impl Input<'a>,
     bytes: &'b [u8],
     microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    duration_parsing: DurationParsing,
 ) -> ValResult<EitherTimedelta<'a>> {
+    if duration_parsing.forbid_calendar_units {
+        if let Some(unit) = iso_calendar_unit(bytes) {
+            return Err(calendar_unit_err(input, unit));
+        }
+    }
     match Duration::parse_bytes_with_config(
         bytes,
         &TimeConfig {
@@ -478,21 +788,119 @@ pub fn bytes_as_timedelta<'a, 'b>(
         },
     ) {
         Ok(dt) => Ok(dt.into()),
-        Err(err) => Err(map_timedelta_err(input, err)),
+        Err(err) => {
+            let human = match std::str::from_utf8(bytes) {
+                Ok(s) if duration_parsing.human_strings => parse_human_duration(s, duration_parsing.unit),
+                _ => None,
+            };
+            match human {
+                Some((_, Some(unit))) if duration_parsing.forbid_calendar_units => Err(calendar_unit_err(input, unit)),
+                Some((micros, _)) => Ok(micros_as_duration(input, micros)?.into()),
+                None => Err(map_timedelta_err(input, err)),
+            }
+        }
     }
 }
 
-pub fn int_as_duration<'a>(input: &'a impl Input<'a>, total_seconds: i64) -> ValResult<Duration> {
-    let positive = total_seconds >= 0;
-    let total_seconds = total_seconds.unsigned_abs();
-    // we can safely unwrap here since we've guaranteed seconds and microseconds can't cause overflow
-    let days = (total_seconds / 86400) as u32;
-    let seconds = (total_seconds % 86400) as u32;
-    Duration::new(positive, days, seconds, 0).map_err(|err| map_timedelta_err(input, err))
+/// The first calendar unit, years or months, used in an ISO 8601 duration like `P1Y2M`.
+fn iso_calendar_unit(bytes: &[u8]) -> Option<&'static str> {
+    let bytes = bytes
+        .strip_prefix(b"-")
+        .or_else(|| bytes.strip_prefix(b"+"))
+        .unwrap_or(bytes);
+    let (b'P' | b'p', designators) = bytes.split_first()? else {
+        return None;
+    };
+    // `M` after the `T` is minutes
+    designators
+        .iter()
+        .take_while(|b| !matches!(b, b'T' | b't'))
+        .find_map(|b| match b {
+            b'Y' | b'y' => Some("years"),
+            b'M' | b'm' => Some("months"),
+            _ => None,
+        })
+}
+
+/// Microseconds in a unit of a human readable duration, months and years are calendar units with the
+/// same lengths speedate uses in ISO 8601 durations.
+fn human_unit(word: &str) -> Option<(f64, Option<&'static str>)> {
+    let micros = match word {
+        "us" | "µs" | "usec" | "usecs" | "microsecond" | "microseconds" => 1.0,
+        "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => 1e3,
+        "s" | "sec" | "secs" | "second" | "seconds" => 1e6,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60e6,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3_600e6,
+        "d" | "day" | "days" => 86_400e6,
+        "w" | "wk" | "wks" | "week" | "weeks" => 604_800e6,
+        "mo" | "month" | "months" => return Some((30.0 * 86_400e6, Some("months"))),
+        "y" | "yr" | "yrs" | "year" | "years" => return Some((365.0 * 86_400e6, Some("years"))),
+        _ => return None,
+    };
+    Some((micros, None))
+}
+
+/// Parse human readable durations like `1h30m`, `2 days, 4 hours` or `1.5h`, a number on its own is in `unit`.
+///
+/// Returns the total microseconds and the calendar unit used, if any.
+fn parse_human_duration(s: &str, unit: DurationUnit) -> Option<(i128, Option<&'static str>)> {
+    let s = s.trim();
+    let (negative, mut rest) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let mut total = 0.0;
+    let mut calendar_unit = None;
+    let mut parts = 0;
+    loop {
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+        if rest.is_empty() {
+            break;
+        }
+        let number_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..number_len].parse().ok()?;
+        rest = rest[number_len..].trim_start();
+        let word_len = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+        let micros = if word_len == 0 {
+            if parts > 0 || !rest.is_empty() {
+                return None;
+            }
+            unit.micros() as f64
+        } else {
+            let (micros, calendar) = human_unit(&rest[..word_len].to_lowercase())?;
+            calendar_unit = calendar_unit.or(calendar);
+            micros
+        };
+        total += number * micros;
+        rest = &rest[word_len..];
+        parts += 1;
+    }
+    if parts == 0 || !total.is_finite() {
+        return None;
+    }
+    let micros = total.round() as i128;
+    Some((if negative { -micros } else { micros }, calendar_unit))
+}
+
+fn micros_as_duration<'a>(input: &'a impl Input<'a>, micros: i128) -> ValResult<Duration> {
+    let positive = micros >= 0;
+    let micros = micros.unsigned_abs();
+    // days beyond `u32::MAX` are saturated, speedate will reject them as too large
+    let days = u32::try_from(micros / 86_400_000_000).unwrap_or(u32::MAX);
+    let seconds = (micros / 1_000_000 % 86_400) as u32;
+    let microseconds = (micros % 1_000_000) as u32;
+    Duration::new(positive, days, seconds, microseconds).map_err(|err| map_timedelta_err(input, err))
+}
+
+pub fn int_as_duration<'a>(input: &'a impl Input<'a>, value: i64, unit: DurationUnit) -> ValResult<Duration> {
+    micros_as_duration(input, i128::from(value) * i128::from(unit.micros()))
 }
 
-pub fn float_as_duration<'a>(input: &'a impl Input<'a>, total_seconds: f64) -> ValResult<Duration> {
-    nan_check!(input, total_seconds, TimeDeltaParsing);
+pub fn float_as_duration<'a>(input: &'a impl Input<'a>, value: f64, unit: DurationUnit) -> ValResult<Duration> {
+    nan_check!(input, value, TimeDeltaParsing);
+    let total_seconds = value * unit.micros() as f64 / 1_000_000.0;
     let positive = total_seconds >= 0_f64;
     let total_seconds = total_seconds.abs();
     let microsecond = total_seconds.fract() * 1_000_000.0;
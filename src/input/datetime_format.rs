@@ -0,0 +1,363 @@
+use std::fmt;
+
+use speedate::{Date, DateTime, Time};
+
+const MONTH_NAMES: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Directive {
+    /// `%Y`, four digit year
+    Year,
+    /// `%y`, two digit year, 69-99 map to 1969-1999 and 00-68 to 2000-2068 as in `time.strptime`
+    ShortYear,
+    /// `%m`
+    Month,
+    /// `%b` or `%B`, English month name, full or abbreviated
+    MonthName,
+    /// `%d`
+    Day,
+    /// `%j`
+    DayOfYear,
+    /// `%a` or `%A`, English weekday name, full or abbreviated, checked but otherwise ignored
+    WeekdayName,
+    /// `%H`
+    Hour,
+    /// `%I`
+    Hour12,
+    /// `%p`
+    AmPm,
+    /// `%M`
+    Minute,
+    /// `%S`
+    Second,
+    /// `%f`, one to six digits of fractional seconds
+    Microsecond,
+    /// `%z`, `Z`, `±HHMM` or `±HH:MM`
+    Offset,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum FormatItem {
+    Directive(Directive),
+    /// any run of whitespace in the format matches one or more whitespace characters in the input
+    Whitespace,
+    Literal(char),
+}
+
+/// A strftime-style format pattern, e.g. `%d/%m/%Y %H:%M`, used to parse dates, times and datetimes
+/// which aren't in ISO 8601 format.
+///
+/// Matching follows `datetime.strptime`: numeric fields may omit leading zeros, literals and names are
+/// matched case-insensitively, and the whole input must be consumed.
+#[derive(Debug, Clone)]
+pub struct DateTimeFormat {
+    pattern: String,
+    items: Vec<FormatItem>,
+}
+
+impl fmt::Display for DateTimeFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}'", self.pattern)
+    }
+}
+
+impl DateTimeFormat {
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        let mut items = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            let item = match c {
+                '%' => {
+                    let directive = match chars.next() {
+                        Some('Y') => Directive::Year,
+                        Some('y') => Directive::ShortYear,
+                        Some('m') => Directive::Month,
+                        Some('b' | 'B') => Directive::MonthName,
+                        Some('d') => Directive::Day,
+                        Some('j') => Directive::DayOfYear,
+                        Some('a' | 'A') => Directive::WeekdayName,
+                        Some('H') => Directive::Hour,
+                        Some('I') => Directive::Hour12,
+                        Some('p') => Directive::AmPm,
+                        Some('M') => Directive::Minute,
+                        Some('S') => Directive::Second,
+                        Some('f') => Directive::Microsecond,
+                        Some('z') => Directive::Offset,
+                        Some('%') => {
+                            items.push(FormatItem::Literal('%'));
+                            continue;
+                        }
+                        Some(other) => return Err(format!("unsupported directive `%{other}`")),
+                        None => return Err("pattern ends with an incomplete directive `%`".to_string()),
+                    };
+                    FormatItem::Directive(directive)
+                }
+                c if c.is_whitespace() => {
+                    if items.last() == Some(&FormatItem::Whitespace) {
+                        continue;
+                    }
+                    FormatItem::Whitespace
+                }
+                c => FormatItem::Literal(c),
+            };
+            items.push(item);
+        }
+        Ok(Self {
+            pattern: pattern.to_string(),
+            items,
+        })
+    }
+
+    /// Parse `input` using this format, returning `None` if it doesn't match or describes an invalid
+    /// date or time; fields missing from the format default to `1900-01-01T00:00:00`.
+    pub fn parse(&self, input: &str) -> Option<DateTime> {
+        let mut fields = Fields::default();
+        let mut rest = input;
+        for item in &self.items {
+            rest = match item {
+                FormatItem::Whitespace => {
+                    let trimmed = rest.trim_start();
+                    if trimmed.len() == rest.len() {
+                        return None;
+                    }
+                    trimmed
+                }
+                FormatItem::Literal(expected) => {
+                    let mut chars = rest.chars();
+                    let c = chars.next()?;
+                    if !c.to_lowercase().eq(expected.to_lowercase()) {
+                        return None;
+                    }
+                    chars.as_str()
+                }
+                FormatItem::Directive(directive) => fields.consume(*directive, rest)?,
+            };
+        }
+        if rest.is_empty() {
+            fields.into_datetime()
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct Fields {
+    year: Option<u16>,
+    month: Option<u8>,
+    day: Option<u8>,
+    day_of_year: Option<u16>,
+    hour: Option<u8>,
+    hour12: Option<u8>,
+    pm: Option<bool>,
+    minute: u8,
+    second: u8,
+    microsecond: u32,
+    tz_offset: Option<i32>,
+}
+
+impl Fields {
+    /// Parse a single directive from the start of `input`, returning the remaining input.
+    fn consume<'s>(&mut self, directive: Directive, input: &'s str) -> Option<&'s str> {
+        let rest = match directive {
+            Directive::Year => {
+                let (year, rest) = take_number(input, 4, 4)?;
+                self.year = Some(u16::try_from(year).ok()?);
+                rest
+            }
+            Directive::ShortYear => {
+                let (year, rest) = take_number(input, 1, 2)?;
+                self.year = Some(if year < 69 { 2000 + year } else { 1900 + year } as u16);
+                rest
+            }
+            Directive::Month => {
+                let (month, rest) = take_number(input, 1, 2)?;
+                self.month = Some(month as u8);
+                rest
+            }
+            Directive::MonthName => {
+                let (index, rest) = take_name(input, &MONTH_NAMES)?;
+                self.month = Some(index as u8 + 1);
+                rest
+            }
+            Directive::Day => {
+                let (day, rest) = take_number(input, 1, 2)?;
+                self.day = Some(day as u8);
+                rest
+            }
+            Directive::DayOfYear => {
+                let (day_of_year, rest) = take_number(input, 1, 3)?;
+                self.day_of_year = Some(day_of_year as u16);
+                rest
+            }
+            Directive::WeekdayName => take_name(input, &WEEKDAY_NAMES)?.1,
+            Directive::Hour => {
+                let (hour, rest) = take_number(input, 1, 2)?;
+                self.hour = Some(hour as u8);
+                rest
+            }
+            Directive::Hour12 => {
+                let (hour, rest) = take_number(input, 1, 2)?;
+                if !(1..=12).contains(&hour) {
+                    return None;
+                }
+                self.hour12 = Some(hour as u8);
+                rest
+            }
+            Directive::AmPm => {
+                let (index, rest) = take_name(input, &["am", "pm"])?;
+                self.pm = Some(index == 1);
+                rest
+            }
+            Directive::Minute => {
+                let (minute, rest) = take_number(input, 1, 2)?;
+                self.minute = minute as u8;
+                rest
+            }
+            Directive::Second => {
+                let (second, rest) = take_number(input, 1, 2)?;
+                self.second = second as u8;
+                rest
+            }
+            Directive::Microsecond => {
+                let digits = input.bytes().take(6).take_while(u8::is_ascii_digit).count();
+                let (fraction, rest) = take_number(input, 1, 6)?;
+                self.microsecond = fraction * 10_u32.pow(6 - digits as u32);
+                rest
+            }
+            Directive::Offset => {
+                let (offset, rest) = take_offset(input)?;
+                self.tz_offset = Some(offset);
+                rest
+            }
+        };
+        Some(rest)
+    }
+
+    fn into_datetime(self) -> Option<DateTime> {
+        let year = self.year.unwrap_or(1900);
+        let (month, day) = match (self.day_of_year, self.month, self.day) {
+            (Some(day_of_year), None, None) => month_day_from_ordinal(year, day_of_year)?,
+            (_, month, day) => (month.unwrap_or(1), day.unwrap_or(1)),
+        };
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        let hour = match (self.hour12, self.hour) {
+            (Some(hour12), _) => hour12 % 12 + if self.pm == Some(true) { 12 } else { 0 },
+            (None, hour) => hour.unwrap_or(0),
+        };
+        if hour > 23 || self.minute > 59 || self.second > 59 {
+            return None;
+        }
+
+        Some(DateTime {
+            date: Date { year, month, day },
+            time: Time {
+                hour,
+                minute: self.minute,
+                second: self.second,
+                microsecond: self.microsecond,
+                tz_offset: self.tz_offset,
+            },
+        })
+    }
+}
+
+/// Take between `min` and `max` ASCII digits from the start of `input`.
+fn take_number(input: &str, min: usize, max: usize) -> Option<(u32, &str)> {
+    let digits = input.bytes().take(max).take_while(u8::is_ascii_digit).count();
+    if digits < min {
+        return None;
+    }
+    let (number, rest) = input.split_at(digits);
+    Some((number.parse().ok()?, rest))
+}
+
+/// Match a full or three letter abbreviated name from `names` case-insensitively, returning its index.
+fn take_name<'s>(input: &'s str, names: &[&str]) -> Option<(usize, &'s str)> {
+    let starts_with = |prefix: &str| {
+        input
+            .get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+    };
+    names.iter().enumerate().find_map(|(index, name)| {
+        if starts_with(name) {
+            Some((index, &input[name.len()..]))
+        } else if name.len() > 3 && starts_with(&name[..3]) {
+            Some((index, &input[3..]))
+        } else {
+            None
+        }
+    })
+}
+
+/// Take a UTC offset in seconds: `Z`, `±HHMM` or `±HH:MM`.
+fn take_offset(input: &str) -> Option<(i32, &str)> {
+    if let Some(rest) = input.strip_prefix(['Z', 'z']) {
+        return Some((0, rest));
+    }
+    let sign = match input.as_bytes().first()? {
+        b'+' => 1,
+        b'-' => -1,
+        _ => return None,
+    };
+    let (hours, rest) = take_number(&input[1..], 2, 2)?;
+    let rest = rest.strip_prefix(':').unwrap_or(rest);
+    let (minutes, rest) = take_number(rest, 2, 2)?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some((sign * (hours * 3600 + minutes * 60) as i32, rest))
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400))
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+fn month_day_from_ordinal(year: u16, day_of_year: u16) -> Option<(u8, u8)> {
+    let mut remaining = day_of_year;
+    for month in 1..=12 {
+        let days = u16::from(days_in_month(year, month));
+        if remaining == 0 {
+            return None;
+        } else if remaining <= days {
+            return Some((month, remaining as u8));
+        }
+        remaining -= days;
+    }
+    None
+}
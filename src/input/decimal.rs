@@ -0,0 +1,381 @@
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+
+use num_bigint::BigUint;
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyTuple;
+
+use crate::validators::decimal::get_decimal_type;
+
+/// Exponents beyond this are left to Python's `decimal` module, which enforces its own limits,
+/// this keeps all exponent arithmetic below well clear of `i64` overflow.
+const MAX_EXPONENT: i64 = 999_999_999_999_999_999;
+
+/// Precision of Python's default decimal context, which `Decimal.normalize()` rounds to.
+const CONTEXT_PRECISION: usize = 28;
+
+/// A decimal number in the same shape as `decimal.Decimal.as_tuple()`, so that parsing, constraint
+/// checks and formatting can happen in Rust without calling into the `decimal` module.
+#[derive(Debug, Clone)]
+pub enum DecimalValue {
+    Finite {
+        negative: bool,
+        /// coefficient digits without leading zeros, `"0"` for zero
+        digits: String,
+        exponent: i64,
+    },
+    Infinite {
+        negative: bool,
+    },
+    NaN {
+        negative: bool,
+        signaling: bool,
+        /// diagnostic payload digits, empty if there's no payload
+        payload: String,
+    },
+}
+
+impl DecimalValue {
+    /// Parse a string using the same syntax as `decimal.Decimal(str)`, returns `None` for input which
+    /// isn't a plain ASCII decimal (e.g. invalid input, underscores or non-ASCII digits), those cases
+    /// should be handed to Python.
+    pub fn parse(s: &str) -> Option<Self> {
+        let bytes = s.trim_matches(|c: char| c.is_ascii_whitespace()).as_bytes();
+        let (negative, rest) = match bytes.first() {
+            Some(b'-') => (true, &bytes[1..]),
+            Some(b'+') => (false, &bytes[1..]),
+            _ => (false, bytes),
+        };
+        if matches!(rest.first(), Some(byte) if byte.is_ascii_alphabetic()) {
+            return Self::parse_special(negative, rest);
+        }
+
+        let mut digits = String::with_capacity(rest.len());
+        let mut fraction_len: i64 = 0;
+        let mut seen_dot = false;
+        let mut index = 0;
+        while let Some(&byte) = rest.get(index) {
+            match byte {
+                b'0'..=b'9' => {
+                    if !(digits.is_empty() && byte == b'0') {
+                        digits.push(char::from(byte));
+                    }
+                    if seen_dot {
+                        fraction_len += 1;
+                    }
+                }
+                b'.' if !seen_dot => seen_dot = true,
+                _ => break,
+            }
+            index += 1;
+        }
+        // at least one digit is required, "." alone is invalid
+        if index == usize::from(seen_dot) {
+            return None;
+        }
+
+        let mut exponent: i64 = 0;
+        if let Some(&indicator) = rest.get(index) {
+            if indicator != b'e' && indicator != b'E' {
+                return None;
+            }
+            exponent = std::str::from_utf8(&rest[index + 1..]).ok()?.parse().ok()?;
+        }
+        let exponent = exponent.checked_sub(fraction_len)?;
+        if !(-MAX_EXPONENT..=MAX_EXPONENT).contains(&exponent) {
+            return None;
+        }
+        if digits.is_empty() {
+            digits.push('0');
+        }
+        Some(Self::Finite {
+            negative,
+            digits,
+            exponent,
+        })
+    }
+
+    fn parse_special(negative: bool, value: &[u8]) -> Option<Self> {
+        if value.eq_ignore_ascii_case(b"inf") || value.eq_ignore_ascii_case(b"infinity") {
+            return Some(Self::Infinite { negative });
+        }
+        let (signaling, payload) = if value.len() >= 4 && value[..4].eq_ignore_ascii_case(b"snan") {
+            (true, &value[4..])
+        } else if value.len() >= 3 && value[..3].eq_ignore_ascii_case(b"nan") {
+            (false, &value[3..])
+        } else {
+            return None;
+        };
+        if !payload.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        let payload = std::str::from_utf8(payload).ok()?.trim_start_matches('0');
+        // Python rejects payloads longer than the context precision, let it raise the error
+        if payload.len() > 27 {
+            return None;
+        }
+        Some(Self::NaN {
+            negative,
+            signaling,
+            payload: payload.to_string(),
+        })
+    }
+
+    /// Read the value of a `decimal.Decimal` instance via `as_tuple()`.
+    pub fn from_py_decimal(decimal: &PyAny) -> PyResult<Self> {
+        let py = decimal.py();
+        let (sign, digit_tuple, exponent): (u8, &PyTuple, &PyAny) =
+            decimal.call_method0(intern!(py, "as_tuple"))?.extract()?;
+        let negative = sign == 1;
+        let mut digits = String::with_capacity(digit_tuple.len());
+        for digit in digit_tuple {
+            digits.push(char::from(b'0' + digit.extract::<u8>()?));
+        }
+
+        if let Ok(exponent) = exponent.extract::<i64>() {
+            return Ok(Self::Finite {
+                negative,
+                digits,
+                exponent,
+            });
+        }
+        match exponent.extract::<&str>()? {
+            "F" => Ok(Self::Infinite { negative }),
+            "n" => Ok(Self::NaN {
+                negative,
+                signaling: false,
+                payload: digits,
+            }),
+            "N" => Ok(Self::NaN {
+                negative,
+                signaling: true,
+                payload: digits,
+            }),
+            other => Err(PyValueError::new_err(format!("Unexpected decimal exponent {other:?}"))),
+        }
+    }
+
+    pub fn is_finite(&self) -> bool {
+        matches!(self, Self::Finite { .. })
+    }
+
+    /// Number of digits after the decimal point and total number of digits, as used by the
+    /// `decimal_places` and `max_digits` constraints, `None` for non-finite values.
+    /// With `normalized`, the value is first rounded to the default context precision like `Decimal.normalize()`.
+    pub fn digits_info(&self, normalized: bool) -> Option<(u64, u64)> {
+        let Self::Finite { digits, exponent, .. } = self else {
+            return None;
+        };
+        let (digits_len, exponent) = if normalized {
+            let (rounded, exponent) = round_to_precision(digits, *exponent, CONTEXT_PRECISION);
+            let trimmed = rounded.trim_end_matches('0');
+            if trimmed.is_empty() {
+                (1, 0)
+            } else {
+                (trimmed.len(), exponent + (rounded.len() - trimmed.len()) as i64)
+            }
+        } else {
+            (digits.len(), *exponent)
+        };
+        let mut total_digits = digits_len as u64;
+        let decimals;
+        if exponent >= 0 {
+            // A positive exponent adds that many trailing zeros.
+            total_digits += exponent.unsigned_abs();
+            decimals = 0;
+        } else {
+            // If the absolute value of the negative exponent is larger than the
+            // number of digits, then it's the same as the number of digits,
+            // because it'll consume all the digits and then add
+            // abs(exponent) - len(digits) leading zeros after the decimal point.
+            decimals = exponent.unsigned_abs();
+            total_digits = total_digits.max(decimals);
+        }
+        Some((decimals, total_digits))
+    }
+
+    /// Coefficient digits and exponent with trailing zeros removed, the equivalent of `Decimal.normalize()`
+    /// without rounding to the context precision, so comparisons stay exact.
+    fn normalized_parts(&self) -> Option<(&str, i64)> {
+        match self {
+            Self::Finite { digits, exponent, .. } => {
+                let trimmed = digits.trim_end_matches('0');
+                if trimmed.is_empty() {
+                    Some(("0", 0))
+                } else {
+                    Some((trimmed, exponent + (digits.len() - trimmed.len()) as i64))
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// -2 for -Infinity, -1 for negative, 0 for zero, 1 for positive and 2 for Infinity
+    fn sign_rank(&self) -> Option<i8> {
+        match self {
+            Self::Finite { digits, .. } if digits == "0" => Some(0),
+            Self::Finite { negative: true, .. } => Some(-1),
+            Self::Finite { negative: false, .. } => Some(1),
+            Self::Infinite { negative: true } => Some(-2),
+            Self::Infinite { negative: false } => Some(2),
+            Self::NaN { .. } => None,
+        }
+    }
+
+    /// Numeric comparison, `None` if either value is NaN.
+    pub fn compare(&self, other: &Self) -> Option<Ordering> {
+        let (self_rank, other_rank) = (self.sign_rank()?, other.sign_rank()?);
+        match (self_rank.cmp(&other_rank), self_rank) {
+            (Ordering::Equal, 1) => self.compare_magnitude(other),
+            (Ordering::Equal, -1) => other.compare_magnitude(self),
+            (ordering, _) => Some(ordering),
+        }
+    }
+
+    /// compare the absolute values of two non-zero finite numbers
+    fn compare_magnitude(&self, other: &Self) -> Option<Ordering> {
+        let (self_digits, self_exponent) = self.normalized_parts()?;
+        let (other_digits, other_exponent) = other.normalized_parts()?;
+        // the exponent of the most significant digit decides, unless they're the same
+        let self_adjusted = self_exponent + self_digits.len() as i64;
+        let other_adjusted = other_exponent + other_digits.len() as i64;
+        Some(
+            self_adjusted
+                .cmp(&other_adjusted)
+                .then_with(|| self_digits.cmp(other_digits)),
+        )
+    }
+
+    /// Whether `self` is an exact integer multiple of `other`, `false` for non-finite values and zero `other`.
+    pub fn is_multiple_of(&self, other: &Self) -> bool {
+        let (Some((digits, exponent)), Some((other_digits, other_exponent))) =
+            (self.normalized_parts(), other.normalized_parts())
+        else {
+            return false;
+        };
+        if other_digits == "0" {
+            return false;
+        }
+        if digits == "0" {
+            return true;
+        }
+        // the coefficient has no trailing zeros, so if it has a digit below the lowest digit of `other`
+        // it can't be a multiple
+        if exponent < other_exponent {
+            return false;
+        }
+        let (Some(coefficient), Some(other_coefficient)) = (
+            BigUint::parse_bytes(digits.as_bytes(), 10),
+            BigUint::parse_bytes(other_digits.as_bytes(), 10),
+        ) else {
+            return false;
+        };
+        // (coefficient * 10 ** shift) % other_coefficient, without materialising 10 ** shift
+        let shift = BigUint::from((exponent - other_exponent).unsigned_abs());
+        let remainder = (coefficient % &other_coefficient) * BigUint::from(10u8).modpow(&shift, &other_coefficient)
+            % &other_coefficient;
+        remainder == BigUint::default()
+    }
+}
+
+/// Round coefficient `digits` to at most `precision` significant digits using `ROUND_HALF_EVEN`, the default
+/// rounding of the `decimal` module, returning the new digits and exponent.
+fn round_to_precision(digits: &str, exponent: i64, precision: usize) -> (Cow<'_, str>, i64) {
+    if digits.len() <= precision {
+        return (Cow::Borrowed(digits), exponent);
+    }
+    let (kept, dropped) = digits.split_at(precision);
+    let exponent = exponent + dropped.len() as i64;
+    let round_up = match dropped.as_bytes()[0] {
+        b'6'..=b'9' => true,
+        b'5' => dropped[1..].bytes().any(|d| d != b'0') || kept.bytes().last().is_some_and(|d| (d - b'0') % 2 == 1),
+        _ => false,
+    };
+    if !round_up {
+        return (Cow::Borrowed(kept), exponent);
+    }
+    let mut rounded = kept.as_bytes().to_vec();
+    for digit in rounded.iter_mut().rev() {
+        if *digit == b'9' {
+            *digit = b'0';
+        } else {
+            *digit += 1;
+            let rounded = String::from_utf8(rounded).expect("coefficient digits are ASCII");
+            return (Cow::Owned(rounded), exponent);
+        }
+    }
+    // every kept digit was a 9, so the coefficient becomes `1` followed by zeros one place higher
+    let mut rounded = "1".to_string();
+    rounded.push_str(&"0".repeat(precision - 1));
+    (Cow::Owned(rounded), exponent + 1)
+}
+
+/// Formats the value the same way as `str(decimal.Decimal)`.
+impl fmt::Display for DecimalValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = |negative: bool| if negative { "-" } else { "" };
+        match self {
+            Self::Infinite { negative } => write!(f, "{}Infinity", sign(*negative)),
+            Self::NaN {
+                negative,
+                signaling,
+                payload,
+            } => {
+                let prefix = if *signaling { "sNaN" } else { "NaN" };
+                write!(f, "{}{prefix}{payload}", sign(*negative))
+            }
+            Self::Finite {
+                negative,
+                digits,
+                exponent,
+            } => {
+                f.write_str(sign(*negative))?;
+                let len = digits.len() as i64;
+                let left_digits = exponent + len;
+                // position of the decimal point relative to the start of `digits`
+                let dot_place = if *exponent <= 0 && left_digits > -6 {
+                    left_digits
+                } else {
+                    1
+                };
+                if dot_place <= 0 {
+                    write!(f, "0.{}{digits}", "0".repeat(dot_place.unsigned_abs() as usize))?;
+                } else if dot_place >= len {
+                    write!(f, "{digits}{}", "0".repeat((dot_place - len) as usize))?;
+                } else {
+                    let (int_part, fraction_part) = digits.split_at(dot_place as usize);
+                    write!(f, "{int_part}.{fraction_part}")?;
+                }
+                if left_digits != dot_place {
+                    write!(f, "E{:+}", left_digits - dot_place)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub enum EitherDecimal<'a> {
+    Rust(DecimalValue),
+    Py(&'a PyAny),
+}
+
+impl EitherDecimal<'_> {
+    pub fn as_value(&self) -> PyResult<Cow<'_, DecimalValue>> {
+        match self {
+            Self::Rust(value) => Ok(Cow::Borrowed(value)),
+            Self::Py(decimal) => DecimalValue::from_py_decimal(decimal).map(Cow::Owned),
+        }
+    }
+
+    pub fn try_into_py(self, py: Python<'_>) -> PyResult<PyObject> {
+        match self {
+            Self::Rust(value) => get_decimal_type(py).call1(py, (value.to_string(),)),
+            Self::Py(decimal) => Ok(decimal.into_py(py)),
+        }
+    }
+}
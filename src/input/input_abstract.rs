@@ -6,10 +6,12 @@ use pyo3::{intern, prelude::*};
 
 use crate::errors::{AsLocItem, ErrorTypeDefaults, InputValue, ValError, ValResult};
 use crate::tools::py_err;
+use crate::validators::bytes::ValBytesMode;
 use crate::{PyMultiHostUrl, PyUrl};
 
-use super::datetime::{EitherDate, EitherDateTime, EitherTime, EitherTimedelta};
-use super::return_enums::{EitherBytes, EitherInt, EitherString};
+use super::datetime::{DurationParsing, EitherDate, EitherDateTime, EitherTime, EitherTimedelta, TimestampUnit};
+use super::decimal::EitherDecimal;
+use super::return_enums::{EitherBytes, EitherComplex, EitherInt, EitherString};
 use super::{EitherFloat, GenericArguments, GenericIterable, GenericIterator, GenericMapping, ValidationMatch};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -61,6 +63,10 @@ pub trait Input<'a>: fmt::Debug + ToPyObject + AsLocItem + Sized {
         None
     }
 
+    fn input_is_exact_instance(&self, _class: &PyType) -> bool {
+        false
+    }
+
     fn is_python(&self) -> bool {
         false
     }
@@ -93,7 +99,7 @@ pub trait Input<'a>: fmt::Debug + ToPyObject + AsLocItem + Sized {
         coerce_numbers_to_str: bool,
     ) -> ValResult<ValidationMatch<EitherString<'a>>>;
 
-    fn validate_bytes(&'a self, strict: bool) -> ValResult<ValidationMatch<EitherBytes<'a>>>;
+    fn validate_bytes(&'a self, strict: bool, mode: ValBytesMode) -> ValResult<ValidationMatch<EitherBytes<'a>>>;
 
     fn validate_bool(&self, strict: bool) -> ValResult<ValidationMatch<bool>>;
 
@@ -119,16 +125,18 @@ pub trait Input<'a>: fmt::Debug + ToPyObject + AsLocItem + Sized {
 
     fn validate_float(&'a self, strict: bool) -> ValResult<ValidationMatch<EitherFloat<'a>>>;
 
-    fn validate_decimal(&'a self, strict: bool, py: Python<'a>) -> ValResult<&'a PyAny> {
+    fn validate_complex(&'a self, strict: bool, py: Python<'a>) -> ValResult<ValidationMatch<EitherComplex<'a>>>;
+
+    fn validate_decimal(&'a self, strict: bool, py: Python<'a>) -> ValResult<EitherDecimal<'a>> {
         if strict {
             self.strict_decimal(py)
         } else {
             self.lax_decimal(py)
         }
     }
-    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny>;
+    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<EitherDecimal<'a>>;
     #[cfg_attr(has_coverage_attribute, coverage(off))]
-    fn lax_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+    fn lax_decimal(&'a self, py: Python<'a>) -> ValResult<EitherDecimal<'a>> {
         self.strict_decimal(py)
     }
 
@@ -205,24 +213,27 @@ pub trait Input<'a>: fmt::Debug + ToPyObject + AsLocItem + Sized {
 
     fn validate_iter(&self) -> ValResult<GenericIterator>;
 
-    fn validate_date(&self, strict: bool) -> ValResult<ValidationMatch<EitherDate>>;
+    fn validate_date(&self, strict: bool, timestamp_unit: TimestampUnit) -> ValResult<ValidationMatch<EitherDate>>;
 
     fn validate_time(
         &self,
         strict: bool,
         microseconds_overflow_behavior: speedate::MicrosecondsPrecisionOverflowBehavior,
+        timestamp_unit: TimestampUnit,
     ) -> ValResult<ValidationMatch<EitherTime>>;
 
     fn validate_datetime(
         &self,
         strict: bool,
         microseconds_overflow_behavior: speedate::MicrosecondsPrecisionOverflowBehavior,
+        timestamp_unit: TimestampUnit,
     ) -> ValResult<ValidationMatch<EitherDateTime>>;
 
     fn validate_timedelta(
         &self,
         strict: bool,
         microseconds_overflow_behavior: speedate::MicrosecondsPrecisionOverflowBehavior,
+        duration_parsing: DurationParsing,
     ) -> ValResult<ValidationMatch<EitherTimedelta>>;
 }
 
@@ -2,22 +2,24 @@ use std::borrow::Cow;
 
 use jiter::{JsonArray, JsonValue};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::PyDict;
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 use strum::EnumMessage;
 
 use crate::errors::{AsLocItem, ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
-use crate::validators::decimal::create_decimal;
+use crate::validators::bytes::ValBytesMode;
+use crate::validators::decimal::create_decimal_from_str;
 
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
-    float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
+    float_as_time, int_as_datetime, int_as_duration, int_as_time, DurationParsing, EitherDate, EitherDateTime,
+    EitherTime, TimestampUnit,
 };
 use super::return_enums::ValidationMatch;
-use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_float, str_as_int};
+use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_complex, str_as_float, str_as_int};
 use super::{
-    BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericArguments, GenericIterable,
-    GenericIterator, GenericMapping, Input, JsonArgs,
+    BorrowInput, EitherBytes, EitherComplex, EitherDecimal, EitherFloat, EitherInt, EitherString, EitherTimedelta,
+    GenericArguments, GenericIterable, GenericIterator, GenericMapping, Input, JsonArgs,
 };
 
 /// This is required but since JSON object keys are always strings, I don't think it can be called
@@ -110,9 +112,12 @@ impl<'a> Input<'a> for JsonValue {
         }
     }
 
-    fn validate_bytes(&'a self, _strict: bool) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
+    fn validate_bytes(&'a self, _strict: bool, mode: ValBytesMode) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
         match self {
-            JsonValue::Str(s) => Ok(ValidationMatch::strict(s.as_bytes().into())),
+            JsonValue::Str(s) => match mode.deserialize_string(s) {
+                Ok(b) => Ok(ValidationMatch::strict(b)),
+                Err(e) => Err(ValError::new(e, self)),
+            },
             _ => Err(ValError::new(ErrorTypeDefaults::BytesType, self)),
         }
     }
@@ -154,13 +159,39 @@ impl<'a> Input<'a> for JsonValue {
         }
     }
 
-    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+    fn validate_complex(&'a self, strict: bool, py: Python<'a>) -> ValResult<ValidationMatch<EitherComplex<'a>>> {
+        // JSON has no complex type, so strings, `[real, imag]` arrays and `{"real": .., "imag": ..}` objects
+        // are all accepted in strict mode
         match self {
-            JsonValue::Float(f) => create_decimal(PyString::new(py, &f.to_string()), self, py),
-
-            JsonValue::Str(..) | JsonValue::Int(..) | JsonValue::BigInt(..) => {
-                create_decimal(self.to_object(py).into_ref(py), self, py)
+            JsonValue::Str(s) => str_as_complex(py, self, s).map(ValidationMatch::strict),
+            JsonValue::Array(array) => match array.as_slice() {
+                [real, imag] => match (json_as_f64(real), json_as_f64(imag)) {
+                    (Some(real), Some(imag)) => Ok(ValidationMatch::strict(EitherComplex::Complex([real, imag]))),
+                    _ => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
+                },
+                _ => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
+            },
+            JsonValue::Object(object) if object.len() == 2 => {
+                match (
+                    object.get("real").and_then(json_as_f64),
+                    object.get("imag").and_then(json_as_f64),
+                ) {
+                    (Some(real), Some(imag)) => Ok(ValidationMatch::strict(EitherComplex::Complex([real, imag]))),
+                    _ => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
+                }
             }
+            JsonValue::Float(f) if !strict => Ok(ValidationMatch::lax(EitherComplex::Complex([*f, 0.0]))),
+            JsonValue::Int(i) if !strict => Ok(ValidationMatch::lax(EitherComplex::Complex([*i as f64, 0.0]))),
+            _ => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
+        }
+    }
+
+    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<EitherDecimal<'a>> {
+        match self {
+            JsonValue::Float(f) => create_decimal_from_str(&f.to_string(), self, py),
+            JsonValue::Str(s) => create_decimal_from_str(s, self, py),
+            JsonValue::Int(i) => create_decimal_from_str(&i.to_string(), self, py),
+            JsonValue::BigInt(b) => create_decimal_from_str(&b.to_string(), self, py),
             _ => Err(ValError::new(ErrorTypeDefaults::DecimalType, self)),
         }
     }
@@ -245,9 +276,9 @@ impl<'a> Input<'a> for JsonValue {
         }
     }
 
-    fn validate_date(&self, _strict: bool) -> ValResult<ValidationMatch<EitherDate>> {
+    fn validate_date(&self, _strict: bool, timestamp_unit: TimestampUnit) -> ValResult<ValidationMatch<EitherDate>> {
         match self {
-            JsonValue::Str(v) => bytes_as_date(self, v.as_bytes()).map(ValidationMatch::strict),
+            JsonValue::Str(v) => bytes_as_date(self, v.as_bytes(), timestamp_unit).map(ValidationMatch::strict),
             _ => Err(ValError::new(ErrorTypeDefaults::DateType, self)),
         }
     }
@@ -255,13 +286,13 @@ impl<'a> Input<'a> for JsonValue {
         &self,
         strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        timestamp_unit: TimestampUnit,
     ) -> ValResult<ValidationMatch<EitherTime>> {
         match self {
-            JsonValue::Str(v) => {
-                bytes_as_time(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
-            }
-            JsonValue::Int(v) if !strict => int_as_time(self, *v, 0).map(ValidationMatch::lax),
-            JsonValue::Float(v) if !strict => float_as_time(self, *v).map(ValidationMatch::lax),
+            JsonValue::Str(v) => bytes_as_time(self, v.as_bytes(), microseconds_overflow_behavior, timestamp_unit)
+                .map(ValidationMatch::strict),
+            JsonValue::Int(v) if !strict => int_as_time(self, *v, timestamp_unit).map(ValidationMatch::lax),
+            JsonValue::Float(v) if !strict => float_as_time(self, *v, timestamp_unit).map(ValidationMatch::lax),
             JsonValue::BigInt(_) if !strict => Err(ValError::new(
                 ErrorType::TimeParsing {
                     error: Cow::Borrowed(
@@ -281,13 +312,18 @@ impl<'a> Input<'a> for JsonValue {
         &self,
         strict: bool,
         microseconds_overflow_behavior: speedate::MicrosecondsPrecisionOverflowBehavior,
+        timestamp_unit: TimestampUnit,
     ) -> ValResult<ValidationMatch<EitherDateTime>> {
         match self {
-            JsonValue::Str(v) => {
-                bytes_as_datetime(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
-            }
-            JsonValue::Int(v) if !strict => int_as_datetime(self, *v, 0).map(ValidationMatch::lax),
-            JsonValue::Float(v) if !strict => float_as_datetime(self, *v).map(ValidationMatch::lax),
+            JsonValue::Str(v) => bytes_as_datetime(self, v.as_bytes(), microseconds_overflow_behavior, timestamp_unit)
+                .map(ValidationMatch::strict),
+            JsonValue::Int(v) if !strict => int_as_datetime(self, *v, timestamp_unit).map(ValidationMatch::lax),
+            JsonValue::Float(v) if !strict => float_as_datetime(self, *v, timestamp_unit).map(ValidationMatch::lax),
+            // nanosecond timestamps have 19 digits and may be parsed as big ints even though they fit in an `i64`
+            JsonValue::BigInt(b) if !strict => match i64::try_from(b) {
+                Ok(v) => int_as_datetime(self, v, timestamp_unit).map(ValidationMatch::lax),
+                Err(_) => Err(ValError::new(ErrorTypeDefaults::DatetimeType, self)),
+            },
             _ => Err(ValError::new(ErrorTypeDefaults::DatetimeType, self)),
         }
     }
@@ -296,16 +332,18 @@ impl<'a> Input<'a> for JsonValue {
         &self,
         strict: bool,
         microseconds_overflow_behavior: speedate::MicrosecondsPrecisionOverflowBehavior,
+        duration_parsing: DurationParsing,
     ) -> ValResult<ValidationMatch<EitherTimedelta>> {
         match self {
             JsonValue::Str(v) => {
-                bytes_as_timedelta(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
+                bytes_as_timedelta(self, v.as_bytes(), microseconds_overflow_behavior, duration_parsing)
+                    .map(ValidationMatch::strict)
             }
             JsonValue::Int(v) if !strict => {
-                int_as_duration(self, *v).map(|duration| ValidationMatch::lax(duration.into()))
+                int_as_duration(self, *v, duration_parsing.unit).map(|duration| ValidationMatch::lax(duration.into()))
             }
             JsonValue::Float(v) if !strict => {
-                float_as_duration(self, *v).map(|duration| ValidationMatch::lax(duration.into()))
+                float_as_duration(self, *v, duration_parsing.unit).map(|duration| ValidationMatch::lax(duration.into()))
             }
             _ => Err(ValError::new(ErrorTypeDefaults::TimeDeltaType, self)),
         }
@@ -373,8 +411,11 @@ impl<'a> Input<'a> for String {
         Ok(ValidationMatch::strict(self.as_str().into()))
     }
 
-    fn validate_bytes(&'a self, _strict: bool) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
-        Ok(ValidationMatch::strict(self.as_bytes().into()))
+    fn validate_bytes(&'a self, _strict: bool, mode: ValBytesMode) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
+        match mode.deserialize_string(self) {
+            Ok(b) => Ok(ValidationMatch::strict(b)),
+            Err(e) => Err(ValError::new(e, self)),
+        }
     }
 
     fn validate_bool(&self, _strict: bool) -> ValResult<ValidationMatch<bool>> {
@@ -392,8 +433,12 @@ impl<'a> Input<'a> for String {
         str_as_float(self, self).map(ValidationMatch::lax)
     }
 
-    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
-        create_decimal(self.to_object(py).into_ref(py), self, py)
+    fn validate_complex(&'a self, _strict: bool, py: Python<'a>) -> ValResult<ValidationMatch<EitherComplex<'a>>> {
+        str_as_complex(py, self, self).map(ValidationMatch::strict)
+    }
+
+    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<EitherDecimal<'a>> {
+        create_decimal_from_str(self, self, py)
     }
 
     #[cfg_attr(has_coverage_attribute, coverage(off))]
@@ -429,32 +474,37 @@ impl<'a> Input<'a> for String {
         Ok(string_to_vec(self).into())
     }
 
-    fn validate_date(&self, _strict: bool) -> ValResult<ValidationMatch<EitherDate>> {
-        bytes_as_date(self, self.as_bytes()).map(ValidationMatch::lax)
+    fn validate_date(&self, _strict: bool, timestamp_unit: TimestampUnit) -> ValResult<ValidationMatch<EitherDate>> {
+        bytes_as_date(self, self.as_bytes(), timestamp_unit).map(ValidationMatch::lax)
     }
 
     fn validate_time(
         &self,
         _strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        timestamp_unit: TimestampUnit,
     ) -> ValResult<ValidationMatch<EitherTime>> {
-        bytes_as_time(self, self.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::lax)
+        bytes_as_time(self, self.as_bytes(), microseconds_overflow_behavior, timestamp_unit).map(ValidationMatch::lax)
     }
 
     fn validate_datetime(
         &self,
         _strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        timestamp_unit: TimestampUnit,
     ) -> ValResult<ValidationMatch<EitherDateTime>> {
-        bytes_as_datetime(self, self.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::lax)
+        bytes_as_datetime(self, self.as_bytes(), microseconds_overflow_behavior, timestamp_unit)
+            .map(ValidationMatch::lax)
     }
 
     fn validate_timedelta(
         &self,
         _strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        duration_parsing: DurationParsing,
     ) -> ValResult<ValidationMatch<EitherTimedelta>> {
-        bytes_as_timedelta(self, self.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::lax)
+        bytes_as_timedelta(self, self.as_bytes(), microseconds_overflow_behavior, duration_parsing)
+            .map(ValidationMatch::lax)
     }
 }
 
@@ -472,6 +522,157 @@ impl BorrowInput for String {
     }
 }
 
+/// The value of a JSON number as a float, used for the parts of a complex number.
+fn json_as_f64(value: &JsonValue) -> Option<f64> {
+    match value {
+        JsonValue::Float(f) => Some(*f),
+        JsonValue::Int(i) => Some(*i as f64),
+        _ => None,
+    }
+}
+
 fn string_to_vec(s: &str) -> JsonArray {
     JsonArray::new(s.chars().map(|c| JsonValue::Str(c.to_string())).collect())
 }
+
+#[derive(Clone, Copy)]
+enum PartialContainer {
+    Array,
+    Object { expect_key: bool },
+}
+
+/// Complete JSON data which may have been truncated, e.g. because it's being streamed.
+///
+/// Unterminated string values are closed, incomplete keys, literals and numbers are dropped along with any
+/// dangling `,` or `:`, and finally all open arrays and objects are closed. Complete data is returned unchanged,
+/// invalid (as opposed to truncated) data is also returned as is so that it's reported by the JSON parser.
+pub fn complete_partial_json(data: &[u8]) -> Cow<'_, [u8]> {
+    let mut stack: Vec<PartialContainer> = Vec::new();
+    // the length of `data` which can be kept as is, with the open containers closed after it
+    let mut safe_len = 0;
+    // whether the data ends with an unterminated string value which should be closed
+    let mut closing_quote = false;
+    let mut index = 0;
+
+    while index < data.len() {
+        match data[index] {
+            b' ' | b'\t' | b'\n' | b'\r' => index += 1,
+            b'[' => {
+                stack.push(PartialContainer::Array);
+                index += 1;
+                safe_len = index;
+            }
+            b'{' => {
+                stack.push(PartialContainer::Object { expect_key: true });
+                index += 1;
+                safe_len = index;
+            }
+            b']' | b'}' => {
+                stack.pop();
+                index += 1;
+                safe_len = index;
+            }
+            b',' => {
+                if let Some(PartialContainer::Object { expect_key }) = stack.last_mut() {
+                    *expect_key = true;
+                }
+                index += 1;
+            }
+            b':' => {
+                if let Some(PartialContainer::Object { expect_key }) = stack.last_mut() {
+                    *expect_key = false;
+                }
+                index += 1;
+            }
+            b'"' => {
+                let is_key = matches!(stack.last(), Some(PartialContainer::Object { expect_key: true }));
+                match string_end(data, index + 1) {
+                    Ok(end) => {
+                        index = end;
+                        if !is_key {
+                            safe_len = index;
+                        }
+                    }
+                    Err(valid_end) => {
+                        if !is_key {
+                            safe_len = valid_end;
+                            closing_quote = true;
+                        }
+                        break;
+                    }
+                }
+            }
+            _ => {
+                let start = index;
+                while index < data.len() && !b" \t\n\r,:[]{}\"".contains(&data[index]) {
+                    index += 1;
+                }
+                if index < data.len() || is_complete_scalar(&data[start..]) {
+                    safe_len = index;
+                }
+            }
+        }
+    }
+
+    if stack.is_empty() && !closing_quote {
+        return Cow::Borrowed(data);
+    }
+
+    let mut completed = data[..safe_len].to_vec();
+    if closing_quote {
+        completed.push(b'"');
+    }
+    completed.extend(stack.iter().rev().map(|container| match container {
+        PartialContainer::Array => b']',
+        PartialContainer::Object { .. } => b'}',
+    }));
+    Cow::Owned(completed)
+}
+
+/// Find the end of the string starting at `start` (just after the opening quote), if the string is unterminated,
+/// return the length of data which may be kept without splitting an escape sequence or a UTF-8 character.
+fn string_end(data: &[u8], start: usize) -> Result<usize, usize> {
+    let mut index = start;
+    let mut valid_end = start;
+    while index < data.len() {
+        match data[index] {
+            b'"' => return Ok(index + 1),
+            b'\\' => {
+                let escape_len = if data.get(index + 1) == Some(&b'u') { 6 } else { 2 };
+                if index + escape_len > data.len() {
+                    break;
+                }
+                index += escape_len;
+            }
+            _ => index += 1,
+        }
+        valid_end = index;
+    }
+    // don't cut a multi-byte character in half
+    let valid_end = match std::str::from_utf8(&data[start..valid_end]) {
+        Ok(_) => valid_end,
+        Err(e) if e.error_len().is_none() => start + e.valid_up_to(),
+        Err(_) => valid_end,
+    };
+    Err(valid_end)
+}
+
+/// Whether a literal or number which runs to the end of the data is complete.
+fn is_complete_scalar(scalar: &[u8]) -> bool {
+    match scalar {
+        b"true" | b"false" | b"null" | b"NaN" | b"Infinity" | b"-Infinity" => true,
+        _ => {
+            let digits = scalar.strip_prefix(b"-").unwrap_or(scalar);
+            let mut parts = digits.splitn(2, |b| *b == b'e' || *b == b'E');
+            let mantissa = parts.next().unwrap_or_default();
+            let mut mantissa_parts = mantissa.splitn(2, |b| *b == b'.');
+            let all_digits = |s: &[u8]| !s.is_empty() && s.iter().all(u8::is_ascii_digit);
+            let int_ok = mantissa_parts.next().is_some_and(all_digits);
+            let fraction_ok = mantissa_parts.next().is_none_or(all_digits);
+            let exponent_ok = parts
+                .next()
+                .is_none_or(|e| all_digits(e.strip_prefix(b"-").or_else(|| e.strip_prefix(b"+")).unwrap_or(e)));
+            int_ok && fraction_ok && exponent_ok
+        }
+    }
+}
@@ -3,8 +3,8 @@ use std::str::from_utf8;
 
 use pyo3::prelude::*;
 use pyo3::types::{
-    PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDict, PyFloat, PyFrozenSet, PyInt, PyIterator, PyList,
-    PyMapping, PySequence, PySet, PyString, PyTime, PyTuple, PyType,
+    PyBool, PyByteArray, PyBytes, PyComplex, PyDate, PyDateTime, PyDict, PyFloat, PyFrozenSet, PyInt, PyIterator,
+    PyList, PyMapping, PySequence, PySet, PyString, PyTime, PyTuple, PyType,
 };
 #[cfg(not(PyPy))]
 use pyo3::types::{PyDictItems, PyDictKeys, PyDictValues};
@@ -14,22 +14,24 @@ use speedate::MicrosecondsPrecisionOverflowBehavior;
 
 use crate::errors::{AsLocItem, ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
 use crate::tools::{extract_i64, safe_repr};
-use crate::validators::decimal::{create_decimal, get_decimal_type};
+use crate::validators::bytes::ValBytesMode;
+use crate::validators::decimal::{create_decimal, create_decimal_from_str, get_decimal_type};
 use crate::validators::Exactness;
 use crate::{ArgsKwargs, PyMultiHostUrl, PyUrl};
 
 use super::datetime::{
     bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, date_as_datetime, float_as_datetime,
-    float_as_duration, float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime,
-    EitherTime,
+    float_as_duration, float_as_time, int_as_datetime, int_as_duration, int_as_time, DurationParsing, EitherDate,
+    EitherDateTime, EitherTime, TimestampUnit,
 };
 use super::return_enums::ValidationMatch;
 use super::shared::{
-    decimal_as_int, float_as_int, get_enum_meta_object, int_as_bool, str_as_bool, str_as_float, str_as_int,
+    decimal_as_int, float_as_int, get_enum_meta_object, int_as_bool, str_as_bool, str_as_complex, str_as_float,
+    str_as_int,
 };
 use super::{
-    py_string_str, BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericArguments,
-    GenericIterable, GenericIterator, GenericMapping, Input, PyArgs,
+    py_string_str, BorrowInput, EitherBytes, EitherComplex, EitherDecimal, EitherFloat, EitherInt, EitherString,
+    EitherTimedelta, GenericArguments, GenericIterable, GenericIterator, GenericMapping, Input, PyArgs,
 };
 
 #[cfg(not(PyPy))]
@@ -131,6 +133,10 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
+    fn input_is_exact_instance(&self, class: &PyType) -> bool {
+        self.is_exact_instance(class)
+    }
+
     fn is_python(&self) -> bool {
         true
     }
@@ -260,7 +266,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn validate_bytes(&'a self, strict: bool) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
+    fn validate_bytes(&'a self, strict: bool, _mode: ValBytesMode) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
         if let Ok(py_bytes) = self.downcast_exact::<PyBytes>() {
             return Ok(ValidationMatch::exact(py_bytes.into()));
         } else if let Ok(py_bytes) = self.downcast::<PyBytes>() {
@@ -331,7 +337,7 @@ impl<'a> Input<'a> for PyAny {
                     str_as_int(self, &cow_str)
                 } else if self.is_exact_instance_of::<PyFloat>() {
                     float_as_int(self, self.extract::<f64>()?)
-                } else if let Ok(decimal) = self.strict_decimal(self.py()) {
+                } else if let Ok(EitherDecimal::Py(decimal)) = self.strict_decimal(self.py()) {
                     decimal_as_int(self.py(), self, decimal)
                 } else if let Ok(float) = self.extract::<f64>() {
                     float_as_int(self, float)
@@ -374,17 +380,44 @@ impl<'a> Input<'a> for PyAny {
         Err(ValError::new(ErrorTypeDefaults::FloatType, self))
     }
 
-    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+    fn validate_complex(&'a self, strict: bool, py: Python<'a>) -> ValResult<ValidationMatch<EitherComplex<'a>>> {
+        if let Ok(complex) = self.downcast::<PyComplex>() {
+            return if self.is_exact_instance_of::<PyComplex>() {
+                Ok(ValidationMatch::exact(EitherComplex::Py(complex)))
+            } else {
+                // subclasses are upcast to `complex`
+                Ok(ValidationMatch::strict(EitherComplex::Complex([
+                    complex.real(),
+                    complex.imag(),
+                ])))
+            };
+        }
+
+        if !strict {
+            if let Some(cow_str) = maybe_as_string(self, ErrorTypeDefaults::ComplexStrParsing)? {
+                return str_as_complex(py, self, &cow_str).map(ValidationMatch::lax);
+            }
+            if !self.is_instance_of::<PyBool>() {
+                if let Ok(float) = self.extract::<f64>() {
+                    return Ok(ValidationMatch::lax(EitherComplex::Complex([float, 0.0])));
+                }
+            }
+        }
+
+        Err(ValError::new(ErrorTypeDefaults::ComplexType, self))
+    }
+
+    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<EitherDecimal<'a>> {
         let decimal_type_obj: Py<PyType> = get_decimal_type(py);
         let decimal_type = decimal_type_obj.as_ref(py);
         // Fast path for existing decimal objects
         if self.is_exact_instance(decimal_type) {
-            return Ok(self);
+            return Ok(EitherDecimal::Py(self));
         }
 
         // Try subclasses of decimals, they will be upcast to Decimal
         if self.is_instance(decimal_type)? {
-            return create_decimal(self, self, py);
+            return create_decimal(self, self, py).map(EitherDecimal::Py);
         }
 
         Err(ValError::new(
@@ -396,22 +429,28 @@ impl<'a> Input<'a> for PyAny {
         ))
     }
 
-    fn lax_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+    fn lax_decimal(&'a self, py: Python<'a>) -> ValResult<EitherDecimal<'a>> {
         let decimal_type_obj: Py<PyType> = get_decimal_type(py);
         let decimal_type = decimal_type_obj.as_ref(py);
         // Fast path for existing decimal objects
         if self.is_exact_instance(decimal_type) {
-            return Ok(self);
+            return Ok(EitherDecimal::Py(self));
         }
 
-        if self.is_instance_of::<PyString>() || (self.is_instance_of::<PyInt>() && !self.is_instance_of::<PyBool>()) {
+        if let Ok(py_str) = self.downcast::<PyString>() {
+            // strings are parsed in Rust, anything unusual is left to `Decimal` itself
+            match py_str.to_str() {
+                Ok(s) => create_decimal_from_str(s, self, py),
+                Err(_) => create_decimal(self, self, py).map(EitherDecimal::Py),
+            }
+        } else if self.is_instance_of::<PyInt>() && !self.is_instance_of::<PyBool>() {
             // checking isinstance for str / int / bool is fast compared to decimal / float
-            create_decimal(self, self, py)
+            create_decimal(self, self, py).map(EitherDecimal::Py)
         } else if self.is_instance(decimal_type)? {
             // upcast subclasses to decimal
-            return create_decimal(self, self, py);
+            create_decimal(self, self, py).map(EitherDecimal::Py)
         } else if self.is_instance_of::<PyFloat>() {
-            create_decimal(self.str()?, self, py)
+            create_decimal_from_str(self.str()?.to_str()?, self, py)
         } else {
             Err(ValError::new(ErrorTypeDefaults::DecimalType, self))
         }
@@ -588,7 +627,7 @@ impl<'a> Input<'a> for PyAny {
         }
     }
 
-    fn validate_date(&self, strict: bool) -> ValResult<ValidationMatch<EitherDate>> {
+    fn validate_date(&self, strict: bool, timestamp_unit: TimestampUnit) -> ValResult<ValidationMatch<EitherDate>> {
         if let Ok(date) = self.downcast_exact::<PyDate>() {
             Ok(ValidationMatch::exact(date.into()))
         } else if PyDateTime::is_type_of(self) {
@@ -609,7 +648,7 @@ impl<'a> Input<'a> for PyAny {
                 None
             }
         } {
-            bytes_as_date(self, bytes).map(ValidationMatch::lax)
+            bytes_as_date(self, bytes, timestamp_unit).map(ValidationMatch::lax)
         } else {
             Err(ValError::new(ErrorTypeDefaults::DateType, self))
         }
@@ -619,6 +658,7 @@ impl<'a> Input<'a> for PyAny {
         &self,
         strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        timestamp_unit: TimestampUnit,
     ) -> ValResult<ValidationMatch<EitherTime>> {
         if let Ok(time) = self.downcast_exact::<PyTime>() {
             return Ok(ValidationMatch::exact(time.into()));
@@ -630,15 +670,20 @@ impl<'a> Input<'a> for PyAny {
             if !strict {
                 return if let Ok(py_str) = self.downcast::<PyString>() {
                     let str = py_string_str(py_str)?;
-                    bytes_as_time(self, str.as_bytes(), microseconds_overflow_behavior)
+                    bytes_as_time(self, str.as_bytes(), microseconds_overflow_behavior, timestamp_unit)
                 } else if let Ok(py_bytes) = self.downcast::<PyBytes>() {
-                    bytes_as_time(self, py_bytes.as_bytes(), microseconds_overflow_behavior)
+                    bytes_as_time(
+                        self,
+                        py_bytes.as_bytes(),
+                        microseconds_overflow_behavior,
+                        timestamp_unit,
+                    )
                 } else if PyBool::is_exact_type_of(self) {
                     Err(ValError::new(ErrorTypeDefaults::TimeType, self))
                 } else if let Ok(int) = extract_i64(self) {
-                    int_as_time(self, int, 0)
+                    int_as_time(self, int, timestamp_unit)
                 } else if let Ok(float) = self.extract::<f64>() {
-                    float_as_time(self, float)
+                    float_as_time(self, float, timestamp_unit)
                 } else {
                     break 'lax;
                 }
@@ -653,6 +698,7 @@ impl<'a> Input<'a> for PyAny {
         &self,
         strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        timestamp_unit: TimestampUnit,
     ) -> ValResult<ValidationMatch<EitherDateTime>> {
         if let Ok(dt) = self.downcast_exact::<PyDateTime>() {
             return Ok(ValidationMatch::exact(dt.into()));
@@ -664,15 +710,20 @@ impl<'a> Input<'a> for PyAny {
             if !strict {
                 return if let Ok(py_str) = self.downcast::<PyString>() {
                     let str = py_string_str(py_str)?;
-                    bytes_as_datetime(self, str.as_bytes(), microseconds_overflow_behavior)
+                    bytes_as_datetime(self, str.as_bytes(), microseconds_overflow_behavior, timestamp_unit)
                 } else if let Ok(py_bytes) = self.downcast::<PyBytes>() {
-                    bytes_as_datetime(self, py_bytes.as_bytes(), microseconds_overflow_behavior)
+                    bytes_as_datetime(
+                        self,
+                        py_bytes.as_bytes(),
+                        microseconds_overflow_behavior,
+                        timestamp_unit,
+                    )
                 } else if PyBool::is_exact_type_of(self) {
                     Err(ValError::new(ErrorTypeDefaults::DatetimeType, self))
                 } else if let Ok(int) = extract_i64(self) {
-                    int_as_datetime(self, int, 0)
+                    int_as_datetime(self, int, timestamp_unit)
                 } else if let Ok(float) = self.extract::<f64>() {
-                    float_as_datetime(self, float)
+                    float_as_datetime(self, float, timestamp_unit)
                 } else if let Ok(date) = self.downcast::<PyDate>() {
                     Ok(date_as_datetime(date)?)
                 } else {
@@ -689,6 +740,7 @@ impl<'a> Input<'a> for PyAny {
         &self,
         strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        duration_parsing: DurationParsing,
     ) -> ValResult<ValidationMatch<EitherTimedelta>> {
         if let Ok(either_dt) = EitherTimedelta::try_from(self) {
             let exactness = if matches!(either_dt, EitherTimedelta::PyExact(_)) {
@@ -703,13 +755,18 @@ impl<'a> Input<'a> for PyAny {
             if !strict {
                 return if let Ok(py_str) = self.downcast::<PyString>() {
                     let str = py_string_str(py_str)?;
-                    bytes_as_timedelta(self, str.as_bytes(), microseconds_overflow_behavior)
+                    bytes_as_timedelta(self, str.as_bytes(), microseconds_overflow_behavior, duration_parsing)
                 } else if let Ok(py_bytes) = self.downcast::<PyBytes>() {
-                    bytes_as_timedelta(self, py_bytes.as_bytes(), microseconds_overflow_behavior)
+                    bytes_as_timedelta(
+                        self,
+                        py_bytes.as_bytes(),
+                        microseconds_overflow_behavior,
+                        duration_parsing,
+                    )
                 } else if let Ok(int) = extract_i64(self) {
-                    Ok(int_as_duration(self, int)?.into())
+                    Ok(int_as_duration(self, int, duration_parsing.unit)?.into())
                 } else if let Ok(float) = self.extract::<f64>() {
-                    Ok(float_as_duration(self, float)?.into())
+                    Ok(float_as_duration(self, float, duration_parsing.unit)?.into())
                 } else {
                     break 'lax;
                 }
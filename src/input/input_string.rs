@@ -6,15 +6,17 @@ use speedate::MicrosecondsPrecisionOverflowBehavior;
 use crate::errors::{AsLocItem, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
 use crate::input::py_string_str;
 use crate::tools::safe_repr;
-use crate::validators::decimal::create_decimal;
+use crate::validators::bytes::ValBytesMode;
+use crate::validators::decimal::{create_decimal, create_decimal_from_str};
 
 use super::datetime::{
-    bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, EitherDate, EitherDateTime, EitherTime,
+    bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, DurationParsing, EitherDate, EitherDateTime,
+    EitherTime, TimestampUnit,
 };
-use super::shared::{str_as_bool, str_as_float};
+use super::shared::{str_as_bool, str_as_complex, str_as_float};
 use super::{
-    BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericArguments, GenericIterable,
-    GenericIterator, GenericMapping, Input, ValidationMatch,
+    BorrowInput, EitherBytes, EitherComplex, EitherDecimal, EitherFloat, EitherInt, EitherString, EitherTimedelta,
+    GenericArguments, GenericIterable, GenericIterator, GenericMapping, Input, ValidationMatch,
 };
 
 #[derive(Debug)]
@@ -96,9 +98,12 @@ impl<'a> Input<'a> for StringMapping<'a> {
         }
     }
 
-    fn validate_bytes(&'a self, _strict: bool) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
+    fn validate_bytes(&'a self, _strict: bool, mode: ValBytesMode) -> ValResult<ValidationMatch<EitherBytes<'a>>> {
         match self {
-            Self::String(s) => py_string_str(s).map(|b| ValidationMatch::strict(b.as_bytes().into())),
+            Self::String(s) => py_string_str(s).and_then(|b| match mode.deserialize_string(b) {
+                Ok(b) => Ok(ValidationMatch::strict(b)),
+                Err(e) => Err(ValError::new(e, self)),
+            }),
             Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::BytesType, self)),
         }
     }
@@ -127,9 +132,19 @@ impl<'a> Input<'a> for StringMapping<'a> {
         }
     }
 
-    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<&'a PyAny> {
+    fn validate_complex(&'a self, _strict: bool, py: Python<'a>) -> ValResult<ValidationMatch<EitherComplex<'a>>> {
         match self {
-            Self::String(s) => create_decimal(s, self, py),
+            Self::String(s) => str_as_complex(py, self, py_string_str(s)?).map(ValidationMatch::strict),
+            Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
+        }
+    }
+
+    fn strict_decimal(&'a self, py: Python<'a>) -> ValResult<EitherDecimal<'a>> {
+        match self {
+            Self::String(s) => match s.to_str() {
+                Ok(str) => create_decimal_from_str(str, self, py),
+                Err(_) => create_decimal(s, self, py).map(EitherDecimal::Py),
+            },
             Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::DecimalType, self)),
         }
     }
@@ -165,9 +180,11 @@ impl<'a> Input<'a> for StringMapping<'a> {
         Err(ValError::new(ErrorTypeDefaults::IterableType, self))
     }
 
-    fn validate_date(&self, _strict: bool) -> ValResult<ValidationMatch<EitherDate>> {
+    fn validate_date(&self, _strict: bool, timestamp_unit: TimestampUnit) -> ValResult<ValidationMatch<EitherDate>> {
         match self {
-            Self::String(s) => bytes_as_date(self, py_string_str(s)?.as_bytes()).map(ValidationMatch::strict),
+            Self::String(s) => {
+                bytes_as_date(self, py_string_str(s)?.as_bytes(), timestamp_unit).map(ValidationMatch::strict)
+            }
             Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::DateType, self)),
         }
     }
@@ -176,10 +193,16 @@ impl<'a> Input<'a> for StringMapping<'a> {
         &self,
         _strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        timestamp_unit: TimestampUnit,
     ) -> ValResult<ValidationMatch<EitherTime>> {
         match self {
-            Self::String(s) => bytes_as_time(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior)
-                .map(ValidationMatch::strict),
+            Self::String(s) => bytes_as_time(
+                self,
+                py_string_str(s)?.as_bytes(),
+                microseconds_overflow_behavior,
+                timestamp_unit,
+            )
+            .map(ValidationMatch::strict),
             Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::TimeType, self)),
         }
     }
@@ -188,10 +211,16 @@ impl<'a> Input<'a> for StringMapping<'a> {
         &self,
         _strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        timestamp_unit: TimestampUnit,
     ) -> ValResult<ValidationMatch<EitherDateTime>> {
         match self {
-            Self::String(s) => bytes_as_datetime(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior)
-                .map(ValidationMatch::strict),
+            Self::String(s) => bytes_as_datetime(
+                self,
+                py_string_str(s)?.as_bytes(),
+                microseconds_overflow_behavior,
+                timestamp_unit,
+            )
+            .map(ValidationMatch::strict),
             Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::DatetimeType, self)),
         }
     }
@@ -200,10 +229,16 @@ impl<'a> Input<'a> for StringMapping<'a> {
         &self,
         _strict: bool,
         microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+        duration_parsing: DurationParsing,
     ) -> ValResult<ValidationMatch<EitherTimedelta>> {
         match self {
-            Self::String(s) => bytes_as_timedelta(self, py_string_str(s)?.as_bytes(), microseconds_overflow_behavior)
-                .map(ValidationMatch::strict),
+            Self::String(s) => bytes_as_timedelta(
+                self,
+                py_string_str(s)?.as_bytes(),
+                microseconds_overflow_behavior,
+                duration_parsing,
+            )
+            .map(ValidationMatch::strict),
             Self::Mapping(_) => Err(ValError::new(ErrorTypeDefaults::TimeDeltaType, self)),
         }
     }
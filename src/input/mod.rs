@@ -3,6 +3,8 @@ use std::os::raw::c_int;
 use pyo3::prelude::*;
 
 mod datetime;
+mod datetime_format;
+mod decimal;
 mod input_abstract;
 mod input_json;
 mod input_python;
@@ -12,15 +14,18 @@ mod shared;
 
 pub use datetime::TzInfo;
 pub(crate) use datetime::{
-    duration_as_pytimedelta, pydate_as_date, pydatetime_as_datetime, pytime_as_time, EitherDate, EitherDateTime,
-    EitherTime, EitherTimedelta,
+    get_zoneinfo, get_zoneinfo_type, pydate_as_date, pydatetime_as_datetime, pytime_as_time, DurationParsing,
+    DurationUnit, EitherDate, EitherDateTime, EitherTime, EitherTimedelta, TimestampUnit,
 };
+pub(crate) use datetime_format::DateTimeFormat;
+pub(crate) use decimal::{DecimalValue, EitherDecimal};
 pub(crate) use input_abstract::{BorrowInput, Input, InputType};
+pub(crate) use input_json::complete_partial_json;
 pub(crate) use input_string::StringMapping;
 pub(crate) use return_enums::{
-    py_string_str, AttributesGenericIterator, DictGenericIterator, EitherBytes, EitherFloat, EitherInt, EitherString,
-    GenericArguments, GenericIterable, GenericIterator, GenericMapping, Int, JsonArgs, JsonObjectGenericIterator,
-    MappingGenericIterator, PyArgs, StringMappingGenericIterator, ValidationMatch,
+    py_string_str, AttributesGenericIterator, DictGenericIterator, EitherBytes, EitherComplex, EitherFloat, EitherInt,
+    EitherString, GenericArguments, GenericIterable, GenericIterator, GenericMapping, Int, JsonArgs,
+    JsonObjectGenericIterator, MappingGenericIterator, PyArgs, StringMappingGenericIterator, ValidationMatch,
 };
 
 // Defined here as it's not exported by pyo3
@@ -11,8 +11,8 @@ use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
 use pyo3::types::iter::PyDictIterator;
 use pyo3::types::{
-    PyByteArray, PyBytes, PyDict, PyFloat, PyFrozenSet, PyIterator, PyList, PyMapping, PySequence, PySet, PyString,
-    PyTuple,
+    PyByteArray, PyBytes, PyComplex, PyDict, PyFloat, PyFrozenSet, PyIterator, PyList, PyMapping, PySequence, PySet,
+    PyString, PyTuple,
 };
 use pyo3::{ffi, intern, PyNativeType};
 
@@ -193,19 +193,26 @@ fn validate_iter_to_vec<'a, 's>(
     mut max_length_check: MaxLengthCheck<'a, impl Input<'a>>,
     validator: &'s CombinedValidator,
     state: &mut ValidationState,
+    fail_fast: bool,
+    partial_last_index: Option<usize>,
 ) -> ValResult<Vec<PyObject>> {
     let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
     let mut errors: Vec<ValLineError> = Vec::new();
     for (index, item_result) in iter.enumerate() {
         let item = item_result.map_err(|e| any_next_error!(py, e, max_length_check.input, index))?;
-        match validator.validate(py, item, state) {
+        let is_last_partial = partial_last_index == Some(index);
+        match state.with_allow_partial(is_last_partial, |state| validator.validate(py, item, state)) {
             Ok(item) => {
                 max_length_check.incr()?;
                 output.push(item);
             }
+            Err(ValError::LineErrors(_)) if is_last_partial => (),
             Err(ValError::LineErrors(line_errors)) => {
                 max_length_check.incr()?;
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                if fail_fast {
+                    break;
+                }
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
@@ -259,11 +266,14 @@ fn validate_iter_to_set<'a, 's>(
     max_length: Option<usize>,
     validator: &'s CombinedValidator,
     state: &mut ValidationState,
+    fail_fast: bool,
+    partial_last_index: Option<usize>,
 ) -> ValResult<()> {
     let mut errors: Vec<ValLineError> = Vec::new();
     for (index, item_result) in iter.enumerate() {
         let item = item_result.map_err(|e| any_next_error!(py, e, input, index))?;
-        match validator.validate(py, item, state) {
+        let is_last_partial = partial_last_index == Some(index);
+        match state.with_allow_partial(is_last_partial, |state| validator.validate(py, item, state)) {
             Ok(item) => {
                 set.build_add(item)?;
                 if let Some(max_length) = max_length {
@@ -283,8 +293,12 @@ fn validate_iter_to_set<'a, 's>(
                     }
                 }
             }
+            Err(ValError::LineErrors(_)) if is_last_partial => (),
             Err(ValError::LineErrors(line_errors)) => {
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+                if fail_fast {
+                    break;
+                }
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
@@ -339,6 +353,15 @@ impl<'a> GenericIterable<'a> {
         }
     }
 
+    /// Index of the last item, which may be incomplete when validating partial input.
+    fn partial_last_index(&self, state: &ValidationState) -> Option<usize> {
+        if state.extra().allow_partial {
+            self.generic_len().and_then(|len| len.checked_sub(1))
+        } else {
+            None
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn validate_to_vec<'s>(
         &'s self,
@@ -348,14 +371,25 @@ impl<'a> GenericIterable<'a> {
         field_type: &'static str,
         validator: &'s CombinedValidator,
         state: &mut ValidationState,
+        fail_fast: bool,
     ) -> ValResult<Vec<PyObject>> {
         let actual_length = self.generic_len();
         let capacity = actual_length.unwrap_or(DEFAULT_CAPACITY);
         let max_length_check = MaxLengthCheck::new(max_length, field_type, input, actual_length);
+        let partial_last_index = self.partial_last_index(state);
 
         macro_rules! validate {
             ($iter:expr) => {
-                validate_iter_to_vec(py, $iter, capacity, max_length_check, validator, state)
+                validate_iter_to_vec(
+                    py,
+                    $iter,
+                    capacity,
+                    max_length_check,
+                    validator,
+                    state,
+                    fail_fast,
+                    partial_last_index,
+                )
             };
         }
 
@@ -381,10 +415,24 @@ impl<'a> GenericIterable<'a> {
         field_type: &'static str,
         validator: &'s CombinedValidator,
         state: &mut ValidationState,
+        fail_fast: bool,
     ) -> ValResult<()> {
+        let partial_last_index = self.partial_last_index(state);
+
         macro_rules! validate_set {
             ($iter:expr) => {
-                validate_iter_to_set(py, set, $iter, input, field_type, max_length, validator, state)
+                validate_iter_to_set(
+                    py,
+                    set,
+                    $iter,
+                    input,
+                    field_type,
+                    max_length,
+                    validator,
+                    state,
+                    fail_fast,
+                    partial_last_index,
+                )
             };
         }
 
@@ -969,6 +1017,22 @@ impl<'a> IntoPy<PyObject> for EitherFloat<'a> {
     }
 }
 
+#[cfg_attr(debug_assertions, derive(Debug))]
+#[derive(Copy, Clone)]
+pub enum EitherComplex<'a> {
+    Complex([f64; 2]),
+    Py(&'a PyComplex),
+}
+
+impl IntoPy<PyObject> for EitherComplex<'_> {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::Complex([real, imag]) => PyComplex::from_doubles(py, real, imag).into_py(py),
+            Self::Py(complex) => complex.into_py(py),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 #[serde(untagged)]
 pub enum Int {
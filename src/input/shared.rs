@@ -1,11 +1,12 @@
 use pyo3::sync::GILOnceCell;
-use pyo3::{intern, Py, PyAny, Python, ToPyObject};
+use pyo3::types::PyComplex;
+use pyo3::{intern, Py, PyAny, PyTypeInfo, Python, ToPyObject};
 
 use num_bigint::BigInt;
 
 use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
 
-use super::{EitherFloat, EitherInt, Input};
+use super::{EitherComplex, EitherFloat, EitherInt, Input};
 static ENUM_META_OBJECT: GILOnceCell<Py<PyAny>> = GILOnceCell::new();
 
 pub fn get_enum_meta_object(py: Python) -> Py<PyAny> {
@@ -105,6 +106,14 @@ pub fn str_as_float<'s, 'l>(input: &'s impl Input<'s>, str: &'l str) -> ValResul
     }
 }
 
+/// parse a string as a complex number using the same rules as python's `complex()`, e.g. `1+2j`
+pub fn str_as_complex<'s>(py: Python<'s>, input: &'s impl Input<'s>, str: &str) -> ValResult<EitherComplex<'s>> {
+    PyComplex::type_object(py)
+        .call1((str,))
+        .and_then(|complex| Ok(EitherComplex::Py(complex.downcast()?)))
+        .map_err(|_| ValError::new(ErrorTypeDefaults::ComplexStrParsing, input))
+}
+
 /// parse a string as an int, `input` is required here to get lifetimes to match up
 ///
 fn _parse_str<'s, 'l>(_input: &'s impl Input<'s>, str: &'l str, len: usize) -> Option<EitherInt<'s>> {
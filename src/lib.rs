@@ -32,16 +32,18 @@ pub use errors::{
     list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
 };
 pub use serializers::{
-    to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
+    to_json, to_json_stream, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue,
+    SchemaSerializer, WarningsMode,
 };
 pub use validators::{validate_core_schema, PySome, SchemaValidator};
 
 use crate::input::Input;
+use crate::validators::bytes::ValBytesMode;
 
 #[pyfunction(signature = (data, *, allow_inf_nan=true, cache_strings=true))]
 pub fn from_json(py: Python, data: &PyAny, allow_inf_nan: bool, cache_strings: bool) -> PyResult<PyObject> {
     let v_match = data
-        .validate_bytes(false)
+        .validate_bytes(false, ValBytesMode::Utf8)
         .map_err(|_| PyTypeError::new_err("Expected bytes, bytearray or str"))?;
     let json_either_bytes = v_match.into_inner();
     let json_bytes = json_either_bytes.as_slice();
@@ -107,6 +109,7 @@ fn _pydantic_core(py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<SchemaSerializer>()?;
     m.add_class::<TzInfo>()?;
     m.add_function(wrap_pyfunction!(to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(to_json_stream, m)?)?;
     m.add_function(wrap_pyfunction!(from_json, m)?)?;
     m.add_function(wrap_pyfunction!(to_jsonable_python, m)?)?;
     m.add_function(wrap_pyfunction!(list_all_errors, m)?)?;
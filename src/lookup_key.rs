@@ -8,7 +8,7 @@ use pyo3::types::{PyDict, PyList, PyMapping, PyString};
 use jiter::{JsonObject, JsonValue};
 
 use crate::build_tools::py_schema_err;
-use crate::errors::{py_err_string, ErrorType, ValError, ValLineError, ValResult};
+use crate::errors::{py_err_string, ErrorType, Location, ValError, ValLineError, ValResult};
 use crate::input::{Input, StringMapping};
 use crate::tools::{extract_i64, py_err};
 
@@ -312,14 +312,27 @@ impl LookupKey {
         field_name: &str,
     ) -> ValLineError {
         if loc_by_alias {
-            let lookup_path = match self {
-                Self::Simple { path, .. } => path,
-                Self::Choice { path1, .. } => path1,
-                Self::PathChoices(paths) => paths.first().unwrap(),
-            };
-            ValLineError::new_with_full_loc(error_type, input, lookup_path.into())
+            let lookup_path = self.first_path();
+            let mut line_error = ValLineError::new_with_full_loc(error_type, input, lookup_path.into());
+            if !lookup_path.is_field_name(field_name) {
+                line_error.field_location = Some(Location::new_some(field_name.to_string().into()));
+            }
+            line_error
         } else {
-            ValLineError::new_with_loc(error_type, input, field_name.to_string())
+            let mut line_error = ValLineError::new_with_loc(error_type, input, field_name.to_string());
+            let lookup_path = self.first_path();
+            if !lookup_path.is_field_name(field_name) {
+                line_error.input_location = Some(lookup_path.into());
+            }
+            line_error
+        }
+    }
+
+    fn first_path(&self) -> &LookupPath {
+        match self {
+            Self::Simple { path, .. } => path,
+            Self::Choice { path1, .. } => path1,
+            Self::PathChoices(paths) => paths.first().unwrap(),
         }
     }
 }
@@ -365,15 +378,34 @@ impl LookupPath {
 
     pub fn apply_error_loc(&self, mut line_error: ValLineError, loc_by_alias: bool, field_name: &str) -> ValLineError {
         if loc_by_alias {
+            if self.is_field_name(field_name) {
+                return line_error.with_outer_location(field_name.to_string().into());
+            }
+            let mut field_location = line_error
+                .field_location
+                .take()
+                .unwrap_or_else(|| line_error.location.clone());
+            field_location.with_outer(field_name.to_string().into());
             for path_item in self.iter().rev() {
                 line_error = line_error.with_outer_location(path_item.clone().into());
             }
+            line_error.field_location = Some(field_location);
             line_error
-        } else {
+        } else if self.is_field_name(field_name) {
             line_error.with_outer_location(field_name.to_string().into())
+        } else {
+            for path_item in self.iter().rev() {
+                line_error = line_error.with_outer_input_location(path_item.clone().into());
+            }
+            line_error.with_outer_label(field_name.to_string().into())
         }
     }
 
+    /// whether this path is just the field name, i.e. the field has no alias
+    fn is_field_name(&self, field_name: &str) -> bool {
+        matches!(self.0.as_slice(), [PathItem::S(key, _)] if key == field_name)
+    }
+
     pub fn iter(&self) -> Iter<PathItem> {
         self.0.iter()
     }
@@ -3,37 +3,48 @@ use std::str::{from_utf8, FromStr, Utf8Error};
 
 use base64::Engine;
 use pyo3::prelude::*;
-use pyo3::types::{PyDelta, PyDict};
+use pyo3::types::{PyDateTime, PyDelta, PyDict};
 use pyo3::{intern, PyNativeType};
 
 use serde::ser::Error;
 
 use crate::build_tools::py_schema_err;
-use crate::input::EitherTimedelta;
+use crate::input::{pydatetime_as_datetime, EitherTimedelta, TimestampUnit};
 use crate::tools::SchemaDict;
 
 use super::errors::py_err_se_err;
+use super::type_serializers::datetime_etc::{datetime_to_string, datetime_to_timestamp};
 
 #[derive(Debug, Clone)]
+#[allow(clippy::struct_field_names)]
 pub(crate) struct SerializationConfig {
     pub timedelta_mode: TimedeltaMode,
     pub bytes_mode: BytesMode,
+    pub datetime_mode: DatetimeMode,
 }
 
 impl SerializationConfig {
     pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
         let timedelta_mode = TimedeltaMode::from_config(config)?;
         let bytes_mode = BytesMode::from_config(config)?;
+        let datetime_mode = DatetimeMode::from_config(config)?;
         Ok(Self {
             timedelta_mode,
             bytes_mode,
+            datetime_mode,
         })
     }
 
-    pub fn from_args(timedelta_mode: &str, bytes_mode: &str) -> PyResult<Self> {
+    pub fn from_args(
+        timedelta_mode: &str,
+        bytes_mode: &str,
+        datetime_mode: &str,
+        timestamp_unit: Option<&str>,
+    ) -> PyResult<Self> {
         Ok(Self {
             timedelta_mode: TimedeltaMode::from_str(timedelta_mode)?,
             bytes_mode: BytesMode::from_str(bytes_mode)?,
+            datetime_mode: DatetimeMode::new(Some(datetime_mode), timestamp_unit)?,
         })
     }
 }
@@ -124,6 +135,96 @@ impl TimedeltaMode {
     }
 }
 
+/// How `datetime` values are serialized to JSON, set by `ser_json_datetime` and `ser_json_timestamp_unit`.
+#[derive(Default, Debug, Clone, Copy)]
+pub(crate) enum DatetimeMode {
+    #[default]
+    Iso8601,
+    /// Unix timestamps, as integers in the given unit or as float seconds if no unit is set
+    Timestamp(Option<TimestampUnit>),
+}
+
+impl DatetimeMode {
+    /// Combine the mode and the timestamp unit, setting a unit without a mode implies `timestamp`.
+    /// `timestamp_ms` is shorthand for `timestamp` in milliseconds.
+    pub fn new(mode: Option<&str>, timestamp_unit: Option<&str>) -> PyResult<Self> {
+        let unit = timestamp_unit.map(Self::timestamp_unit).transpose()?;
+        match (mode, unit) {
+            (None | Some("iso8601"), None) => Ok(Self::Iso8601),
+            (Some("iso8601"), Some(_)) => {
+                py_schema_err!("A timestamp unit cannot be used with the `iso8601` datetime serialization mode")
+            }
+            (None | Some("timestamp"), unit) => Ok(Self::Timestamp(unit)),
+            (Some("timestamp_ms"), None | Some(TimestampUnit::Milliseconds)) => {
+                Ok(Self::Timestamp(Some(TimestampUnit::Milliseconds)))
+            }
+            (Some("timestamp_ms"), Some(_)) => py_schema_err!(
+                "The `timestamp_ms` datetime serialization mode can only be used with the `ms` timestamp unit"
+            ),
+            (Some(s), _) => py_schema_err!(
+                "Invalid datetime serialization mode: `{}`, expected `iso8601`, `timestamp` or `timestamp_ms`",
+                s
+            ),
+        }
+    }
+
+    pub fn from_config(config: Option<&PyDict>) -> PyResult<Self> {
+        let Some(config_dict) = config else {
+            return Ok(Self::default());
+        };
+        let py = config_dict.py();
+        let raw_mode = config_dict.get_as::<&str>(intern!(py, "ser_json_datetime"))?;
+        let raw_unit = config_dict.get_as::<&str>(intern!(py, "ser_json_timestamp_unit"))?;
+        Self::new(raw_mode, raw_unit)
+    }
+
+    /// Parse the unit of serialized timestamps, which unlike validation can't be inferred
+    pub fn timestamp_unit(raw_unit: &str) -> PyResult<TimestampUnit> {
+        match TimestampUnit::from_str(raw_unit)? {
+            TimestampUnit::Auto => {
+                py_schema_err!("Invalid serialization timestamp unit: `auto`, expected `s`, `ms`, `us` or `ns`")
+            }
+            unit => Ok(unit),
+        }
+    }
+
+    /// Seconds since the epoch including the fractional part, naive datetimes are treated as UTC
+    fn timestamp_seconds(py_dt: &PyDateTime) -> PyResult<f64> {
+        let dt = pydatetime_as_datetime(py_dt)?;
+        Ok(dt.timestamp_tz() as f64 + f64::from(dt.time.microsecond) / 1_000_000.0)
+    }
+
+    pub fn datetime_to_json(self, py: Python, py_dt: &PyDateTime) -> PyResult<PyObject> {
+        match self {
+            Self::Iso8601 => Ok(datetime_to_string(py_dt)?.into_py(py)),
+            Self::Timestamp(None) => Ok(Self::timestamp_seconds(py_dt)?.into_py(py)),
+            Self::Timestamp(Some(unit)) => Ok(datetime_to_timestamp(py_dt, unit)?.into_py(py)),
+        }
+    }
+
+    pub fn json_key<'py>(self, py_dt: &PyDateTime) -> PyResult<Cow<'py, str>> {
+        match self {
+            Self::Iso8601 => Ok(datetime_to_string(py_dt)?.into()),
+            Self::Timestamp(None) => Ok(Self::timestamp_seconds(py_dt)?.to_string().into()),
+            Self::Timestamp(Some(unit)) => Ok(datetime_to_timestamp(py_dt, unit)?.to_string().into()),
+        }
+    }
+
+    pub fn datetime_serialize<S: serde::ser::Serializer>(
+        self,
+        py_dt: &PyDateTime,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Iso8601 => serializer.serialize_str(&datetime_to_string(py_dt).map_err(py_err_se_err)?),
+            Self::Timestamp(None) => serializer.serialize_f64(Self::timestamp_seconds(py_dt).map_err(py_err_se_err)?),
+            Self::Timestamp(Some(unit)) => {
+                serializer.serialize_i64(datetime_to_timestamp(py_dt, unit).map_err(py_err_se_err)?)
+            }
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone)]
 pub(crate) enum BytesMode {
     #[default]
@@ -14,6 +14,17 @@ pub(super) fn py_err_se_err<T: ser::Error, E: fmt::Display>(py_error: E) -> T {
     T::custom(py_error.to_string())
 }
 
+// as `py_err_se_err`, but `PydanticSerializationUnexpectedValue` errors are marked so they're raised unchanged
+pub(super) fn py_unexpected_err_se_err<T: ser::Error>(py: Python, py_error: PyErr) -> T {
+    match py_error.value(py).extract::<PydanticSerializationUnexpectedValue>() {
+        Ok(ser_err) => T::custom(format!(
+            "{UNEXPECTED_TYPE_SER_MARKER}{}",
+            ser_err.message.unwrap_or_default()
+        )),
+        Err(_) => py_err_se_err(py_error),
+    }
+}
+
 #[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
 pub struct PythonSerializerError {
@@ -109,7 +120,7 @@ impl PydanticSerializationUnexpectedValue {
         Self { message }
     }
 
-    fn __str__(&self) -> &str {
+    pub(crate) fn __str__(&self) -> &str {
         match self.message {
             Some(ref s) => s,
             None => "Unexpected Value",
@@ -4,12 +4,14 @@ use std::fmt;
 use pyo3::exceptions::PyValueError;
 use pyo3::intern;
 use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyString};
 
 use serde::ser::Error;
 
 use super::config::SerializationConfig;
 use super::errors::{PydanticSerializationUnexpectedValue, UNEXPECTED_TYPE_SER_MARKER};
 use super::ob_type::ObTypeLookup;
+use crate::errors::LocItem;
 use crate::recursion_guard::RecursionGuard;
 
 /// this is ugly, would be much better if extra could be stored in `SerializationState`
@@ -21,10 +23,15 @@ pub(crate) struct SerializationState {
 }
 
 impl SerializationState {
-    pub fn new(timedelta_mode: &str, bytes_mode: &str) -> PyResult<Self> {
-        let warnings = CollectWarnings::new(false);
+    pub fn new(
+        timedelta_mode: &str,
+        bytes_mode: &str,
+        datetime_mode: &str,
+        timestamp_unit: Option<&str>,
+    ) -> PyResult<Self> {
+        let warnings = CollectWarnings::new(WarningsMode::None);
         let rec_guard = SerRecursionGuard::default();
-        let config = SerializationConfig::from_args(timedelta_mode, bytes_mode)?;
+        let config = SerializationConfig::from_args(timedelta_mode, bytes_mode, datetime_mode, timestamp_unit)?;
         Ok(Self {
             warnings,
             rec_guard,
@@ -42,6 +49,9 @@ impl SerializationState {
         round_trip: bool,
         serialize_unknown: bool,
         fallback: Option<&'py PyAny>,
+        sort_keys: bool,
+        canonical: bool,
+        context: Option<&'py PyAny>,
     ) -> Extra<'py> {
         Extra::new(
             py,
@@ -56,6 +66,10 @@ impl SerializationState {
             &self.rec_guard,
             serialize_unknown,
             fallback,
+            false,
+            sort_keys,
+            canonical,
+            context,
         )
     }
 
@@ -87,6 +101,14 @@ pub(crate) struct Extra<'a> {
     pub field_name: Option<&'a str>,
     pub serialize_unknown: bool,
     pub fallback: Option<&'a PyAny>,
+    /// serialize model and dataclass instances using their own `__pydantic_serializer__` rather than the schema's
+    pub serialize_as_any: bool,
+    /// order object keys and set members when serializing to JSON so output is deterministic
+    pub sort_keys: bool,
+    /// write RFC 8785 canonical JSON: sorted keys, no whitespace and ECMAScript number formatting
+    pub canonical: bool,
+    /// context provided by the caller, passed through to function serializers via `SerializationInfo`
+    pub context: Option<&'a PyAny>,
 }
 
 impl<'a> Extra<'a> {
@@ -104,6 +126,10 @@ impl<'a> Extra<'a> {
         rec_guard: &'a SerRecursionGuard,
         serialize_unknown: bool,
         fallback: Option<&'a PyAny>,
+        serialize_as_any: bool,
+        sort_keys: bool,
+        canonical: bool,
+        context: Option<&'a PyAny>,
     ) -> Self {
         Self {
             mode,
@@ -121,6 +147,10 @@ impl<'a> Extra<'a> {
             field_name: None,
             serialize_unknown,
             fallback,
+            serialize_as_any,
+            sort_keys,
+            canonical,
+            context,
         }
     }
 
@@ -163,6 +193,10 @@ pub(crate) struct ExtraOwned {
     field_name: Option<String>,
     serialize_unknown: bool,
     fallback: Option<PyObject>,
+    serialize_as_any: bool,
+    sort_keys: bool,
+    canonical: bool,
+    context: Option<PyObject>,
 }
 
 impl ExtraOwned {
@@ -182,6 +216,10 @@ impl ExtraOwned {
             field_name: extra.field_name.map(ToString::to_string),
             serialize_unknown: extra.serialize_unknown,
             fallback: extra.fallback.map(Into::into),
+            serialize_as_any: extra.serialize_as_any,
+            sort_keys: extra.sort_keys,
+            canonical: extra.canonical,
+            context: extra.context.map(Into::into),
         }
     }
 
@@ -202,6 +240,10 @@ impl ExtraOwned {
             field_name: self.field_name.as_deref(),
             serialize_unknown: self.serialize_unknown,
             fallback: self.fallback.as_ref().map(|m| m.as_ref(py)),
+            serialize_as_any: self.serialize_as_any,
+            sort_keys: self.sort_keys,
+            canonical: self.canonical,
+            context: self.context.as_ref().map(|m| m.as_ref(py)),
         }
     }
 }
@@ -251,24 +293,105 @@ impl ToPyObject for SerMode {
     }
 }
 
+/// How values which don't match the schema are reported, set with the `warnings` argument.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WarningsMode {
+    None,
+    Warn,
+    Error,
+}
+
+impl FromPyObject<'_> for WarningsMode {
+    fn extract(ob: &PyAny) -> PyResult<Self> {
+        if let Ok(bool_mode) = ob.downcast::<PyBool>() {
+            Ok(if bool_mode.is_true() { Self::Warn } else { Self::None })
+        } else if let Ok(str_mode) = ob.downcast::<PyString>() {
+            match str_mode.to_str()? {
+                "none" => Ok(Self::None),
+                "warn" => Ok(Self::Warn),
+                "error" => Ok(Self::Error),
+                s => Err(PyValueError::new_err(format!(
+                    "Invalid warnings parameter `{s}`, expected `none`, `warn`, `error` or a boolean"
+                ))),
+            }
+        } else {
+            Err(PyValueError::new_err(format!(
+                "Invalid warnings parameter `{ob}`, expected `none`, `warn`, `error` or a boolean"
+            )))
+        }
+    }
+}
+
+/// An item in the path to the value being serialized, reported with unexpected values.
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) enum PathItem {
+    Key(PyObject),
+    Index(usize),
+}
+
+/// Removes the item added by `CollectWarnings::enter_key` or `CollectWarnings::enter_index` when dropped.
+#[must_use]
+pub(crate) struct PathGuard<'a>(Option<&'a RefCell<Vec<PathItem>>>);
+
+impl Drop for PathGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(path) = self.0 {
+            path.borrow_mut().pop();
+        }
+    }
+}
+
 #[derive(Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub(crate) struct CollectWarnings {
-    active: bool,
+    mode: WarningsMode,
     warnings: RefCell<Option<Vec<String>>>,
+    path: RefCell<Vec<PathItem>>,
 }
 
 impl CollectWarnings {
-    pub(crate) fn new(active: bool) -> Self {
+    pub(crate) fn new(mode: WarningsMode) -> Self {
         Self {
-            active,
+            mode,
             warnings: RefCell::new(None),
+            path: RefCell::new(Vec::new()),
         }
     }
 
-    pub fn custom_warning(&self, warning: String) {
-        if self.active {
-            self.add_warning(warning);
+    /// Add a dict key or field name to the path until the returned guard is dropped.
+    pub fn enter_key(&self, key: &PyAny) -> PathGuard<'_> {
+        self.enter(|| PathItem::Key(key.into()))
+    }
+
+    /// Add a list or tuple index to the path until the returned guard is dropped.
+    pub fn enter_index(&self, index: usize) -> PathGuard<'_> {
+        self.enter(|| PathItem::Index(index))
+    }
+
+    fn enter(&self, item: impl FnOnce() -> PathItem) -> PathGuard<'_> {
+        // the path is only used in messages, so don't bother tracking it when they're disabled
+        if self.mode == WarningsMode::None {
+            PathGuard(None)
+        } else {
+            self.path.borrow_mut().push(item());
+            PathGuard(Some(&self.path))
+        }
+    }
+
+    /// Report a `PydanticSerializationUnexpectedValue` raised by a function serializer.
+    pub fn custom_warning(&self, py: Python, ser_err: &PydanticSerializationUnexpectedValue) -> PyResult<()> {
+        match self.mode {
+            WarningsMode::None => Ok(()),
+            WarningsMode::Warn => {
+                let message = self.with_path(py, ser_err.__repr__());
+                self.add_warning(message);
+                Ok(())
+            }
+            WarningsMode::Error => {
+                let message = self.with_path(py, ser_err.__str__().to_string());
+                Err(PydanticSerializationUnexpectedValue::new_err(Some(message)))
+            }
         }
     }
 
@@ -279,8 +402,10 @@ impl CollectWarnings {
         } else if extra.check.enabled() {
             Err(PydanticSerializationUnexpectedValue::new_err(None))
         } else {
-            self.fallback_warning(field_type, value);
-            Ok(())
+            match self.fallback_warning(field_type, value) {
+                Some(message) => Err(PydanticSerializationUnexpectedValue::new_err(Some(message))),
+                None => Ok(()),
+            }
         }
     }
 
@@ -296,21 +421,53 @@ impl CollectWarnings {
         } else if extra.check.enabled() {
             // note: I think this should never actually happen since we use `to_python(..., mode='json')` during
             // JSON serialisation to "try" union branches, but it's here for completeness/correctness
-            // in particular, in future we could allow errors instead of warnings on fallback
             Err(S::Error::custom(UNEXPECTED_TYPE_SER_MARKER))
         } else {
-            self.fallback_warning(field_type, value);
-            Ok(())
+            match self.fallback_warning(field_type, value) {
+                Some(message) => Err(S::Error::custom(format!("{UNEXPECTED_TYPE_SER_MARKER}{message}"))),
+                None => Ok(()),
+            }
+        }
+    }
+
+    /// Record a warning for an unexpected value, or in `error` mode return the message to raise.
+    fn fallback_warning(&self, field_type: &str, value: &PyAny) -> Option<String> {
+        if self.mode == WarningsMode::None {
+            return None;
+        }
+        let type_name = value.get_type().name().unwrap_or("<unknown python object>");
+        let message = self.with_path(value.py(), format!("Expected `{field_type}` but got `{type_name}`"));
+        let message = format!("{message} - serialized value may not be as expected");
+        if self.mode == WarningsMode::Error {
+            Some(message)
+        } else {
+            self.add_warning(message);
+            None
         }
     }
 
-    fn fallback_warning(&self, field_type: &str, value: &PyAny) {
-        if self.active {
-            let type_name = value.get_type().name().unwrap_or("<unknown python object>");
-            self.add_warning(format!(
-                "Expected `{field_type}` but got `{type_name}` - serialized value may not be as expected"
-            ));
+    /// Append the path to the value being serialized to `message`, if we're inside a container.
+    fn with_path(&self, py: Python, message: String) -> String {
+        let path = self.path.borrow();
+        if path.is_empty() {
+            return message;
         }
+        let loc = path
+            .iter()
+            .map(|item| match item {
+                PathItem::Key(key) => {
+                    let key = key.as_ref(py);
+                    match key.downcast::<PyString>() {
+                        Ok(py_str) => LocItem::S(py_str.to_string_lossy().into_owned()),
+                        Err(_) => LocItem::S(key.to_string()),
+                    }
+                }
+                PathItem::Index(index) => LocItem::I(i64::try_from(*index).unwrap_or(i64::MAX)),
+            })
+            .map(|loc_item| loc_item.to_string())
+            .collect::<Vec<_>>()
+            .join(".");
+        format!("{message} at `{loc}`")
     }
 
     fn add_warning(&self, message: String) {
@@ -323,7 +480,7 @@ impl CollectWarnings {
     }
 
     pub fn final_check(&self, py: Python) -> PyResult<()> {
-        if self.active {
+        if self.mode == WarningsMode::Warn {
             match *self.warnings.borrow() {
                 Some(ref warnings) => {
                     let message = format!("Pydantic serializer warnings:\n  {}", warnings.join("\n  "));
@@ -1,12 +1,16 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
 use ahash::AHashMap;
 use serde::ser::SerializeMap;
 
+use crate::build_tools::py_schema_err;
+use crate::py_gc::PyGcTraverse;
 use crate::serializers::extra::SerCheck;
+use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::computed_fields::ComputedFields;
@@ -26,9 +30,10 @@ pub(super) struct SerField {
     // None serializer means exclude
     pub serializer: Option<CombinedSerializer>,
     pub required: bool,
+    pub exclude_if: Option<ExcludeIf>,
 }
 
-impl_py_gc_traverse!(SerField { serializer });
+impl_py_gc_traverse!(SerField { serializer, exclude_if });
 
 impl SerField {
     pub fn new(
@@ -37,6 +42,7 @@ impl SerField {
         alias: Option<String>,
         serializer: Option<CombinedSerializer>,
         required: bool,
+        exclude_if: Option<ExcludeIf>,
     ) -> Self {
         let alias_py = alias.as_ref().map(|alias| PyString::new(py, alias.as_str()).into());
         Self {
@@ -45,6 +51,7 @@ impl SerField {
             alias_py,
             serializer,
             required,
+            exclude_if,
         }
     }
 
@@ -67,15 +74,74 @@ impl SerField {
     }
 }
 
-fn exclude_default(value: &PyAny, extra: &Extra, serializer: &CombinedSerializer) -> PyResult<bool> {
-    if extra.exclude_defaults {
-        if let Some(default) = serializer.get_default(value.py())? {
-            if value.eq(default)? {
-                return Ok(true);
-            }
+/// per-field predicate from `serialization_exclude_if`, fields are omitted from the output when it holds
+#[derive(Debug, Clone)]
+pub(super) enum ExcludeIf {
+    None,
+    Falsy,
+    Default,
+    Empty,
+    Callable(PyObject),
+}
+
+impl ExcludeIf {
+    pub fn from_field_info(field_info: &PyDict) -> PyResult<Option<Self>> {
+        let py = field_info.py();
+        let Some(exclude_if) = field_info.get_as::<&PyAny>(intern!(py, "serialization_exclude_if"))? else {
+            return Ok(None);
+        };
+        if let Ok(name) = exclude_if.downcast::<PyString>() {
+            let exclude_if = match name.to_str()? {
+                "none" => Self::None,
+                "falsy" => Self::Falsy,
+                "default" => Self::Default,
+                "empty" => Self::Empty,
+                s => return py_schema_err!("Invalid serialization_exclude_if: `{}`", s),
+            };
+            Ok(Some(exclude_if))
+        } else if exclude_if.is_callable() {
+            Ok(Some(Self::Callable(exclude_if.into_py(py))))
+        } else {
+            py_schema_err!("serialization_exclude_if must be a callable or a string")
         }
     }
-    Ok(false)
+
+    fn matches(&self, value: &PyAny, serializer: &CombinedSerializer) -> PyResult<bool> {
+        match self {
+            Self::None => Ok(value.is_none()),
+            Self::Falsy => Ok(!value.is_true()?),
+            Self::Default => is_default(value, serializer),
+            // values without a length are never considered empty
+            Self::Empty => Ok(matches!(value.len(), Ok(0))),
+            Self::Callable(func) => func.call1(value.py(), (value,))?.is_true(value.py()),
+        }
+    }
+}
+
+impl PyGcTraverse for ExcludeIf {
+    fn py_gc_traverse(&self, visit: &pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        match self {
+            Self::Callable(func) => func.py_gc_traverse(visit),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn is_default(value: &PyAny, serializer: &CombinedSerializer) -> PyResult<bool> {
+    match serializer.get_default(value.py())? {
+        Some(default) => value.eq(default),
+        None => Ok(false),
+    }
+}
+
+fn exclude_field(value: &PyAny, extra: &Extra, field: &SerField, serializer: &CombinedSerializer) -> PyResult<bool> {
+    if extra.exclude_defaults && is_default(value, serializer)? {
+        return Ok(true);
+    }
+    match field.exclude_if {
+        Some(ref exclude_if) => exclude_if.matches(value, serializer),
+        None => Ok(false),
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -195,9 +261,10 @@ impl TypeSerializer for GeneralFieldsSerializer {
                 ..td_extra
             };
             if let Some((next_include, next_exclude)) = self.filter.key_filter(key, include, exclude)? {
+                let _path = extra.warnings.enter_key(key);
                 if let Some(field) = op_field {
                     if let Some(ref serializer) = field.serializer {
-                        if !exclude_default(value, &extra, serializer)? {
+                        if !exclude_field(value, &extra, field, serializer)? {
                             let value = serializer.to_python(value, next_include, next_exclude, &extra)?;
                             let output_key = field.get_key_py(output_dict.py(), &extra);
                             output_dict.set_item(output_key, value)?;
@@ -233,6 +300,7 @@ impl TypeSerializer for GeneralFieldsSerializer {
                     continue;
                 }
                 if let Some((next_include, next_exclude)) = self.filter.key_filter(key, include, exclude)? {
+                    let _path = extra.warnings.enter_key(key);
                     let value = match &self.extra_serializer {
                         Some(serializer) => serializer.to_python(value, next_include, next_exclude, extra)?,
                         None => infer_to_python(value, next_include, next_exclude, extra)?,
@@ -295,9 +363,10 @@ impl TypeSerializer for GeneralFieldsSerializer {
 
             let filter = self.filter.key_filter(key, include, exclude).map_err(py_err_se_err)?;
             if let Some((next_include, next_exclude)) = filter {
+                let _path = extra.warnings.enter_key(key);
                 if let Some(field) = self.fields.get(key_str) {
                     if let Some(ref serializer) = field.serializer {
-                        if !exclude_default(value, &extra, serializer).map_err(py_err_se_err)? {
+                        if !exclude_field(value, &extra, field, serializer).map_err(py_err_se_err)? {
                             let s = PydanticSerializer::new(value, serializer, next_include, next_exclude, &extra);
                             let output_key = field.get_key_json(key_str, &extra);
                             map.serialize_entry(&output_key, &s)?;
@@ -319,6 +388,7 @@ impl TypeSerializer for GeneralFieldsSerializer {
                 }
                 let filter = self.filter.key_filter(key, include, exclude).map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = filter {
+                    let _path = extra.warnings.enter_key(key);
                     let output_key = infer_json_key(key, &td_extra).map_err(py_err_se_err)?;
                     let s = SerializeInfer::new(value, next_include, next_exclude, &td_extra);
                     map.serialize_entry(&output_key, &s)?;
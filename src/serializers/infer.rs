@@ -7,11 +7,12 @@ use pyo3::types::{
     PyByteArray, PyBytes, PyDate, PyDateTime, PyDict, PyFrozenSet, PyIterator, PyList, PySet, PyString, PyTime, PyTuple,
 };
 
-use serde::ser::{Error, Serialize, SerializeMap, SerializeSeq, Serializer};
+use serde::ser::{Error, Serialize, SerializeMap, SerializeSeq, SerializeTupleStruct, Serializer};
 
 use crate::input::{EitherTimedelta, Int};
 use crate::serializers::errors::SERIALIZATION_ERR_MARKER;
 use crate::serializers::filter::SchemaFilter;
+use crate::serializers::ser::SORTED_SET_TOKEN;
 use crate::serializers::shared::{PydanticSerializer, TypeSerializer};
 use crate::serializers::SchemaSerializer;
 use crate::tools::{extract_i64, py_err, safe_repr};
@@ -60,7 +61,11 @@ pub(crate) fn infer_to_python_known(
             value
                 .downcast::<$t>()?
                 .iter()
-                .map(|v| infer_to_python(v, None, None, extra))
+                .enumerate()
+                .map(|(index, v)| {
+                    let _path = extra.warnings.enter_index(index);
+                    infer_to_python(v, None, None, extra)
+                })
                 .collect::<PyResult<Vec<PyObject>>>()?
         };
     }
@@ -75,6 +80,7 @@ pub(crate) fn infer_to_python_known(
             for (index, element) in py_seq.iter().enumerate() {
                 let op_next = filter.index_filter(index, include, exclude, len)?;
                 if let Some((next_include, next_exclude)) = op_next {
+                    let _path = extra.warnings.enter_index(index);
                     items.push(infer_to_python(element, next_include, next_exclude, extra)?);
                 }
             }
@@ -89,6 +95,7 @@ pub(crate) fn infer_to_python_known(
         for (k, v) in dict {
             let op_next = filter.key_filter(k, include, exclude)?;
             if let Some((next_include, next_exclude)) = op_next {
+                let _path = extra.warnings.enter_key(k);
                 let k_str = infer_json_key(k, extra)?;
                 let k = PyString::new(py, &k_str);
                 let v = infer_to_python(v, next_include, next_exclude, extra)?;
@@ -113,6 +120,10 @@ pub(crate) fn infer_to_python_known(
             extra.rec_guard,
             extra.serialize_unknown,
             extra.fallback,
+            extra.serialize_as_any,
+            extra.sort_keys,
+            extra.canonical,
+            extra.context,
         );
         serializer.serializer.to_python(value, include, exclude, &extra)
     };
@@ -125,6 +136,7 @@ pub(crate) fn infer_to_python_known(
             ObType::IntSubclass => extract_i64(value)?.into_py(py),
             ObType::FloatSubclass => value.extract::<f64>()?.into_py(py),
             ObType::Decimal => value.to_string().into_py(py),
+            ObType::Complex => super::type_serializers::complex::complex_to_string(value)?.into_py(py),
             ObType::StrSubclass => value.extract::<&str>()?.into_py(py),
             ObType::Bytes => extra
                 .config
@@ -161,8 +173,7 @@ pub(crate) fn infer_to_python_known(
             ObType::Dict => serialize_dict(value.downcast()?)?,
             ObType::Datetime => {
                 let py_dt: &PyDateTime = value.downcast()?;
-                let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(py_dt)?;
-                iso_dt.into_py(py)
+                extra.config.datetime_mode.datetime_to_json(py, py_dt)?
             }
             ObType::Date => {
                 let py_date: &PyDate = value.downcast()?;
@@ -209,6 +220,7 @@ pub(crate) fn infer_to_python_known(
                     let element = r?;
                     let op_next = filter.index_filter(index, include, exclude, None)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let _path = extra.warnings.enter_index(index);
                         items.push(infer_to_python(element, next_include, next_exclude, extra)?);
                     }
                 }
@@ -254,6 +266,7 @@ pub(crate) fn infer_to_python_known(
                 for (k, v) in dict {
                     let op_next = filter.key_filter(k, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let _path = extra.warnings.enter_key(k);
                         let v = infer_to_python(v, next_include, next_exclude, extra)?;
                         new_dict.set_item(k, v)?;
                     }
@@ -367,14 +380,32 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         ($t:ty) => {{
             let py_seq: &$t = value.downcast().map_err(py_err_se_err)?;
             let mut seq = serializer.serialize_seq(Some(py_seq.len()))?;
-            for element in py_seq {
+            for (index, element) in py_seq.iter().enumerate() {
                 let item_serializer = SerializeInfer::new(element, include, exclude, extra);
+                let _path = extra.warnings.enter_index(index);
                 seq.serialize_element(&item_serializer)?
             }
             seq.end()
         }};
     }
 
+    macro_rules! serialize_set {
+        ($t:ty) => {{
+            if extra.sort_keys {
+                let py_set: &$t = value.downcast().map_err(py_err_se_err)?;
+                let mut seq = serializer.serialize_tuple_struct(SORTED_SET_TOKEN, py_set.len())?;
+                for (index, element) in py_set.iter().enumerate() {
+                    let item_serializer = SerializeInfer::new(element, include, exclude, extra);
+                    let _path = extra.warnings.enter_index(index);
+                    seq.serialize_field(&item_serializer)?
+                }
+                seq.end()
+            } else {
+                serialize_seq!($t)
+            }
+        }};
+    }
+
     macro_rules! serialize_seq_filter {
         ($t:ty) => {{
             let py_seq: &$t = value.downcast().map_err(py_err_se_err)?;
@@ -388,6 +419,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                     .map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = op_next {
                     let item_serializer = SerializeInfer::new(element, next_include, next_exclude, extra);
+                    let _path = extra.warnings.enter_index(index);
                     seq.serialize_element(&item_serializer)?
                 }
             }
@@ -403,6 +435,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             for (key, value) in $py_dict {
                 let op_next = filter.key_filter(key, include, exclude).map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = op_next {
+                    let _path = extra.warnings.enter_key(key);
                     let key = infer_json_key(key, extra).map_err(py_err_se_err)?;
                     let value_serializer = SerializeInfer::new(value, next_include, next_exclude, extra);
                     map.serialize_entry(&key, &value_serializer)?;
@@ -418,6 +451,9 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         ObType::Bool => serialize!(bool),
         ObType::Float | ObType::FloatSubclass => serialize!(f64),
         ObType::Decimal => value.to_string().serialize(serializer),
+        ObType::Complex => super::type_serializers::complex::complex_to_string(value)
+            .map_err(py_err_se_err)?
+            .serialize(serializer),
         ObType::Str | ObType::StrSubclass => {
             let py_str: &PyString = value.downcast().map_err(py_err_se_err)?;
             super::type_serializers::string::serialize_py_str(py_str, serializer)
@@ -438,12 +474,11 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         ObType::Dict => serialize_dict!(value.downcast::<PyDict>().map_err(py_err_se_err)?),
         ObType::List => serialize_seq_filter!(PyList),
         ObType::Tuple => serialize_seq_filter!(PyTuple),
-        ObType::Set => serialize_seq!(PySet),
-        ObType::Frozenset => serialize_seq!(PyFrozenSet),
+        ObType::Set => serialize_set!(PySet),
+        ObType::Frozenset => serialize_set!(PyFrozenSet),
         ObType::Datetime => {
             let py_dt: &PyDateTime = value.downcast().map_err(py_err_se_err)?;
-            let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(py_dt).map_err(py_err_se_err)?;
-            serializer.serialize_str(&iso_dt)
+            extra.config.datetime_mode.datetime_serialize(py_dt, serializer)
         }
         ObType::Date => {
             let py_date: &PyDate = value.downcast().map_err(py_err_se_err)?;
@@ -488,6 +523,10 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                 extra.rec_guard,
                 extra.serialize_unknown,
                 extra.fallback,
+                extra.serialize_as_any,
+                extra.sort_keys,
+                extra.canonical,
+                extra.context,
             );
             let pydantic_serializer =
                 PydanticSerializer::new(value, &extracted_serializer.serializer, include, exclude, &extra);
@@ -514,6 +553,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                     .map_err(py_err_se_err)?;
                 if let Some((next_include, next_exclude)) = op_next {
                     let item_serializer = SerializeInfer::new(element, next_include, next_exclude, extra);
+                    let _path = extra.warnings.enter_index(index);
                     seq.serialize_element(&item_serializer)?;
                 }
             }
@@ -574,6 +614,7 @@ pub(crate) fn infer_json_key_known<'py>(ob_type: ObType, key: &'py PyAny, extra:
             super::type_serializers::simple::to_str_json_key(key)
         }
         ObType::Decimal => Ok(Cow::Owned(key.to_string())),
+        ObType::Complex => Ok(Cow::Owned(super::type_serializers::complex::complex_to_string(key)?)),
         ObType::Bool => super::type_serializers::simple::bool_json_key(key),
         ObType::Str | ObType::StrSubclass => {
             let py_str: &PyString = key.downcast()?;
@@ -597,8 +638,7 @@ pub(crate) fn infer_json_key_known<'py>(ob_type: ObType, key: &'py PyAny, extra:
         }
         ObType::Datetime => {
             let py_dt: &PyDateTime = key.downcast()?;
-            let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(py_dt)?;
-            Ok(Cow::Owned(iso_dt))
+            extra.config.datetime_mode.json_key(py_dt)
         }
         ObType::Date => {
             let py_date: &PyDate = key.downcast()?;
@@ -10,10 +10,11 @@ use crate::py_gc::PyGcTraverse;
 
 use config::SerializationConfig;
 pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
+pub use extra::WarningsMode;
 use extra::{CollectWarnings, SerRecursionGuard};
 pub(crate) use extra::{Extra, SerMode, SerializationState};
 pub use shared::CombinedSerializer;
-use shared::{to_json_bytes, BuildSerializer, TypeSerializer};
+use shared::{to_json_bytes, to_json_file, BuildSerializer, TypeSerializer};
 
 mod computed_fields;
 mod config;
@@ -27,6 +28,9 @@ pub mod ser;
 mod shared;
 mod type_serializers;
 
+/// default number of bytes buffered before calling `write` in `to_json_stream`
+const DEFAULT_BUFFER_SIZE: usize = 65536;
+
 #[pyclass(module = "pydantic_core._pydantic_core", frozen)]
 #[derive(Debug)]
 pub struct SchemaSerializer {
@@ -55,6 +59,10 @@ impl SchemaSerializer {
         rec_guard: &'a SerRecursionGuard,
         serialize_unknown: bool,
         fallback: Option<&'a PyAny>,
+        serialize_as_any: bool,
+        sort_keys: bool,
+        canonical: bool,
+        context: Option<&'a PyAny>,
     ) -> Extra<'b> {
         Extra::new(
             py,
@@ -69,6 +77,10 @@ impl SchemaSerializer {
             rec_guard,
             serialize_unknown,
             fallback,
+            serialize_as_any,
+            sort_keys,
+            canonical,
+            context,
         )
     }
 }
@@ -94,8 +106,8 @@ impl SchemaSerializer {
 
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = true,
-        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = true,
-        fallback = None))]
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false,
+        warnings = WarningsMode::Warn, fallback = None, serialize_as_any = false, context = None))]
     pub fn to_python(
         &self,
         py: Python,
@@ -108,8 +120,10 @@ impl SchemaSerializer {
         exclude_defaults: bool,
         exclude_none: bool,
         round_trip: bool,
-        warnings: bool,
+        warnings: WarningsMode,
         fallback: Option<&PyAny>,
+        serialize_as_any: bool,
+        context: Option<&PyAny>,
     ) -> PyResult<PyObject> {
         let mode: SerMode = mode.into();
         let warnings = CollectWarnings::new(warnings);
@@ -126,6 +140,10 @@ impl SchemaSerializer {
             &rec_guard,
             false,
             fallback,
+            serialize_as_any,
+            false,
+            false,
+            context,
         );
         let v = self.serializer.to_python(value, include, exclude, &extra)?;
         warnings.final_check(py)?;
@@ -134,8 +152,9 @@ impl SchemaSerializer {
 
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
-        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = true,
-        fallback = None))]
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false,
+        warnings = WarningsMode::Warn, fallback = None, serialize_as_any = false, sort_keys = false, canonical = false,
+        context = None))]
     pub fn to_json(
         &self,
         py: Python,
@@ -148,8 +167,12 @@ impl SchemaSerializer {
         exclude_defaults: bool,
         exclude_none: bool,
         round_trip: bool,
-        warnings: bool,
+        warnings: WarningsMode,
         fallback: Option<&PyAny>,
+        serialize_as_any: bool,
+        sort_keys: bool,
+        canonical: bool,
+        context: Option<&PyAny>,
     ) -> PyResult<PyObject> {
         let warnings = CollectWarnings::new(warnings);
         let rec_guard = SerRecursionGuard::default();
@@ -165,6 +188,10 @@ impl SchemaSerializer {
             &rec_guard,
             false,
             fallback,
+            serialize_as_any,
+            sort_keys || canonical,
+            canonical,
+            context,
         );
         let bytes = to_json_bytes(
             value,
@@ -183,6 +210,65 @@ impl SchemaSerializer {
         Ok(py_bytes.into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, fp, *, indent = None, include = None, exclude = None, by_alias = true,
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false,
+        warnings = WarningsMode::Warn, fallback = None, serialize_as_any = false, sort_keys = false, canonical = false,
+        context = None,
+        buffer_size = DEFAULT_BUFFER_SIZE))]
+    pub fn to_json_stream(
+        &self,
+        py: Python,
+        value: &PyAny,
+        fp: &PyAny,
+        indent: Option<usize>,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: WarningsMode,
+        fallback: Option<&PyAny>,
+        serialize_as_any: bool,
+        sort_keys: bool,
+        canonical: bool,
+        context: Option<&PyAny>,
+        buffer_size: usize,
+    ) -> PyResult<()> {
+        let warnings = CollectWarnings::new(warnings);
+        let rec_guard = SerRecursionGuard::default();
+        let extra = self.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            serialize_as_any,
+            sort_keys || canonical,
+            canonical,
+            context,
+        );
+        to_json_file(
+            value,
+            &self.serializer,
+            include,
+            exclude,
+            &extra,
+            indent,
+            fp,
+            buffer_size,
+        )?;
+        warnings.final_check(py)
+    }
+
     pub fn __reduce__(slf: &PyCell<Self>) -> PyResult<(PyObject, (PyObject, PyObject))> {
         // Enables support for `pickle` serialization.
         let py = slf.py();
@@ -213,7 +299,8 @@ impl SchemaSerializer {
 #[pyfunction]
 #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
     exclude_none = false, round_trip = false, timedelta_mode = "iso8601", bytes_mode = "utf8",
-    serialize_unknown = false, fallback = None))]
+    datetime_mode = "iso8601", timestamp_unit = None,
+    serialize_unknown = false, fallback = None, sort_keys = false, canonical = false, context = None))]
 pub fn to_json(
     py: Python,
     value: &PyAny,
@@ -225,10 +312,15 @@ pub fn to_json(
     round_trip: bool,
     timedelta_mode: &str,
     bytes_mode: &str,
+    datetime_mode: &str,
+    timestamp_unit: Option<&str>,
     serialize_unknown: bool,
     fallback: Option<&PyAny>,
+    sort_keys: bool,
+    canonical: bool,
+    context: Option<&PyAny>,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode)?;
+    let state = SerializationState::new(timedelta_mode, bytes_mode, datetime_mode, timestamp_unit)?;
     let extra = state.extra(
         py,
         &SerMode::Json,
@@ -237,6 +329,9 @@ pub fn to_json(
         round_trip,
         serialize_unknown,
         fallback,
+        sort_keys || canonical,
+        canonical,
+        context,
     );
     let serializer = type_serializers::any::AnySerializer.into();
     let bytes = to_json_bytes(value, &serializer, include, exclude, &extra, indent, 1024)?;
@@ -245,10 +340,57 @@ pub fn to_json(
     Ok(py_bytes.into())
 }
 
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (value, fp, *, indent = None, include = None, exclude = None, by_alias = true,
+    exclude_none = false, round_trip = false, timedelta_mode = "iso8601", bytes_mode = "utf8",
+    datetime_mode = "iso8601", timestamp_unit = None,
+    serialize_unknown = false, fallback = None, sort_keys = false, canonical = false, context = None,
+    buffer_size = DEFAULT_BUFFER_SIZE))]
+pub fn to_json_stream(
+    py: Python,
+    value: &PyAny,
+    fp: &PyAny,
+    indent: Option<usize>,
+    include: Option<&PyAny>,
+    exclude: Option<&PyAny>,
+    by_alias: bool,
+    exclude_none: bool,
+    round_trip: bool,
+    timedelta_mode: &str,
+    bytes_mode: &str,
+    datetime_mode: &str,
+    timestamp_unit: Option<&str>,
+    serialize_unknown: bool,
+    fallback: Option<&PyAny>,
+    sort_keys: bool,
+    canonical: bool,
+    context: Option<&PyAny>,
+    buffer_size: usize,
+) -> PyResult<()> {
+    let state = SerializationState::new(timedelta_mode, bytes_mode, datetime_mode, timestamp_unit)?;
+    let extra = state.extra(
+        py,
+        &SerMode::Json,
+        by_alias,
+        exclude_none,
+        round_trip,
+        serialize_unknown,
+        fallback,
+        sort_keys || canonical,
+        canonical,
+        context,
+    );
+    let serializer = type_serializers::any::AnySerializer.into();
+    to_json_file(value, &serializer, include, exclude, &extra, indent, fp, buffer_size)?;
+    state.final_check(py)
+}
+
 #[allow(clippy::too_many_arguments)]
 #[pyfunction]
 #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
-    timedelta_mode = "iso8601", bytes_mode = "utf8", serialize_unknown = false, fallback = None))]
+    timedelta_mode = "iso8601", bytes_mode = "utf8",
+    datetime_mode = "iso8601", timestamp_unit = None, serialize_unknown = false, fallback = None, context = None))]
 pub fn to_jsonable_python(
     py: Python,
     value: &PyAny,
@@ -259,10 +401,13 @@ pub fn to_jsonable_python(
     round_trip: bool,
     timedelta_mode: &str,
     bytes_mode: &str,
+    datetime_mode: &str,
+    timestamp_unit: Option<&str>,
     serialize_unknown: bool,
     fallback: Option<&PyAny>,
+    context: Option<&PyAny>,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode)?;
+    let state = SerializationState::new(timedelta_mode, bytes_mode, datetime_mode, timestamp_unit)?;
     let extra = state.extra(
         py,
         &SerMode::Json,
@@ -271,6 +416,9 @@ pub fn to_jsonable_python(
         round_trip,
         serialize_unknown,
         fallback,
+        false,
+        false,
+        context,
     );
     let v = infer::infer_to_python(value, include, exclude, &extra)?;
     state.final_check(py)?;
@@ -1,8 +1,8 @@
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
 use pyo3::types::{
-    PyBool, PyByteArray, PyBytes, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyInt, PyIterator, PyList,
-    PySet, PyString, PyTime, PyTuple, PyType,
+    PyBool, PyByteArray, PyBytes, PyComplex, PyDate, PyDateTime, PyDelta, PyDict, PyFloat, PyFrozenSet, PyInt,
+    PyIterator, PyList, PySet, PyString, PyTime, PyTuple, PyType,
 };
 use pyo3::{intern, AsPyPointer, PyTypeInfo};
 
@@ -23,6 +23,7 @@ pub struct ObTypeLookup {
     dict: usize,
     // other numeric types
     decimal_object: PyObject,
+    complex: usize,
     // other string types
     bytes: usize,
     bytearray: usize,
@@ -67,6 +68,7 @@ impl ObTypeLookup {
             list: PyList::type_object_raw(py) as usize,
             dict: PyDict::type_object_raw(py) as usize,
             decimal_object: py.import("decimal").unwrap().getattr("Decimal").unwrap().to_object(py),
+            complex: PyComplex::type_object_raw(py) as usize,
             string: PyString::type_object_raw(py) as usize,
             bytes: PyBytes::type_object_raw(py) as usize,
             bytearray: PyByteArray::type_object_raw(py) as usize,
@@ -133,6 +135,7 @@ impl ObTypeLookup {
             ObType::List => self.list == ob_type,
             ObType::Dict => self.dict == ob_type,
             ObType::Decimal => self.decimal_object.as_ptr() as usize == ob_type,
+            ObType::Complex => self.complex == ob_type,
             ObType::StrSubclass => self.string == ob_type && op_value.is_none(),
             ObType::Tuple => self.tuple == ob_type,
             ObType::Set => self.set == ob_type,
@@ -208,6 +211,8 @@ impl ObTypeLookup {
             ObType::Dict
         } else if ob_type == self.decimal_object.as_ptr() as usize {
             ObType::Decimal
+        } else if ob_type == self.complex {
+            ObType::Complex
         } else if ob_type == self.bytes {
             ObType::Bytes
         } else if ob_type == self.tuple {
@@ -291,6 +296,8 @@ impl ObTypeLookup {
             ObType::Bool
         } else if PyFloat::is_type_of(value) {
             ObType::FloatSubclass
+        } else if PyComplex::is_type_of(value) {
+            ObType::Complex
         } else if PyByteArray::is_type_of(value) {
             ObType::Bytearray
         } else if PySet::is_type_of(value) {
@@ -366,6 +373,7 @@ pub enum ObType {
     Float,
     FloatSubclass,
     Decimal,
+    Complex,
     // string types
     Str,
     StrSubclass,
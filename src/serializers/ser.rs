@@ -1,3 +1,4 @@
+use std::cmp::Ordering;
 use std::{io, num::FpCategory};
 
 use serde::{ser::Impossible, serde_if_integer128, Serialize, Serializer};
@@ -16,9 +17,14 @@ macro_rules! tri {
 
 type Result<T> = std::result::Result<T, PythonSerializerError>;
 const TOKEN: &str = "$serde_json::private::Number";
+/// Name of the tuple struct used by set serializers with `sort_keys`, the members are buffered and written
+/// ordered by their JSON rather than in hash order.
+pub(crate) const SORTED_SET_TOKEN: &str = "$pydantic_core::private::SortedSet";
 pub struct PythonSerializer<W, F = CompactFormatter> {
     writer: W,
     formatter: F,
+    sort_keys: bool,
+    utf16_key_order: bool,
 }
 
 impl<W> PythonSerializer<W>
@@ -43,6 +49,22 @@ where
     }
 }
 
+impl<W> PythonSerializer<W, CanonicalFormatter>
+where
+    W: io::Write,
+{
+    /// Creates a serializer for RFC 8785 canonical JSON, keys are sorted by their UTF-16 code units.
+    #[inline]
+    pub fn canonical(writer: W) -> Self {
+        PythonSerializer {
+            writer,
+            formatter: CanonicalFormatter,
+            sort_keys: true,
+            utf16_key_order: true,
+        }
+    }
+}
+
 impl<W, F> PythonSerializer<W, F>
 where
     W: io::Write,
@@ -52,7 +74,19 @@ where
     /// specified.
     #[inline]
     pub fn with_formatter(writer: W, formatter: F) -> Self {
-        PythonSerializer { writer, formatter }
+        PythonSerializer {
+            writer,
+            formatter,
+            sort_keys: false,
+            utf16_key_order: false,
+        }
+    }
+
+    /// Write the entries of every object ordered by key rather than in the order they're serialized.
+    #[inline]
+    pub fn with_sort_keys(mut self, sort_keys: bool) -> Self {
+        self.sort_keys = sort_keys;
+        self
     }
 
     /// Unwrap the `Writer` from the `Serializer`.
@@ -60,12 +94,107 @@ where
     pub fn into_inner(self) -> W {
         self.writer
     }
+
+    /// Serialize `value` to a separate buffer, formatted as it would be at the current position in the output.
+    fn buffered<T>(&self, value: &T, as_key: bool) -> Result<Vec<u8>>
+    where
+        T: ?Sized + Serialize,
+        F: Clone,
+    {
+        let mut ser = PythonSerializer {
+            writer: Vec::new(),
+            formatter: self.formatter.clone(),
+            sort_keys: self.sort_keys,
+            utf16_key_order: self.utf16_key_order,
+        };
+        if as_key {
+            tri!(value.serialize(MapKeySerializer { ser: &mut ser }));
+        } else {
+            tri!(value.serialize(&mut ser));
+        }
+        Ok(ser.writer)
+    }
+
+    /// Write the object entries buffered by `Compound::SortedMap` ordered by key and close the object.
+    fn end_sorted_map(&mut self, mut entries: Vec<SortedEntry>) -> Result<()> {
+        if self.utf16_key_order {
+            entries.sort_by(|a, b| a.key.encode_utf16().cmp(b.key.encode_utf16()));
+        } else {
+            entries.sort_by(|a, b| a.key.cmp(&b.key));
+        }
+        for (index, entry) in entries.iter().enumerate() {
+            tri!(self
+                .formatter
+                .begin_object_key(&mut self.writer, index == 0)
+                .and_then(|()| self.writer.write_all(&entry.key_json))
+                .and_then(|()| self.formatter.end_object_key(&mut self.writer))
+                .and_then(|()| self.formatter.begin_object_value(&mut self.writer))
+                .and_then(|()| self.writer.write_all(&entry.value_json))
+                .and_then(|()| self.formatter.end_object_value(&mut self.writer))
+                .map_err(|e| PythonSerializerError { message: e.to_string() }));
+        }
+        self.formatter
+            .end_object(&mut self.writer)
+            .map_err(|e| PythonSerializerError { message: e.to_string() })
+    }
+
+    /// Write the members buffered by `Compound::SortedSet` ordered by their JSON and close the array.
+    fn end_sorted_set(&mut self, members: Vec<Vec<u8>>) -> Result<()> {
+        let mut keyed: Vec<(Vec<u8>, Vec<u8>)> = members.into_iter().map(|json| (compact_json(&json), json)).collect();
+        keyed.sort_by(|(a, _), (b, _)| compare_json(a, b));
+        for (index, (_, json)) in keyed.iter().enumerate() {
+            tri!(self
+                .formatter
+                .begin_array_value(&mut self.writer, index == 0)
+                .and_then(|()| self.writer.write_all(json))
+                .and_then(|()| self.formatter.end_array_value(&mut self.writer))
+                .map_err(|e| PythonSerializerError { message: e.to_string() }));
+        }
+        self.formatter
+            .end_array(&mut self.writer)
+            .map_err(|e| PythonSerializerError { message: e.to_string() })
+    }
+}
+
+/// `json` without the whitespace added by pretty printing, so set members are ordered the same with any indent.
+fn compact_json(json: &[u8]) -> Vec<u8> {
+    let mut compact = Vec::with_capacity(json.len());
+    let mut in_string = false;
+    let mut escaped = false;
+    for &b in json {
+        if in_string {
+            in_string = escaped || b != b'"';
+            escaped = !escaped && b == b'\\';
+        } else if b == b'"' {
+            in_string = true;
+        } else if b.is_ascii_whitespace() {
+            continue;
+        }
+        compact.push(b);
+    }
+    compact
+}
+
+/// Numbers are compared by value so `{10, 3}` is written as `[3,10]`, everything else by its JSON.
+fn compare_json(a: &[u8], b: &[u8]) -> Ordering {
+    // all JSON numbers start with `-` or a digit, and no other value does, so numbers stay contiguous
+    let as_number = |json: &[u8]| match json.first() {
+        Some(b'-' | b'0'..=b'9') => std::str::from_utf8(json).ok()?.parse::<f64>().ok(),
+        _ => None,
+    };
+    match (as_number(a), as_number(b)) {
+        (Some(a_num), Some(b_num)) => a_num
+            .partial_cmp(&b_num)
+            .unwrap_or(Ordering::Equal)
+            .then_with(|| a.cmp(b)),
+        _ => a.cmp(b),
+    }
 }
 
 impl<'a, W, F> Serializer for &'a mut PythonSerializer<W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -306,8 +435,19 @@ where
         self.serialize_seq(Some(len))
     }
 
-    fn serialize_tuple_struct(self, _name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct> {
-        self.serialize_seq(Some(len))
+    fn serialize_tuple_struct(self, name: &'static str, len: usize) -> Result<Self::SerializeTupleStruct> {
+        if name == SORTED_SET_TOKEN {
+            tri!(self
+                .formatter
+                .begin_array(&mut self.writer)
+                .map_err(|e| PythonSerializerError { message: e.to_string() }));
+            Ok(Compound::SortedSet {
+                ser: self,
+                members: Vec::with_capacity(len),
+            })
+        } else {
+            self.serialize_seq(Some(len))
+        }
     }
 
     fn serialize_tuple_variant(
@@ -342,7 +482,12 @@ where
             .formatter
             .begin_object(&mut self.writer)
             .map_err(|e| PythonSerializerError { message: e.to_string() }));
-        if len == Some(0) {
+        if self.sort_keys {
+            Ok(Compound::SortedMap {
+                ser: self,
+                entries: Vec::with_capacity(len.unwrap_or(0)),
+            })
+        } else if len == Some(0) {
             tri!(self
                 .formatter
                 .end_object(&mut self.writer)
@@ -397,7 +542,7 @@ where
 impl<'a, W, F> serde::ser::SerializeSeq for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -421,7 +566,11 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::SortedSet { ser, members } => {
+                members.push(tri!(ser.buffered(value, false)));
+                Ok(())
+            }
+            Compound::Number { .. } | Compound::SortedMap { .. } => unreachable!(),
         }
     }
 
@@ -437,7 +586,8 @@ where
                 }
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::SortedSet { ser, members } => ser.end_sorted_set(members),
+            Compound::Number { .. } | Compound::SortedMap { .. } => unreachable!(),
         }
     }
 }
@@ -445,7 +595,7 @@ where
 impl<'a, W, F> serde::ser::SerializeTuple for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -467,7 +617,7 @@ where
 impl<'a, W, F> serde::ser::SerializeTupleStruct for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -489,7 +639,7 @@ where
 impl<'a, W, F> serde::ser::SerializeTupleVariant for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -523,7 +673,7 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::SortedMap { .. } | Compound::SortedSet { .. } => unreachable!(),
         }
     }
 }
@@ -531,7 +681,7 @@ where
 impl<'a, W, F> serde::ser::SerializeMap for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -557,7 +707,19 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::SortedMap { ser, entries } => {
+                let key_json = tri!(ser.buffered(key, true));
+                let key = tri!(
+                    serde_json::from_slice(&key_json).map_err(|e| PythonSerializerError { message: e.to_string() })
+                );
+                entries.push(SortedEntry {
+                    key,
+                    key_json,
+                    value_json: Vec::new(),
+                });
+                Ok(())
+            }
+            Compound::Number { .. } | Compound::SortedSet { .. } => unreachable!(),
         }
     }
 
@@ -579,7 +741,14 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::SortedMap { ser, entries } => {
+                let value_json = tri!(ser.buffered(value, false));
+                if let Some(entry) = entries.last_mut() {
+                    entry.value_json = value_json;
+                }
+                Ok(())
+            }
+            Compound::Number { .. } | Compound::SortedSet { .. } => unreachable!(),
         }
     }
 
@@ -596,7 +765,8 @@ where
                 }
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::SortedMap { ser, entries } => ser.end_sorted_map(entries),
+            Compound::Number { .. } | Compound::SortedSet { .. } => unreachable!(),
         }
     }
 }
@@ -604,7 +774,7 @@ where
 impl<'a, W, F> serde::ser::SerializeStruct for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -615,7 +785,9 @@ where
         T: ?Sized + Serialize,
     {
         match self {
-            Compound::Map { .. } => serde::ser::SerializeMap::serialize_entry(self, key, value),
+            Compound::Map { .. } | Compound::SortedMap { .. } => {
+                serde::ser::SerializeMap::serialize_entry(self, key, value)
+            }
             Compound::Number { ser, .. } => {
                 if key == TOKEN {
                     tri!(value.serialize(NumberStrEmitter(ser)));
@@ -624,14 +796,16 @@ where
                     Err(invalid_number())
                 }
             }
+            Compound::SortedSet { .. } => unreachable!(),
         }
     }
 
     #[inline]
     fn end(self) -> Result<()> {
         match self {
-            Compound::Map { .. } => serde::ser::SerializeMap::end(self),
+            Compound::Map { .. } | Compound::SortedMap { .. } => serde::ser::SerializeMap::end(self),
             Compound::Number { .. } => Ok(()),
+            Compound::SortedSet { .. } => unreachable!(),
         }
     }
 }
@@ -639,7 +813,7 @@ where
 impl<'a, W, F> serde::ser::SerializeStructVariant for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -650,14 +824,16 @@ where
         T: ?Sized + Serialize,
     {
         match *self {
-            Compound::Map { .. } => serde::ser::SerializeStruct::serialize_field(self, key, value),
-            Compound::Number { .. } => unreachable!(),
+            Compound::Map { .. } | Compound::SortedMap { .. } => {
+                serde::ser::SerializeStruct::serialize_field(self, key, value)
+            }
+            Compound::Number { .. } | Compound::SortedSet { .. } => unreachable!(),
         }
     }
 
     #[inline]
     fn end(self) -> Result<()> {
-        match self {
+        let ser = match self {
             Compound::Map { ser, state } => {
                 match state {
                     State::Empty => {}
@@ -666,18 +842,23 @@ where
                         .end_object(&mut ser.writer)
                         .map_err(|e| PythonSerializerError { message: e.to_string() })),
                 }
-                tri!(ser
-                    .formatter
-                    .end_object_value(&mut ser.writer)
-                    .map_err(|e| PythonSerializerError { message: e.to_string() }));
-                tri!(ser
-                    .formatter
-                    .end_object(&mut ser.writer)
-                    .map_err(|e| PythonSerializerError { message: e.to_string() }));
-                Ok(())
+                ser
             }
-            Compound::Number { .. } => unreachable!(),
-        }
+            Compound::SortedMap { ser, entries } => {
+                tri!(ser.end_sorted_map(entries));
+                ser
+            }
+            Compound::Number { .. } | Compound::SortedSet { .. } => unreachable!(),
+        };
+        tri!(ser
+            .formatter
+            .end_object_value(&mut ser.writer)
+            .map_err(|e| PythonSerializerError { message: e.to_string() }));
+        tri!(ser
+            .formatter
+            .end_object(&mut ser.writer)
+            .map_err(|e| PythonSerializerError { message: e.to_string() }));
+        Ok(())
     }
 }
 
@@ -763,6 +944,173 @@ pub enum Compound<'a, W: 'a, F: 'a> {
     Number {
         ser: &'a mut PythonSerializer<W, F>,
     },
+    /// an object whose entries are buffered until `end`, used with `sort_keys`
+    SortedMap {
+        ser: &'a mut PythonSerializer<W, F>,
+        entries: Vec<SortedEntry>,
+    },
+    /// an array of set members which are buffered until `end`, see `SORTED_SET_TOKEN`
+    SortedSet {
+        ser: &'a mut PythonSerializer<W, F>,
+        members: Vec<Vec<u8>>,
+    },
+}
+
+pub struct SortedEntry {
+    key: String,
+    key_json: Vec<u8>,
+    value_json: Vec<u8>,
+}
+
+/// Formatter for RFC 8785 (JCS) canonical JSON, output is compact and numbers are written the way
+/// ECMAScript's `Number.prototype.toString` writes them.
+/// The string escaping done by `format_escaped_str` already matches the RFC so isn't changed.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CanonicalFormatter;
+
+impl Formatter for CanonicalFormatter {
+    fn write_i64<W>(&mut self, writer: &mut W, value: i64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        write_canonical_int(writer, &value.to_string())
+    }
+
+    fn write_u64<W>(&mut self, writer: &mut W, value: u64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        write_canonical_int(writer, &value.to_string())
+    }
+
+    fn write_i128<W>(&mut self, writer: &mut W, value: i128) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        write_canonical_int(writer, &value.to_string())
+    }
+
+    fn write_u128<W>(&mut self, writer: &mut W, value: u128) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        write_canonical_int(writer, &value.to_string())
+    }
+
+    fn write_f32<W>(&mut self, writer: &mut W, value: f32) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        writer.write_all(format_es_number(f64::from(value)).as_bytes())
+    }
+
+    fn write_f64<W>(&mut self, writer: &mut W, value: f64) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        writer.write_all(format_es_number(value).as_bytes())
+    }
+
+    /// Called for integers too big for `i64` and for `NaN` and `Infinity` with `ser_json_inf_nan='constants'`.
+    fn write_number_str<W>(&mut self, writer: &mut W, value: &str) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        let unsigned = value.strip_prefix('-').unwrap_or(value);
+        if !unsigned.is_empty() && unsigned.bytes().all(|b| b.is_ascii_digit()) {
+            return write_canonical_int(writer, value);
+        }
+        match value.parse::<f64>() {
+            Ok(float) if float.is_finite() => writer.write_all(format_es_number(float).as_bytes()),
+            _ => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("`{value}` can't be represented in canonical JSON"),
+            )),
+        }
+    }
+}
+
+/// Numbers in canonical JSON are IEEE 754 doubles, so integers which a double can't hold exactly are an error
+/// rather than silently losing precision.
+fn write_canonical_int<W>(writer: &mut W, digits: &str) -> io::Result<()>
+where
+    W: ?Sized + io::Write,
+{
+    match digits.parse::<f64>() {
+        Ok(float) if format!("{float:.0}") == digits => writer.write_all(format_es_number(float).as_bytes()),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Integer {digits} can't be represented exactly in canonical JSON"),
+        )),
+    }
+}
+
+/// The shortest digits which round-trip to a positive float, and the position of the decimal point relative
+/// to the start of those digits, e.g. `("12345", -6)` for `1.2345e-7`.
+fn shortest_digits(value: f64) -> (String, i32) {
+    let (mut digits, n) = split_scientific(&format!("{value:e}"));
+    // when the two shortest candidates are equally close rust picks the larger, ECMAScript picks the even one,
+    // e.g. `1424953923781206.25` is written as `1424953923781206.2`
+    let k = digits.len();
+    let (half_digits, half_n) = split_scientific(&format!("{value:.k$e}"));
+    if half_n == n && half_digits.ends_with('5') {
+        // 767 decimal places is enough to write any double exactly
+        let (exact, _) = split_scientific(&format!("{value:.767e}"));
+        if exact.trim_end_matches('0') == half_digits {
+            let lower: u64 = half_digits[..k].parse().expect("at most 17 digits");
+            let even = (lower + lower % 2).to_string();
+            if even.len() == k && format!("0.{even}e{n}").parse::<f64>().ok() == Some(value) {
+                digits = even;
+            }
+        }
+    }
+    (digits, n)
+}
+
+/// Split rust's `{:e}` output into its digits and the position of the decimal point, as `shortest_digits`.
+fn split_scientific(scientific: &str) -> (String, i32) {
+    let (mantissa, exponent) = scientific.split_once('e').expect("`{:e}` output contains an exponent");
+    let exponent: i32 = exponent.parse().expect("`{:e}` exponent is an integer");
+    (mantissa.replace('.', ""), exponent + 1)
+}
+
+/// Format a finite float as ECMAScript's `Number.prototype.toString` does, see RFC 8785 section 3.2.2.3.
+fn format_es_number(value: f64) -> String {
+    // this includes `-0`
+    if value == 0.0 {
+        return "0".to_string();
+    }
+    let (digits, n) = shortest_digits(value.abs());
+    let k = digits.len() as i32;
+
+    let mut output = String::with_capacity(digits.len() + 8);
+    if value.is_sign_negative() {
+        output.push('-');
+    }
+    if (k..=21).contains(&n) {
+        output.push_str(&digits);
+        output.push_str(&"0".repeat((n - k) as usize));
+    } else if (1..=21).contains(&n) {
+        let (integer, fraction) = digits.split_at(n as usize);
+        output.push_str(integer);
+        output.push('.');
+        output.push_str(fraction);
+    } else if (-5..=0).contains(&n) {
+        output.push_str("0.");
+        output.push_str(&"0".repeat(-n as usize));
+        output.push_str(&digits);
+    } else {
+        let (first, rest) = digits.split_at(1);
+        output.push_str(first);
+        if !rest.is_empty() {
+            output.push('.');
+            output.push_str(rest);
+        }
+        output.push('e');
+        output.push(if n > 0 { '+' } else { '-' });
+        output.push_str(&(n - 1).abs().to_string());
+    }
+    output
 }
 
 /// Represents a character escape code in a type-safe manner.
@@ -803,7 +1151,7 @@ fn invalid_number() -> PythonSerializerError {
 impl<'a, W, F> serde::ser::Serializer for MapKeySerializer<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -1,10 +1,11 @@
 use std::borrow::Cow;
 use std::fmt::Debug;
+use std::io::{self, BufWriter, Write};
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyBytes, PyDict, PyString};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use enum_dispatch::enum_dispatch;
@@ -115,12 +116,14 @@ combined_serializer! {
         Bool: super::type_serializers::simple::BoolSerializer;
         Float: super::type_serializers::float::FloatSerializer;
         Decimal: super::type_serializers::decimal::DecimalSerializer;
+        Complex: super::type_serializers::complex::ComplexSerializer;
         Str: super::type_serializers::string::StrSerializer;
         Bytes: super::type_serializers::bytes::BytesSerializer;
         Datetime: super::type_serializers::datetime_etc::DatetimeSerializer;
         TimeDelta: super::type_serializers::timedelta::TimeDeltaSerializer;
         Date: super::type_serializers::datetime_etc::DateSerializer;
         Time: super::type_serializers::datetime_etc::TimeSerializer;
+        Timezone: super::type_serializers::timezone::TimezoneSerializer;
         List: super::type_serializers::list::ListSerializer;
         Set: super::type_serializers::set_frozenset::SetSerializer;
         FrozenSet: super::type_serializers::set_frozenset::FrozenSetSerializer;
@@ -139,9 +142,9 @@ combined_serializer! {
         JsonOrPython: super::type_serializers::json_or_python::JsonOrPythonSerializer;
         Union: super::type_serializers::union::UnionSerializer;
         Literal: super::type_serializers::literal::LiteralSerializer;
+        Enum: super::type_serializers::enum_::EnumSerializer;
         Recursive: super::type_serializers::definitions::DefinitionRefSerializer;
-        TuplePositional: super::type_serializers::tuple::TuplePositionalSerializer;
-        TupleVariable: super::type_serializers::tuple::TupleVariableSerializer;
+        Tuple: super::type_serializers::tuple::TupleSerializer;
     }
 }
 
@@ -224,12 +227,14 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Bool(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Float(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Decimal(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Complex(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Str(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Bytes(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Datetime(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::TimeDelta(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Date(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Time(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Timezone(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::List(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Set(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::FrozenSet(inner) => inner.py_gc_traverse(visit),
@@ -247,9 +252,9 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::JsonOrPython(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Union(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Literal(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Enum(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Recursive(inner) => inner.py_gc_traverse(visit),
-            CombinedSerializer::TuplePositional(inner) => inner.py_gc_traverse(visit),
-            CombinedSerializer::TupleVariable(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Tuple(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Uuid(inner) => inner.py_gc_traverse(visit),
         }
     }
@@ -346,24 +351,93 @@ pub(crate) fn to_json_bytes(
     indent: Option<usize>,
     expected_json_size: usize,
 ) -> PyResult<Vec<u8>> {
+    let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
+    to_json_writer(value, serializer, include, exclude, extra, indent, writer)
+}
+
+/// Serialize `value` as JSON to `fp`, any object with a `write` method accepting `bytes`,
+/// output is written in chunks of up to `buffer_size` bytes rather than building the whole document in memory.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn to_json_file(
+    value: &PyAny,
+    serializer: &CombinedSerializer,
+    include: Option<&PyAny>,
+    exclude: Option<&PyAny>,
+    extra: &Extra,
+    indent: Option<usize>,
+    fp: &PyAny,
+    buffer_size: usize,
+) -> PyResult<()> {
+    let mut writer = BufWriter::with_capacity(buffer_size, PyFileWriter { fp, error: None });
+    let result = to_json_writer(value, serializer, include, exclude, extra, indent, &mut writer)
+        .and_then(|writer| writer.flush().map_err(PyErr::from));
+    // `into_parts` avoids flushing the remaining buffer if serialization failed
+    let (file_writer, _) = writer.into_parts();
+    match file_writer.error {
+        // an error raised by `write` takes precedence over the serialization error it caused
+        Some(err) => Err(err),
+        None => result,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn to_json_writer<W: io::Write>(
+    value: &PyAny,
+    serializer: &CombinedSerializer,
+    include: Option<&PyAny>,
+    exclude: Option<&PyAny>,
+    extra: &Extra,
+    indent: Option<usize>,
+    writer: W,
+) -> PyResult<W> {
     let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
 
-    let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
-    let bytes = match indent {
+    let writer = match indent {
+        Some(_) if extra.canonical => return py_err!(PyValueError; "`indent` can't be used with `canonical=True`"),
+        None if extra.canonical => {
+            let mut ser = PythonSerializer::canonical(writer);
+            serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+            ser.into_inner()
+        }
         Some(indent) => {
             let indent = vec![b' '; indent];
             let formatter = PrettyFormatter::with_indent(&indent);
-            let mut ser = PythonSerializer::with_formatter(writer, formatter);
+            let mut ser = PythonSerializer::with_formatter(writer, formatter).with_sort_keys(extra.sort_keys);
             serializer.serialize(&mut ser).map_err(se_err_py_err)?;
             ser.into_inner()
         }
         None => {
-            let mut ser = PythonSerializer::new(writer);
+            let mut ser = PythonSerializer::new(writer).with_sort_keys(extra.sort_keys);
             serializer.serialize(&mut ser).map_err(se_err_py_err)?;
             ser.into_inner()
         }
     };
-    Ok(bytes)
+    Ok(writer)
+}
+
+/// `io::Write` implementation which calls `write` on a Python file-like object.
+struct PyFileWriter<'py> {
+    fp: &'py PyAny,
+    error: Option<PyErr>,
+}
+
+impl io::Write for PyFileWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let py = self.fp.py();
+        match self.fp.call_method1(intern!(py, "write"), (PyBytes::new(py, buf),)) {
+            // raw streams may write fewer bytes than requested, `write` returning something other
+            // than an int is taken to mean everything was written
+            Ok(written) => Ok(written.extract::<usize>().map_or(buf.len(), |n| n.min(buf.len()))),
+            Err(err) => {
+                self.error = Some(err);
+                Err(io::Error::other("error writing to file"))
+            }
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
 }
 
 static DC_FIELD_MARKER: GILOnceCell<PyObject> = GILOnceCell::new();
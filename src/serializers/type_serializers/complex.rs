@@ -0,0 +1,94 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyComplex, PyDict};
+
+use crate::definitions::DefinitionsBuilder;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    IsType, ObType, SerMode, TypeSerializer,
+};
+
+/// Format a complex number the same way as `repr(complex)` without the parentheses, e.g. `1+2j`,
+/// which `complex()` accepts, so the value can be round-tripped.
+pub(crate) fn complex_to_string(py_complex: &PyAny) -> PyResult<String> {
+    let py_complex: &PyComplex = py_complex.downcast()?;
+    // build an exact complex so the `__repr__` of subclasses isn't used
+    let exact = PyComplex::from_doubles(py_complex.py(), py_complex.real(), py_complex.imag());
+    let repr = exact.repr()?.to_str()?;
+    Ok(repr.trim_start_matches('(').trim_end_matches(')').to_string())
+}
+
+#[derive(Debug, Clone)]
+pub struct ComplexSerializer;
+
+impl_py_gc_traverse!(ComplexSerializer {});
+
+impl BuildSerializer for ComplexSerializer {
+    const EXPECTED_TYPE: &'static str = "complex";
+
+    fn build(
+        _schema: &PyDict,
+        _config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {}.into())
+    }
+}
+
+impl TypeSerializer for ComplexSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match extra.ob_type_lookup.is_type(value, ObType::Complex) {
+            IsType::Exact | IsType::Subclass => match extra.mode {
+                SerMode::Json => Ok(complex_to_string(value)?.into_py(py)),
+                _ => Ok(value.into_py(py)),
+            },
+            IsType::False => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        match extra.ob_type_lookup.is_type(key, ObType::Complex) {
+            IsType::Exact | IsType::Subclass => Ok(Cow::Owned(complex_to_string(key)?)),
+            IsType::False => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match extra.ob_type_lookup.is_type(value, ObType::Complex) {
+            IsType::Exact | IsType::Subclass => {
+                let s = complex_to_string(value).map_err(py_err_se_err)?;
+                serializer.serialize_str(&s)
+            }
+            IsType::False => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
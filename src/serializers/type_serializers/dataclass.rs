@@ -5,14 +5,15 @@ use std::borrow::Cow;
 
 use ahash::AHashMap;
 
-use crate::build_tools::{py_schema_error_type, ExtraBehavior};
+use crate::build_tools::{py_schema_error_type, schema_or_config_same, ExtraBehavior};
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 
+use super::model::use_own_serializer;
 use super::{
-    infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer,
-    CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
-    TypeSerializer,
+    infer_json_key, infer_json_key_known, infer_serialize, infer_serialize_known, infer_to_python,
+    infer_to_python_known, py_err_se_err, BuildSerializer, CombinedSerializer, ComputedFields, ExcludeIf, Extra,
+    FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField, TypeSerializer,
 };
 
 pub struct DataclassArgsBuilder;
@@ -42,14 +43,18 @@ impl BuildSerializer for DataclassArgsBuilder {
             let key_py: Py<PyString> = PyString::new(py, &name).into_py(py);
 
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
-                fields.insert(name, SerField::new(py, key_py, None, None, true));
+                fields.insert(name, SerField::new(py, key_py, None, None, true, None));
             } else {
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", index, e))?;
 
                 let alias = field_info.get_as(intern!(py, "serialization_alias"))?;
-                fields.insert(name, SerField::new(py, key_py, alias, Some(serializer), true));
+                let exclude_if = ExcludeIf::from_field_info(field_info)?;
+                fields.insert(
+                    name,
+                    SerField::new(py, key_py, alias, Some(serializer), true, exclude_if),
+                );
             }
         }
 
@@ -65,6 +70,7 @@ pub struct DataclassSerializer {
     serializer: Box<CombinedSerializer>,
     fields: Vec<Py<PyString>>,
     name: String,
+    serialize_as_any: bool,
 }
 
 impl BuildSerializer for DataclassSerializer {
@@ -89,12 +95,14 @@ impl BuildSerializer for DataclassSerializer {
             .iter()
             .map(|s| Ok(s.downcast::<PyString>()?.into_py(py)))
             .collect::<PyResult<Vec<_>>>()?;
+        let serialize_as_any = schema_or_config_same(schema, config, intern!(py, "serialize_as_any"))?.unwrap_or(false);
 
         Ok(Self {
             class: class.into(),
             serializer,
             fields,
             name: class.getattr(intern!(py, "__name__"))?.extract()?,
+            serialize_as_any,
         }
         .into())
     }
@@ -131,6 +139,9 @@ impl TypeSerializer for DataclassSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
+        if use_own_serializer(value, self.class.as_ref(value.py()), self.serialize_as_any, extra)? {
+            return infer_to_python_known(ObType::PydanticSerializable, value, include, exclude, extra);
+        }
         let extra = Extra {
             model: Some(value),
             ..*extra
@@ -161,6 +172,11 @@ impl TypeSerializer for DataclassSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
+        if use_own_serializer(value, self.class.as_ref(value.py()), self.serialize_as_any, extra)
+            .map_err(py_err_se_err)?
+        {
+            return infer_serialize_known(ObType::PydanticSerializable, value, serializer, include, exclude, extra);
+        }
         let extra = Extra {
             model: Some(value),
             ..*extra
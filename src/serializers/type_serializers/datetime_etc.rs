@@ -1,10 +1,14 @@
 use std::borrow::Cow;
 
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDate, PyDateTime, PyDict, PyTime};
 
+use crate::build_tools::schema_or_config_same;
 use crate::definitions::DefinitionsBuilder;
-use crate::input::{pydate_as_date, pydatetime_as_datetime, pytime_as_time};
+use crate::input::{pydate_as_date, pydatetime_as_datetime, pytime_as_time, TimestampUnit};
+use crate::serializers::config::DatetimeMode;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::{
@@ -16,6 +20,17 @@ pub(crate) fn datetime_to_string(py_dt: &PyDateTime) -> PyResult<String> {
     pydatetime_as_datetime(py_dt).map(|dt| dt.to_string())
 }
 
+/// Unix timestamp of a datetime as an integer in `unit`, naive datetimes are treated as UTC.
+pub(crate) fn datetime_to_timestamp(py_dt: &PyDateTime, unit: TimestampUnit) -> PyResult<i64> {
+    let dt = pydatetime_as_datetime(py_dt)?;
+    let per_second = unit.per_second();
+    let fraction = i64::from(dt.time.microsecond) * per_second / 1_000_000;
+    dt.timestamp_tz()
+        .checked_mul(per_second)
+        .and_then(|ts| ts.checked_add(fraction))
+        .ok_or_else(|| PyValueError::new_err(format!("{dt} is out of range for a timestamp in {unit:?}")))
+}
+
 pub(crate) fn date_to_string(py_date: &PyDate) -> PyResult<String> {
     pydate_as_date(py_date).map(|dt| dt.to_string())
 }
@@ -118,11 +133,92 @@ macro_rules! build_serializer {
     };
 }
 
-build_serializer!(
-    DatetimeSerializer,
-    "datetime",
-    PyAny::downcast::<PyDateTime>,
-    datetime_to_string
-);
+/// Serializes datetimes in JSON according to the `ser_json_datetime` config, unless `ser_json_timestamp_unit`
+/// is set on the schema (or the config of a nested model) in which case they're serialized as integer timestamps
+/// in that unit.
+#[derive(Debug, Clone)]
+pub struct DatetimeSerializer {
+    mode: Option<DatetimeMode>,
+}
+
+impl BuildSerializer for DatetimeSerializer {
+    const EXPECTED_TYPE: &'static str = "datetime";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let raw_unit: Option<&str> =
+            schema_or_config_same(schema, config, intern!(schema.py(), "ser_json_timestamp_unit"))?;
+        let mode = match raw_unit {
+            Some(raw_unit) => Some(DatetimeMode::Timestamp(Some(DatetimeMode::timestamp_unit(raw_unit)?))),
+            None => None,
+        };
+        Ok(Self { mode }.into())
+    }
+}
+
+impl DatetimeSerializer {
+    fn mode(&self, extra: &Extra) -> DatetimeMode {
+        self.mode.unwrap_or(extra.config.datetime_mode)
+    }
+}
+
+impl_py_gc_traverse!(DatetimeSerializer {});
+
+impl TypeSerializer for DatetimeSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match value.downcast::<PyDateTime>() {
+            Ok(py_dt) => match extra.mode {
+                SerMode::Json => self.mode(extra).datetime_to_json(py, py_dt),
+                _ => Ok(value.into_py(py)),
+            },
+            Err(_) => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        match key.downcast::<PyDateTime>() {
+            Ok(py_dt) => self.mode(extra).json_key(py_dt),
+            Err(_) => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match value.downcast::<PyDateTime>() {
+            Ok(py_dt) => self.mode(extra).datetime_serialize(py_dt, serializer),
+            Err(_) => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
 build_serializer!(DateSerializer, "date", downcast_date_reject_datetime, date_to_string);
 build_serializer!(TimeSerializer, "time", PyAny::downcast::<PyTime>, time_to_string);
@@ -3,12 +3,16 @@ use std::borrow::Cow;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
+use serde::Serialize;
+
 use crate::definitions::DefinitionsBuilder;
+use crate::input::DecimalValue;
 use crate::serializers::infer::{infer_json_key_known, infer_serialize_known, infer_to_python_known};
 use crate::serializers::ob_type::{IsType, ObType};
 
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, TypeSerializer,
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
@@ -28,6 +32,11 @@ impl BuildSerializer for DecimalSerializer {
 
 impl_py_gc_traverse!(DecimalSerializer {});
 
+/// Format an exact `Decimal` from its digits, rather than calling `str()` on it.
+fn decimal_to_string(value: &PyAny) -> PyResult<String> {
+    DecimalValue::from_py_decimal(value).map(|decimal| decimal.to_string())
+}
+
 impl TypeSerializer for DecimalSerializer {
     fn to_python(
         &self,
@@ -36,9 +45,13 @@ impl TypeSerializer for DecimalSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        let _py = value.py();
+        let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_to_python_known(ObType::Decimal, value, include, exclude, extra),
+            IsType::Exact => match extra.mode {
+                SerMode::Json => Ok(decimal_to_string(value)?.into_py(py)),
+                _ => Ok(value.into_py(py)),
+            },
+            IsType::Subclass => infer_to_python_known(ObType::Decimal, value, include, exclude, extra),
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
@@ -48,7 +61,8 @@ impl TypeSerializer for DecimalSerializer {
 
     fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
         match extra.ob_type_lookup.is_type(key, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_json_key_known(ObType::Decimal, key, extra),
+            IsType::Exact => Ok(Cow::Owned(decimal_to_string(key)?)),
+            IsType::Subclass => infer_json_key_known(ObType::Decimal, key, extra),
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                 infer_json_key(key, extra)
@@ -65,9 +79,8 @@ impl TypeSerializer for DecimalSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match extra.ob_type_lookup.is_type(value, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => {
-                infer_serialize_known(ObType::Decimal, value, serializer, include, exclude, extra)
-            }
+            IsType::Exact => decimal_to_string(value).map_err(py_err_se_err)?.serialize(serializer),
+            IsType::Subclass => infer_serialize_known(ObType::Decimal, value, serializer, include, exclude, extra),
             IsType::False => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
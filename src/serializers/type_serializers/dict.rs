@@ -87,6 +87,7 @@ impl TypeSerializer for DictSerializer {
                 for (key, value) in py_dict {
                     let op_next = self.filter.key_filter(key, include, exclude)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let _path = extra.warnings.enter_key(key);
                         let key = match extra.mode {
                             SerMode::Json => self.key_serializer.json_key(key, extra)?.into_py(py),
                             _ => self.key_serializer.to_python(key, None, None, extra)?,
@@ -125,6 +126,7 @@ impl TypeSerializer for DictSerializer {
                 for (key, value) in py_dict {
                     let op_next = self.filter.key_filter(key, include, exclude).map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let _path = extra.warnings.enter_key(key);
                         let key = key_serializer.json_key(key, extra).map_err(py_err_se_err)?;
                         let value_serialize =
                             PydanticSerializer::new(value, value_serializer, next_include, next_exclude, extra);
@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::py_schema_err;
+use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
+
+use super::float::FloatSerializer;
+use super::simple::IntSerializer;
+use super::string::StrSerializer;
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+#[derive(Debug, Clone)]
+pub struct EnumSerializer {
+    class: Py<PyType>,
+    serializer: Option<Box<CombinedSerializer>>,
+}
+
+impl BuildSerializer for EnumSerializer {
+    const EXPECTED_TYPE: &'static str = "enum";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let sub_type: Option<&str> = schema.get_as(intern!(py, "sub_type"))?;
+
+        let serializer = match sub_type {
+            Some("int") => Some(Box::new(IntSerializer.into())),
+            Some("str") => Some(Box::new(StrSerializer.into())),
+            Some("float") => Some(Box::new(FloatSerializer::new(py, config)?.into())),
+            Some(_) => return py_schema_err!("`sub_type` must be one of: 'int', 'str', 'float' or None"),
+            None => None,
+        };
+        Ok(Self {
+            class: schema.get_as_req(intern!(py, "cls"))?,
+            serializer,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(EnumSerializer { class, serializer });
+
+impl TypeSerializer for EnumSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        if value.is_exact_instance(self.class.as_ref(py)) {
+            // if we're in JSON mode, we need to get the value attribute and serialize that
+            match extra.mode {
+                SerMode::Json => {
+                    let dot_value = value.getattr(intern!(py, "value"))?;
+                    match self.serializer {
+                        Some(ref s) => s.to_python(dot_value, include, exclude, extra),
+                        None => infer_to_python(dot_value, include, exclude, extra),
+                    }
+                }
+                // if we're not in JSON mode, the member itself is returned
+                _ => Ok(value.into_py(py)),
+            }
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+            infer_to_python(value, include, exclude, extra)
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        let py = key.py();
+        if key.is_exact_instance(self.class.as_ref(py)) {
+            let dot_value = key.getattr(intern!(py, "value"))?;
+            match self.serializer {
+                Some(ref s) => s.json_key(dot_value, extra),
+                None => infer_json_key(dot_value, extra),
+            }
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+            infer_json_key(key, extra)
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        let py = value.py();
+        if value.is_exact_instance(self.class.as_ref(py)) {
+            let dot_value = value.getattr(intern!(py, "value")).map_err(py_err_se_err)?;
+            match self.serializer {
+                Some(ref s) => s.serde_serialize(dot_value, serializer, include, exclude, extra),
+                None => infer_serialize(dot_value, serializer, include, exclude, extra),
+            }
+        } else {
+            extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+            infer_serialize(value, serializer, include, exclude, extra)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+
+    fn retry_with_lax_check(&self) -> bool {
+        match self.serializer {
+            Some(ref s) => s.retry_with_lax_check(),
+            None => false,
+        }
+    }
+}
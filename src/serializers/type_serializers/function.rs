@@ -16,8 +16,8 @@ use super::format::WhenUsed;
 
 use super::any::AnySerializer;
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, AnyFilter, BuildSerializer, CombinedSerializer,
-    Extra, ExtraOwned, PydanticSerializationError, SerMode, TypeSerializer,
+    infer_json_key, infer_serialize, infer_to_python, py_unexpected_err_se_err, AnyFilter, BuildSerializer,
+    CombinedSerializer, Extra, ExtraOwned, PydanticSerializationError, SerMode, TypeSerializer,
 };
 
 pub struct FunctionBeforeSerializerBuilder;
@@ -184,8 +184,7 @@ fn on_error(py: Python, err: PyErr, function_name: &str, extra: &Extra) -> PyRes
         if extra.check.enabled() {
             Err(err)
         } else {
-            extra.warnings.custom_warning(ser_err.__repr__());
-            Ok(())
+            extra.warnings.custom_warning(py, &ser_err)
         }
     } else if let Ok(err) = exception.extract::<PydanticSerializationError>() {
         py_err!(PydanticSerializationError; "{}", err)
@@ -256,7 +255,8 @@ macro_rules! function_type_serializer {
                             .serde_serialize(v.into_ref(py), serializer, None, None, extra)
                     }
                     Err(err) => {
-                        on_error(py, err, &self.function_name, extra).map_err(py_err_se_err)?;
+                        on_error(py, err, &self.function_name, extra)
+                            .map_err(|err| py_unexpected_err_se_err(py, err))?;
                         infer_serialize(value, serializer, include, exclude, extra)
                     }
                 }
@@ -488,6 +488,8 @@ struct SerializationInfo {
     include: Option<PyObject>,
     #[pyo3(get)]
     exclude: Option<PyObject>,
+    #[pyo3(get)]
+    context: Option<PyObject>,
     _mode: SerMode,
     #[pyo3(get)]
     by_alias: bool,
@@ -515,6 +517,7 @@ impl SerializationInfo {
                 Some(field_name) => Ok(Self {
                     include: include.map(|i| i.into_py(py)),
                     exclude: exclude.map(|e| e.into_py(py)),
+                    context: extra.context.map(Into::into),
                     _mode: extra.mode.clone(),
                     by_alias: extra.by_alias,
                     exclude_unset: extra.exclude_unset,
@@ -531,6 +534,7 @@ impl SerializationInfo {
             Ok(Self {
                 include: include.map(|i| i.into_py(py)),
                 exclude: exclude.map(|e| e.into_py(py)),
+                context: extra.context.map(Into::into),
                 _mode: extra.mode.clone(),
                 by_alias: extra.by_alias,
                 exclude_unset: extra.exclude_unset,
@@ -563,6 +567,9 @@ impl SerializationInfo {
         if let Some(ref exclude) = self.exclude {
             d.set_item("exclude", exclude)?;
         }
+        if let Some(ref context) = self.context {
+            d.set_item("context", context)?;
+        }
         d.set_item("mode", self.mode(py))?;
         d.set_item("by_alias", self.by_alias)?;
         d.set_item("exclude_unset", self.exclude_unset)?;
@@ -574,7 +581,7 @@ impl SerializationInfo {
 
     fn __repr__(&self, py: Python) -> PyResult<String> {
         Ok(format!(
-            "SerializationInfo(include={}, exclude={}, mode='{}', by_alias={}, exclude_unset={}, exclude_defaults={}, exclude_none={}, round_trip={})",
+            "SerializationInfo(include={}, exclude={}, context={}, mode='{}', by_alias={}, exclude_unset={}, exclude_defaults={}, exclude_none={}, round_trip={})",
             match self.include {
                 Some(ref include) => include.as_ref(py).repr()?.to_str()?,
                 None => "None",
@@ -583,6 +590,10 @@ impl SerializationInfo {
                 Some(ref exclude) => exclude.as_ref(py).repr()?.to_str()?,
                 None => "None",
             },
+            match self.context {
+                Some(ref context) => context.as_ref(py).repr()?.to_str()?,
+                None => "None",
+            },
             self._mode,
             py_bool(self.by_alias),
             py_bool(self.exclude_unset),
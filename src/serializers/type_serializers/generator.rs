@@ -67,6 +67,7 @@ impl TypeSerializer for GeneratorSerializer {
                             let element = iter_result?;
                             let op_next = self.filter.index_filter(index, include, exclude, None)?;
                             if let Some((next_include, next_exclude)) = op_next {
+                                let _path = extra.warnings.enter_index(index);
                                 items.push(item_serializer.to_python(element, next_include, next_exclude, extra)?);
                             }
                         }
@@ -122,6 +123,7 @@ impl TypeSerializer for GeneratorSerializer {
                     if let Some((next_include, next_exclude)) = op_next {
                         let item_serialize =
                             PydanticSerializer::new(element, item_serializer, next_include, next_exclude, extra);
+                        let _path = extra.warnings.enter_index(index);
                         seq.serialize_element(&item_serialize)?;
                     }
                 }
@@ -189,9 +191,11 @@ impl SerializationIterator {
 
         for iter_result in iterator {
             let element = iter_result?;
-            let filter = self.filter.index_filter(self.index, include, exclude, None)?;
+            let index = self.index;
+            let filter = self.filter.index_filter(index, include, exclude, None)?;
             self.index += 1;
             if let Some((next_include, next_exclude)) = filter {
+                let _path = extra.warnings.enter_index(index);
                 let v = self
                     .item_serializer
                     // TODO do we need error_on_fallback to be customizable?
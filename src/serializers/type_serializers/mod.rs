@@ -1,10 +1,12 @@
 pub mod any;
 pub mod bytes;
+pub mod complex;
 pub mod dataclass;
 pub mod datetime_etc;
 pub mod decimal;
 pub mod definitions;
 pub mod dict;
+pub mod enum_;
 pub mod float;
 pub mod format;
 pub mod function;
@@ -20,6 +22,7 @@ pub mod set_frozenset;
 pub mod simple;
 pub mod string;
 pub mod timedelta;
+pub mod timezone;
 pub mod tuple;
 pub mod typed_dict;
 pub mod union;
@@ -29,10 +32,13 @@ pub mod with_default;
 
 use super::computed_fields::ComputedFields;
 use super::config::utf8_py_error;
-use super::errors::{py_err_se_err, PydanticSerializationError};
+use super::errors::{py_err_se_err, py_unexpected_err_se_err, PydanticSerializationError};
 use super::extra::{Extra, ExtraOwned, SerCheck, SerMode};
-use super::fields::{FieldsMode, GeneralFieldsSerializer, SerField};
+use super::fields::{ExcludeIf, FieldsMode, GeneralFieldsSerializer, SerField};
 use super::filter::{AnyFilter, SchemaFilter};
-use super::infer::{infer_json_key, infer_json_key_known, infer_serialize, infer_to_python};
+use super::infer::{
+    infer_json_key, infer_json_key_known, infer_serialize, infer_serialize_known, infer_to_python,
+    infer_to_python_known,
+};
 use super::ob_type::{IsType, ObType};
 use super::shared::{to_json_bytes, BuildSerializer, CombinedSerializer, PydanticSerializer, TypeSerializer};
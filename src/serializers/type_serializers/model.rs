@@ -7,12 +7,12 @@ use pyo3::types::{PyDict, PySet, PyString, PyType};
 use ahash::AHashMap;
 
 use super::{
-    infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer,
-    CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
-    TypeSerializer,
+    infer_json_key, infer_json_key_known, infer_serialize, infer_serialize_known, infer_to_python,
+    infer_to_python_known, py_err_se_err, BuildSerializer, CombinedSerializer, ComputedFields, ExcludeIf, Extra,
+    FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField, TypeSerializer,
 };
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{py_schema_error_type, ExtraBehavior};
+use crate::build_tools::{py_schema_error_type, schema_or_config_same, ExtraBehavior};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::errors::PydanticSerializationUnexpectedValue;
 use crate::tools::SchemaDict;
@@ -53,7 +53,7 @@ impl BuildSerializer for ModelFieldsBuilder {
             let key_py: Py<PyString> = key_py.into_py(py);
 
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
-                fields.insert(key, SerField::new(py, key_py, None, None, true));
+                fields.insert(key, SerField::new(py, key_py, None, None, true, None));
             } else {
                 let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
 
@@ -61,7 +61,12 @@ impl BuildSerializer for ModelFieldsBuilder {
                 let serializer = CombinedSerializer::build(schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", key, e))?;
 
-                fields.insert(key, SerField::new(py, key_py, alias, Some(serializer), true));
+                let exclude_if = ExcludeIf::from_field_info(field_info)?;
+
+                fields.insert(
+                    key,
+                    SerField::new(py, key_py, alias, Some(serializer), true, exclude_if),
+                );
             }
         }
 
@@ -78,6 +83,7 @@ pub struct ModelSerializer {
     has_extra: bool,
     root_model: bool,
     name: String,
+    serialize_as_any: bool,
 }
 
 impl BuildSerializer for ModelSerializer {
@@ -97,6 +103,7 @@ impl BuildSerializer for ModelSerializer {
         let sub_schema: &PyDict = schema.get_as_req(intern!(py, "schema"))?;
         let serializer = Box::new(CombinedSerializer::build(sub_schema, config, definitions)?);
         let root_model = schema.get_as(intern!(py, "root_model"))?.unwrap_or(false);
+        let serialize_as_any = schema_or_config_same(schema, config, intern!(py, "serialize_as_any"))?.unwrap_or(false);
 
         Ok(Self {
             class: class.into(),
@@ -104,6 +111,7 @@ impl BuildSerializer for ModelSerializer {
             has_extra: has_extra(schema, config)?,
             root_model,
             name: class.getattr(intern!(py, "__name__"))?.extract()?,
+            serialize_as_any,
         }
         .into())
     }
@@ -115,6 +123,32 @@ fn has_extra(schema: &PyDict, config: Option<&PyDict>) -> PyResult<bool> {
     Ok(matches!(extra_behaviour, ExtraBehavior::Allow))
 }
 
+/// With `serialize_as_any`, instances of a subclass of `class` which carry their own `__pydantic_serializer__`
+/// are serialized with that serializer so fields added by the subclass are included.
+pub(super) fn use_own_serializer(
+    value: &PyAny,
+    class: &PyType,
+    serialize_as_any: bool,
+    extra: &Extra,
+) -> PyResult<bool> {
+    if !(serialize_as_any || extra.serialize_as_any)
+        || extra.check == SerCheck::Strict
+        || value.get_type().is(class)
+        || !value.is_instance(class)?
+    {
+        return Ok(false);
+    }
+    let py = value.py();
+    let Ok(own_serializer) = value.getattr(intern!(py, "__pydantic_serializer__")) else {
+        return Ok(false);
+    };
+    // subclasses without their own schema inherit the parent's serializer, use the schema as usual
+    match class.getattr(intern!(py, "__pydantic_serializer__")) {
+        Ok(class_serializer) => Ok(!own_serializer.is(class_serializer)),
+        Err(_) => Ok(true),
+    }
+}
+
 impl ModelSerializer {
     fn allow_value(&self, value: &PyAny, extra: &Extra) -> PyResult<bool> {
         let class = self.class.as_ref(value.py());
@@ -161,6 +195,9 @@ impl TypeSerializer for ModelSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
+        if use_own_serializer(value, self.class.as_ref(value.py()), self.serialize_as_any, extra)? {
+            return infer_to_python_known(ObType::PydanticSerializable, value, include, exclude, extra);
+        }
         let mut extra = Extra {
             model: Some(value),
             field_name: None,
@@ -203,6 +240,11 @@ impl TypeSerializer for ModelSerializer {
         exclude: Option<&PyAny>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
+        if use_own_serializer(value, self.class.as_ref(value.py()), self.serialize_as_any, extra)
+            .map_err(py_err_se_err)?
+        {
+            return infer_serialize_known(ObType::PydanticSerializable, value, serializer, include, exclude, extra);
+        }
         let mut extra = Extra {
             model: Some(value),
             field_name: None,
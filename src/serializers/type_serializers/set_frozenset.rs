@@ -4,7 +4,7 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyFrozenSet, PyList, PySet};
 
-use serde::ser::SerializeSeq;
+use serde::ser::{SerializeSeq, SerializeTupleStruct};
 
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
@@ -14,6 +14,7 @@ use super::{
     infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer, SerMode,
     TypeSerializer,
 };
+use crate::serializers::ser::SORTED_SET_TOKEN;
 
 macro_rules! build_serializer {
     ($struct_name:ident, $expected_type:literal, $py_type:ty) => {
@@ -61,7 +62,9 @@ macro_rules! build_serializer {
                         let item_serializer = self.item_serializer.as_ref();
 
                         let mut items = Vec::with_capacity(py_set.len());
-                        for element in py_set.iter() {
+                        // there's no position in a set, so members are located by their iteration order
+                        for (index, element) in py_set.iter().enumerate() {
+                            let _path = extra.warnings.enter_index(index);
                             items.push(item_serializer.to_python(element, include, exclude, extra)?);
                         }
                         match extra.mode {
@@ -90,15 +93,27 @@ macro_rules! build_serializer {
             ) -> Result<S::Ok, S::Error> {
                 match value.downcast::<$py_type>() {
                     Ok(py_set) => {
-                        let mut seq = serializer.serialize_seq(Some(py_set.len()))?;
                         let item_serializer = self.item_serializer.as_ref();
 
-                        for value in py_set.iter() {
-                            let item_serialize =
-                                PydanticSerializer::new(value, item_serializer, include, exclude, extra);
-                            seq.serialize_element(&item_serialize)?;
+                        let items = py_set
+                            .iter()
+                            .map(|value| PydanticSerializer::new(value, item_serializer, include, exclude, extra));
+                        if extra.sort_keys {
+                            // members are serialized once and ordered by their JSON as the set is written
+                            let mut seq = serializer.serialize_tuple_struct(SORTED_SET_TOKEN, py_set.len())?;
+                            for (index, item_serialize) in items.enumerate() {
+                                let _path = extra.warnings.enter_index(index);
+                                seq.serialize_field(&item_serialize)?;
+                            }
+                            seq.end()
+                        } else {
+                            let mut seq = serializer.serialize_seq(Some(py_set.len()))?;
+                            for (index, item_serialize) in items.enumerate() {
+                                let _path = extra.warnings.enter_index(index);
+                                seq.serialize_element(&item_serialize)?;
+                            }
+                            seq.end()
                         }
-                        seq.end()
                     }
                     Err(_) => {
                         extra
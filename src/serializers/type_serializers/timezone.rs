@@ -0,0 +1,89 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::definitions::DefinitionsBuilder;
+use crate::input::get_zoneinfo_type;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+/// `str(ZoneInfo)` is the key used to create it, e.g. `America/New_York`.
+pub(crate) fn zoneinfo_to_string(py_zoneinfo: &PyAny) -> PyResult<String> {
+    Ok(py_zoneinfo.str()?.to_string())
+}
+
+fn is_zoneinfo(value: &PyAny) -> PyResult<bool> {
+    value.is_instance(get_zoneinfo_type(value.py())?)
+}
+
+#[derive(Debug, Clone)]
+pub struct TimezoneSerializer;
+
+impl_py_gc_traverse!(TimezoneSerializer {});
+
+impl BuildSerializer for TimezoneSerializer {
+    const EXPECTED_TYPE: &'static str = "timezone";
+
+    fn build(
+        _schema: &PyDict,
+        _config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {}.into())
+    }
+}
+
+impl TypeSerializer for TimezoneSerializer {
+    fn to_python(
+        &self,
+        value: &PyAny,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        if is_zoneinfo(value)? {
+            match extra.mode {
+                SerMode::Json => Ok(zoneinfo_to_string(value)?.into_py(py)),
+                _ => Ok(value.into_py(py)),
+            }
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+            infer_to_python(value, include, exclude, extra)
+        }
+    }
+
+    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
+        if is_zoneinfo(key)? {
+            Ok(Cow::Owned(zoneinfo_to_string(key)?))
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+            infer_json_key(key, extra)
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &PyAny,
+        serializer: S,
+        include: Option<&PyAny>,
+        exclude: Option<&PyAny>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        if is_zoneinfo(value).map_err(py_err_se_err)? {
+            let s = zoneinfo_to_string(value).map_err(py_err_se_err)?;
+            serializer.serialize_str(&s)
+        } else {
+            extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+            infer_serialize(value, serializer, include, exclude, extra)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -5,6 +5,7 @@ use std::borrow::Cow;
 
 use serde::ser::SerializeSeq;
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 
@@ -15,14 +16,15 @@ use super::{
 };
 
 #[derive(Debug, Clone)]
-pub struct TupleVariableSerializer {
-    item_serializer: Box<CombinedSerializer>,
+pub struct TupleSerializer {
+    serializers: Vec<CombinedSerializer>,
+    variadic_item_index: usize,
     filter: SchemaFilter<usize>,
     name: String,
 }
 
-impl BuildSerializer for TupleVariableSerializer {
-    const EXPECTED_TYPE: &'static str = "tuple-variable";
+impl BuildSerializer for TupleSerializer {
+    const EXPECTED_TYPE: &'static str = "tuple";
 
     fn build(
         schema: &PyDict,
@@ -30,165 +32,66 @@ impl BuildSerializer for TupleVariableSerializer {
         definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
-        if let Some("positional") = schema.get_as::<&str>(intern!(py, "mode"))? {
-            return TuplePositionalSerializer::build(schema, config, definitions);
-        }
-        let item_serializer = match schema.get_as::<&PyDict>(intern!(py, "items_schema"))? {
-            Some(items_schema) => CombinedSerializer::build(items_schema, config, definitions)?,
-            None => AnySerializer::build(schema, config, definitions)?,
+        let mut serializers: Vec<CombinedSerializer> = match schema.get_as::<&PyList>(intern!(py, "items_schema"))? {
+            Some(items) => items
+                .iter()
+                .map(|item| CombinedSerializer::build(item.downcast()?, config, definitions))
+                .collect::<PyResult<_>>()?,
+            None => Vec::new(),
         };
-        let name = format!("tuple[{}, ...]", item_serializer.get_name());
-        Ok(Self {
-            item_serializer: Box::new(item_serializer),
-            filter: SchemaFilter::from_schema(schema)?,
-            name,
-        }
-        .into())
-    }
-}
-
-impl_py_gc_traverse!(TupleVariableSerializer { item_serializer });
-
-impl TypeSerializer for TupleVariableSerializer {
-    fn to_python(
-        &self,
-        value: &PyAny,
-        include: Option<&PyAny>,
-        exclude: Option<&PyAny>,
-        extra: &Extra,
-    ) -> PyResult<PyObject> {
-        match value.downcast::<PyTuple>() {
-            Ok(py_tuple) => {
-                let py = value.py();
-                let item_serializer = self.item_serializer.as_ref();
 
-                let mut items = Vec::with_capacity(py_tuple.len());
-                for (index, element) in py_tuple.iter().enumerate() {
-                    let op_next = self
-                        .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        items.push(item_serializer.to_python(element, next_include, next_exclude, extra)?);
-                    }
-                }
-                match extra.mode {
-                    SerMode::Json => Ok(PyList::new(py, items).into_py(py)),
-                    _ => Ok(PyTuple::new(py, items).into_py(py)),
-                }
-            }
-            Err(_) => {
-                extra.warnings.on_fallback_py(&self.name, value, extra)?;
-                infer_to_python(value, include, exclude, extra)
+        let variadic_item_index: Option<usize> = schema.get_as(intern!(py, "variadic_item_index"))?;
+        let mut descr: Vec<&str> = serializers.iter().map(TypeSerializer::get_name).collect();
+        if let Some(index) = variadic_item_index {
+            if index >= serializers.len() {
+                return py_schema_err!(
+                    "`variadic_item_index` {} is out of range for `items_schema` of length {}",
+                    index,
+                    serializers.len()
+                );
             }
+            descr.insert(index + 1, "...");
         }
-    }
-
-    fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
-        match key.downcast::<PyTuple>() {
-            Ok(py_tuple) => {
-                let item_serializer = self.item_serializer.as_ref();
-
-                let mut key_builder = KeyBuilder::new();
-                for element in py_tuple {
-                    key_builder.push(&item_serializer.json_key(element, extra)?);
-                }
-                Ok(Cow::Owned(key_builder.finish()))
-            }
-            Err(_) => {
-                extra.warnings.on_fallback_py(&self.name, key, extra)?;
-                infer_json_key(key, extra)
-            }
-        }
-    }
-
-    fn serde_serialize<S: serde::ser::Serializer>(
-        &self,
-        value: &PyAny,
-        serializer: S,
-        include: Option<&PyAny>,
-        exclude: Option<&PyAny>,
-        extra: &Extra,
-    ) -> Result<S::Ok, S::Error> {
-        match value.downcast::<PyTuple>() {
-            Ok(py_tuple) => {
-                let py_tuple: &PyTuple = py_tuple.downcast().map_err(py_err_se_err)?;
-                let item_serializer = self.item_serializer.as_ref();
-
-                let mut seq = serializer.serialize_seq(Some(py_tuple.len()))?;
-                for (index, element) in py_tuple.iter().enumerate() {
-                    let op_next = self
-                        .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))
-                        .map_err(py_err_se_err)?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        let item_serialize =
-                            PydanticSerializer::new(element, item_serializer, next_include, next_exclude, extra);
-                        seq.serialize_element(&item_serialize)?;
-                    }
-                }
-                seq.end()
-            }
-            Err(_) => {
-                extra.warnings.on_fallback_ser::<S>(&self.name, value, extra)?;
-                infer_serialize(value, serializer, include, exclude, extra)
+        let name = format!("tuple[{}]", descr.join(", "));
+
+        let variadic_item_index = match variadic_item_index {
+            Some(index) => index,
+            None => {
+                // unexpected extra items are serialized with `any`
+                serializers.push(AnySerializer::build(schema, config, definitions)?);
+                serializers.len() - 1
             }
-        }
-    }
-
-    fn get_name(&self) -> &str {
-        &self.name
-    }
-}
-
-#[derive(Debug, Clone)]
-pub struct TuplePositionalSerializer {
-    items_serializers: Vec<CombinedSerializer>,
-    extra_serializer: Box<CombinedSerializer>,
-    filter: SchemaFilter<usize>,
-    name: String,
-}
-
-impl BuildSerializer for TuplePositionalSerializer {
-    const EXPECTED_TYPE: &'static str = "tuple-positional";
-
-    fn build(
-        schema: &PyDict,
-        config: Option<&PyDict>,
-        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
-    ) -> PyResult<CombinedSerializer> {
-        let py = schema.py();
-        let items: &PyList = schema.get_as_req(intern!(py, "items_schema"))?;
-
-        let extra_serializer = match schema.get_as::<&PyDict>(intern!(py, "extras_schema"))? {
-            Some(extras_schema) => CombinedSerializer::build(extras_schema, config, definitions)?,
-            None => AnySerializer::build(schema, config, definitions)?,
         };
-        let items_serializers: Vec<CombinedSerializer> = items
-            .iter()
-            .map(|item| CombinedSerializer::build(item.downcast()?, config, definitions))
-            .collect::<PyResult<_>>()?;
 
-        let descr = items_serializers
-            .iter()
-            .map(TypeSerializer::get_name)
-            .collect::<Vec<_>>()
-            .join(", ");
         Ok(Self {
-            items_serializers,
-            extra_serializer: Box::new(extra_serializer),
+            serializers,
+            variadic_item_index,
             filter: SchemaFilter::from_schema(schema)?,
-            name: format!("tuple[{descr}]"),
+            name,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(TuplePositionalSerializer {
-    items_serializers,
-    extra_serializer
-});
+impl_py_gc_traverse!(TupleSerializer { serializers });
+
+impl TupleSerializer {
+    /// The serializer for the item at `index` in a tuple of length `len`.
+    fn item_serializer(&self, index: usize, len: usize) -> &CombinedSerializer {
+        let variadic_item_index = self.variadic_item_index;
+        let tail_len = self.serializers.len().saturating_sub(variadic_item_index + 1);
+        let tail_start = len.saturating_sub(tail_len).max(variadic_item_index);
+        if index < variadic_item_index {
+            &self.serializers[index]
+        } else if index < tail_start {
+            &self.serializers[variadic_item_index]
+        } else {
+            &self.serializers[variadic_item_index + 1 + index - tail_start]
+        }
+    }
+}
 
-impl TypeSerializer for TuplePositionalSerializer {
+impl TypeSerializer for TupleSerializer {
     fn to_python(
         &self,
         value: &PyAny,
@@ -199,33 +102,17 @@ impl TypeSerializer for TuplePositionalSerializer {
         match value.downcast::<PyTuple>() {
             Ok(py_tuple) => {
                 let py = value.py();
+                let len = py_tuple.len();
 
-                let mut py_tuple_iter = py_tuple.iter();
-                let mut items = Vec::with_capacity(py_tuple.len());
-                for (index, serializer) in self.items_serializers.iter().enumerate() {
-                    let element = match py_tuple_iter.next() {
-                        Some(value) => value,
-                        None => break,
-                    };
-                    let op_next = self
-                        .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))?;
+                let mut items = Vec::with_capacity(len);
+                for (index, element) in py_tuple.iter().enumerate() {
+                    let op_next = self.filter.index_filter(index, include, exclude, Some(len))?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let _path = extra.warnings.enter_index(index);
+                        let serializer = self.item_serializer(index, len);
                         items.push(serializer.to_python(element, next_include, next_exclude, extra)?);
                     }
                 }
-                let expected_length = self.items_serializers.len();
-                let extra_serializer = self.extra_serializer.as_ref();
-                for (index2, element) in py_tuple_iter.enumerate() {
-                    let index = index2 + expected_length;
-                    let op_next = self
-                        .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        items.push(extra_serializer.to_python(element, next_include, next_exclude, extra)?);
-                    }
-                }
-
                 match extra.mode {
                     SerMode::Json => Ok(PyList::new(py, items).into_py(py)),
                     _ => Ok(PyTuple::new(py, items).into_py(py)),
@@ -241,19 +128,11 @@ impl TypeSerializer for TuplePositionalSerializer {
     fn json_key<'py>(&self, key: &'py PyAny, extra: &Extra) -> PyResult<Cow<'py, str>> {
         match key.downcast::<PyTuple>() {
             Ok(py_tuple) => {
-                let mut py_tuple_iter = py_tuple.iter();
+                let len = py_tuple.len();
 
                 let mut key_builder = KeyBuilder::new();
-                for serializer in &self.items_serializers {
-                    let element = match py_tuple_iter.next() {
-                        Some(value) => value,
-                        None => break,
-                    };
-                    key_builder.push(&serializer.json_key(element, extra)?);
-                }
-                let extra_serializer = self.extra_serializer.as_ref();
-                for element in py_tuple_iter {
-                    key_builder.push(&extra_serializer.json_key(element, extra)?);
+                for (index, element) in py_tuple.iter().enumerate() {
+                    key_builder.push(&self.item_serializer(index, len).json_key(element, extra)?);
                 }
                 Ok(Cow::Owned(key_builder.finish()))
             }
@@ -274,41 +153,22 @@ impl TypeSerializer for TuplePositionalSerializer {
     ) -> Result<S::Ok, S::Error> {
         match value.downcast::<PyTuple>() {
             Ok(py_tuple) => {
-                let py_tuple: &PyTuple = py_tuple.downcast().map_err(py_err_se_err)?;
+                let len = py_tuple.len();
 
-                let mut py_tuple_iter = py_tuple.iter();
-                let mut seq = serializer.serialize_seq(Some(py_tuple.len()))?;
-                for (index, serializer) in self.items_serializers.iter().enumerate() {
-                    let element = match py_tuple_iter.next() {
-                        Some(value) => value,
-                        None => break,
-                    };
-                    let op_next = self
-                        .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))
-                        .map_err(py_err_se_err)?;
-                    if let Some((next_include, next_exclude)) = op_next {
-                        let item_serialize =
-                            PydanticSerializer::new(element, serializer, next_include, next_exclude, extra);
-                        seq.serialize_element(&item_serialize)?;
-                    }
-                }
-
-                let expected_length = self.items_serializers.len();
-                let extra_serializer = self.extra_serializer.as_ref();
-                for (index2, element) in py_tuple_iter.enumerate() {
-                    let index = index2 + expected_length;
+                let mut seq = serializer.serialize_seq(Some(len))?;
+                for (index, element) in py_tuple.iter().enumerate() {
                     let op_next = self
                         .filter
-                        .index_filter(index, include, exclude, Some(py_tuple.len()))
+                        .index_filter(index, include, exclude, Some(len))
                         .map_err(py_err_se_err)?;
                     if let Some((next_include, next_exclude)) = op_next {
+                        let _path = extra.warnings.enter_index(index);
+                        let item_serializer = self.item_serializer(index, len);
                         let item_serialize =
-                            PydanticSerializer::new(element, extra_serializer, next_include, next_exclude, extra);
+                            PydanticSerializer::new(element, item_serializer, next_include, next_exclude, extra);
                         seq.serialize_element(&item_serialize)?;
                     }
                 }
-
                 seq.end()
             }
             Err(_) => {
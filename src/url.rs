@@ -45,7 +45,7 @@ impl PyUrl {
     pub fn py_new(py: Python, url: &PyAny) -> PyResult<Self> {
         let schema_obj = SCHEMA_DEFINITION_URL
             .get_or_init(py, || build_schema_validator(py, "url"))
-            .validate_python(py, url, None, None, None, None)?;
+            .validate_python(py, url, None, None, None, None, None, None)?;
         schema_obj.extract(py)
     }
 
@@ -233,7 +233,7 @@ impl PyMultiHostUrl {
     pub fn py_new(py: Python, url: &PyAny) -> PyResult<Self> {
         let schema_obj = SCHEMA_DEFINITION_MULTI_HOST_URL
             .get_or_init(py, || build_schema_validator(py, "multi-host-url"))
-            .validate_python(py, url, None, None, None, None)?;
+            .validate_python(py, url, None, None, None, None, None, None)?;
         schema_obj.extract(py)
     }
 
@@ -5,7 +5,7 @@ use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{is_fail_fast, schema_or_config_same, ExtraBehavior};
 use crate::errors::{AsLocItem, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{GenericArguments, Input, ValidationMatch};
 use crate::lookup_key::LookupKey;
@@ -30,6 +30,7 @@ pub struct ArgumentsValidator {
     positional_params_count: usize,
     var_args_validator: Option<Box<CombinedValidator>>,
     var_kwargs_validator: Option<Box<CombinedValidator>>,
+    fail_fast: bool,
     loc_by_alias: bool,
     extra: ExtraBehavior,
 }
@@ -119,6 +120,7 @@ impl BuildValidator for ArgumentsValidator {
                 Some(v) => Some(Box::new(build_validator(v, config, definitions)?)),
                 None => None,
             },
+            fail_fast: is_fail_fast(schema, config)?,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
             extra: ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Forbid)?,
         }
@@ -175,11 +177,16 @@ impl Validator for ArgumentsValidator {
         let output_kwargs = PyDict::new(py);
         let mut errors: Vec<ValLineError> = Vec::new();
         let mut used_kwargs: AHashSet<&str> = AHashSet::with_capacity(self.parameters.len());
+        let fail_fast = state.fail_fast_or(self.fail_fast);
+        let loc_by_alias = state.loc_by_alias_or(self.loc_by_alias);
 
         macro_rules! process {
             ($args:ident, $get_method:ident, $get_macro:ident, $slice_macro:ident) => {{
                 // go through arguments getting the value from args or kwargs and validating it
                 for (index, parameter) in self.parameters.iter().enumerate() {
+                    if fail_fast && !errors.is_empty() {
+                        break;
+                    }
                     let mut pos_value = None;
                     if let Some(args) = $args.args {
                         if parameter.positional {
@@ -220,7 +227,7 @@ impl Validator for ArgumentsValidator {
                                 Ok(value) => output_kwargs.set_item(parameter.kwarg_key.as_ref().unwrap(), value)?,
                                 Err(ValError::LineErrors(line_errors)) => {
                                     errors.extend(line_errors.into_iter().map(|err| {
-                                        lookup_path.apply_error_loc(err, self.loc_by_alias, &parameter.name)
+                                        lookup_path.apply_error_loc(err, loc_by_alias, &parameter.name)
                                     }));
                                 }
                                 Err(err) => return Err(err),
@@ -242,7 +249,7 @@ impl Validator for ArgumentsValidator {
                                 errors.push(lookup_key.error(
                                     error_type,
                                     input,
-                                    self.loc_by_alias,
+                                    loc_by_alias,
                                     &parameter.name,
                                 ));
                             } else {
@@ -257,6 +264,9 @@ impl Validator for ArgumentsValidator {
                     if len > self.positional_params_count {
                         if let Some(ref validator) = self.var_args_validator {
                             for (index, item) in $slice_macro!(args, self.positional_params_count, len).iter().enumerate() {
+                                if fail_fast && !errors.is_empty() {
+                                    break;
+                                }
                                 match validator.validate(py, item, state) {
                                     Ok(value) => output_args.push(value),
                                     Err(ValError::LineErrors(line_errors)) => {
@@ -271,6 +281,9 @@ impl Validator for ArgumentsValidator {
                             }
                         } else {
                             for (index, item) in $slice_macro!(args, self.positional_params_count, len).iter().enumerate() {
+                                if fail_fast && !errors.is_empty() {
+                                    break;
+                                }
                                 errors.push(ValLineError::new_with_loc(
                                     ErrorTypeDefaults::UnexpectedPositionalArgument,
                                     item,
@@ -284,6 +297,9 @@ impl Validator for ArgumentsValidator {
                 if let Some(kwargs) = $args.kwargs {
                     if kwargs.len() > used_kwargs.len() {
                         for (raw_key, value) in kwargs.iter() {
+                            if fail_fast && !errors.is_empty() {
+                                break;
+                            }
                             let either_str = match raw_key.validate_str(true, false).map(ValidationMatch::into_inner) {
                                 Ok(k) => k,
                                 Err(ValError::LineErrors(line_errors)) => {
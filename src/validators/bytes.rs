@@ -1,18 +1,109 @@
+use std::str::FromStr;
+
+use base64::alphabet;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err, schema_or_config_same};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherBytes, Input};
 
 use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
+const LENIENT_CONFIG: GeneralPurposeConfig =
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent);
+const BASE64_STANDARD: GeneralPurpose = GeneralPurpose::new(&alphabet::STANDARD, LENIENT_CONFIG);
+const BASE64_URL_SAFE: GeneralPurpose = GeneralPurpose::new(&alphabet::URL_SAFE, LENIENT_CONFIG);
+
+/// How strings from JSON (and other string-based input) are decoded into bytes, the counterpart of
+/// `ser_json_bytes` for validation.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValBytesMode {
+    #[default]
+    Utf8,
+    Base64,
+    Base64Url,
+    Hex,
+}
+
+impl FromStr for ValBytesMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "utf8" => Ok(Self::Utf8),
+            "base64" => Ok(Self::Base64),
+            "base64url" => Ok(Self::Base64Url),
+            "hex" => Ok(Self::Hex),
+            s => py_schema_err!(
+                "Invalid bytes validation mode: `{}`, expected `utf8`, `base64`, `base64url` or `hex`",
+                s
+            ),
+        }
+    }
+}
+
+impl ValBytesMode {
+    pub fn from_schema(schema: &PyDict, config: Option<&PyDict>) -> PyResult<Self> {
+        let raw_mode: Option<&str> = schema_or_config_same(schema, config, intern!(schema.py(), "val_json_bytes"))?;
+        raw_mode.map_or_else(|| Ok(Self::default()), Self::from_str)
+    }
+
+    pub fn deserialize_string(self, s: &str) -> Result<EitherBytes<'_>, ErrorType> {
+        match self {
+            Self::Utf8 => Ok(s.as_bytes().into()),
+            // `ser_json_bytes='base64'` emits the URL-safe alphabet, so accept either alphabet here
+            Self::Base64 => BASE64_STANDARD
+                .decode(s)
+                .or_else(|err| BASE64_URL_SAFE.decode(s).map_err(|_| err))
+                .map(Into::into)
+                .map_err(|err| invalid_encoding("base64", err.to_string())),
+            Self::Base64Url => BASE64_URL_SAFE
+                .decode(s)
+                .map(Into::into)
+                .map_err(|err| invalid_encoding("base64url", err.to_string())),
+            Self::Hex => decode_hex(s)
+                .map(Into::into)
+                .map_err(|err| invalid_encoding("hex", err)),
+        }
+    }
+}
+
+fn invalid_encoding(encoding: &str, encoding_error: String) -> ErrorType {
+    ErrorType::BytesInvalidEncoding {
+        encoding: encoding.to_string(),
+        encoding_error,
+        context: None,
+    }
+}
+
+fn decode_hex(s: &str) -> Result<Vec<u8>, String> {
+    let pairs = s.as_bytes().chunks_exact(2);
+    if !pairs.remainder().is_empty() {
+        return Err("Odd number of digits".to_string());
+    }
+    let hex_value = |index: usize, c: u8| {
+        (c as char)
+            .to_digit(16)
+            .map(|d| d as u8)
+            .ok_or_else(|| format!("Invalid character {:?} at position {index}", c as char))
+    };
+    pairs
+        .enumerate()
+        .map(|(i, pair)| Ok(hex_value(i * 2, pair[0])? << 4 | hex_value(i * 2 + 1, pair[1])?))
+        .collect()
+}
+
 #[derive(Debug, Clone)]
 pub struct BytesValidator {
     strict: bool,
+    bytes_mode: ValBytesMode,
 }
 
 impl BuildValidator for BytesValidator {
@@ -31,6 +122,7 @@ impl BuildValidator for BytesValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                bytes_mode: ValBytesMode::from_schema(schema, config)?,
             }
             .into())
         }
@@ -47,7 +139,7 @@ impl Validator for BytesValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         input
-            .validate_bytes(state.strict_or(self.strict))
+            .validate_bytes(state.strict_or(self.strict), self.bytes_mode)
             .map(|m| m.unpack(state).into_py(py))
     }
 
@@ -59,6 +151,7 @@ impl Validator for BytesValidator {
 #[derive(Debug, Clone)]
 pub struct BytesConstrainedValidator {
     strict: bool,
+    bytes_mode: ValBytesMode,
     max_length: Option<usize>,
     min_length: Option<usize>,
 }
@@ -72,7 +165,9 @@ impl Validator for BytesConstrainedValidator {
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
-        let either_bytes = input.validate_bytes(state.strict_or(self.strict))?.unpack(state);
+        let either_bytes = input
+            .validate_bytes(state.strict_or(self.strict), self.bytes_mode)?
+            .unpack(state);
         let len = either_bytes.len()?;
 
         if let Some(min_length) = self.min_length {
@@ -110,6 +205,7 @@ impl BytesConstrainedValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            bytes_mode: ValBytesMode::from_schema(schema, config)?,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
         }
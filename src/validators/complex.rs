@@ -0,0 +1,46 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::is_strict;
+use crate::errors::ValResult;
+use crate::input::Input;
+
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+#[derive(Debug, Clone)]
+pub struct ComplexValidator {
+    strict: bool,
+}
+
+impl BuildValidator for ComplexValidator {
+    const EXPECTED_TYPE: &'static str = "complex";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(ComplexValidator {});
+
+impl Validator for ComplexValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let either_complex = input.validate_complex(state.strict_or(self.strict), py)?.unpack(state);
+        Ok(either_complex.into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -6,7 +6,7 @@ use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyType};
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{is_fail_fast, is_strict, schema_or_config_same, ExtraBehavior};
 use crate::errors::{AsLocItem, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::InputType;
 use crate::input::{BorrowInput, GenericArguments, Input, ValidationMatch};
@@ -41,6 +41,7 @@ pub struct DataclassArgsValidator {
     validator_name: String,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
+    fail_fast: bool,
     loc_by_alias: bool,
 }
 
@@ -128,6 +129,7 @@ impl BuildValidator for DataclassArgsValidator {
             validator_name,
             extra_behavior,
             extras_validator,
+            fail_fast: is_fail_fast(schema, config)?,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
         }
         .into())
@@ -152,6 +154,8 @@ impl Validator for DataclassArgsValidator {
 
         let mut errors: Vec<ValLineError> = Vec::new();
         let mut used_keys: AHashSet<&str> = AHashSet::with_capacity(self.fields.len());
+        let fail_fast = state.fail_fast_or(self.fail_fast);
+        let loc_by_alias = state.loc_by_alias_or(self.loc_by_alias);
 
         state.with_new_extra(
             Extra {
@@ -176,6 +180,9 @@ impl Validator for DataclassArgsValidator {
                     ($args:ident, $get_method:ident, $get_macro:ident, $slice_macro:ident) => {{
                         // go through fields getting the value from args or kwargs and validating it
                         for (index, field) in self.fields.iter().enumerate() {
+                            if fail_fast && !errors.is_empty() {
+                                break;
+                            }
                             let mut pos_value = None;
                             if let Some(args) = $args.args {
                                 if !field.kw_only {
@@ -224,7 +231,7 @@ impl Validator for DataclassArgsValidator {
                                         Err(ValError::LineErrors(line_errors)) => {
                                             errors.extend(line_errors.into_iter().map(|err| {
                                                 lookup_path
-                                                    .apply_error_loc(err, self.loc_by_alias, &field.name)
+                                                    .apply_error_loc(err, loc_by_alias, &field.name)
                                             }));
                                         }
                                         Err(err) => return Err(err),
@@ -242,7 +249,7 @@ impl Validator for DataclassArgsValidator {
                                             errors.push(field.lookup_key.error(
                                                 ErrorTypeDefaults::Missing,
                                                 input,
-                                                self.loc_by_alias,
+                                                loc_by_alias,
                                                 &field.name
                                             ));
                                         },
@@ -269,6 +276,9 @@ impl Validator for DataclassArgsValidator {
                                     .iter()
                                     .enumerate()
                                 {
+                                    if fail_fast && !errors.is_empty() {
+                                        break;
+                                    }
                                     errors.push(ValLineError::new_with_loc(
                                         ErrorTypeDefaults::UnexpectedPositionalArgument,
                                         item,
@@ -281,6 +291,9 @@ impl Validator for DataclassArgsValidator {
                         if let Some(kwargs) = $args.kwargs {
                             if kwargs.len() != used_keys.len() {
                                 for (raw_key, value) in kwargs.iter() {
+                                    if fail_fast && !errors.is_empty() {
+                                        break;
+                                    }
                                     match raw_key.validate_str(true, false).map(ValidationMatch::into_inner) {
                                         Ok(either_str) => {
                                             if !used_keys.contains(either_str.as_cow()?.as_ref()) {
@@ -2,14 +2,13 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDate, PyDict, PyString};
 use speedate::{Date, Time};
-use strum::EnumMessage;
 
-use crate::build_tools::{is_strict, py_schema_error_type};
+use crate::build_tools::is_strict;
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDate, Input};
+use crate::input::{EitherDate, Input, TimestampUnit};
 
 use crate::tools::SchemaDict;
-use crate::validators::datetime::{NowConstraint, NowOp};
+use crate::validators::datetime::{extract_timestamp_unit, InputFormats, NowConstraint, NowOp};
 
 use super::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -18,6 +17,8 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationSta
 pub struct DateValidator {
     strict: bool,
     constraints: Option<DateConstraints>,
+    timestamp_unit: TimestampUnit,
+    formats: Option<InputFormats>,
 }
 
 impl BuildValidator for DateValidator {
@@ -31,6 +32,8 @@ impl BuildValidator for DateValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             constraints: DateConstraints::from_py(schema)?,
+            timestamp_unit: extract_timestamp_unit(schema, config)?,
+            formats: InputFormats::from_py(schema)?,
         }
         .into())
     }
@@ -46,14 +49,22 @@ impl Validator for DateValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
-        let date = match input.validate_date(strict) {
-            Ok(val_match) => val_match.unpack(state),
+        let iso_date = match input.validate_date(strict, self.timestamp_unit) {
+            Ok(val_match) => Ok(val_match.unpack(state)),
             // if the error was a parsing error, in lax mode we allow datetimes at midnight
             Err(line_errors @ ValError::LineErrors(..)) if !strict => {
                 state.floor_exactness(Exactness::Lax);
-                date_from_datetime(input)?.ok_or(line_errors)?
+                date_from_datetime(input, self.timestamp_unit).and_then(|date| date.ok_or(line_errors))
             }
-            Err(otherwise) => return Err(otherwise),
+            Err(otherwise) => Err(otherwise),
+        };
+        let date = match (iso_date, &self.formats) {
+            (Ok(date), _) => date,
+            (Err(err), Some(formats)) => {
+                state.floor_exactness(Exactness::Lax);
+                formats.parse_fallback(input, err, |dt| EitherDate::Raw(dt.date))?
+            }
+            (Err(err), None) => return Err(err),
         };
         if let Some(constraints) = &self.constraints {
             let raw_date = date.as_raw()?;
@@ -80,17 +91,22 @@ impl Validator for DateValidator {
             check_constraint!(gt, GreaterThan);
 
             if let Some(ref today_constraint) = constraints.today {
-                let offset = today_constraint.utc_offset(py)?;
-                let today = Date::today(offset).map_err(|e| {
-                    py_schema_error_type!("Date::today() error: {}", e.get_documentation().unwrap_or("unknown"))
-                })?;
+                let today = today_constraint.date_bound(py, state)?;
                 // `if let Some(c)` to match behaviour of gt/lt/le/ge
                 if let Some(c) = raw_date.partial_cmp(&today) {
                     let date_compliant = today_constraint.op.compare(c);
                     if !date_compliant {
-                        let error_type = match today_constraint.op {
-                            NowOp::Past => ErrorTypeDefaults::DatePast,
-                            NowOp::Future => ErrorTypeDefaults::DateFuture,
+                        let error_type = match (&today_constraint.op, today_constraint.delta.is_some()) {
+                            (NowOp::Past, false) => ErrorTypeDefaults::DatePast,
+                            (NowOp::Future, false) => ErrorTypeDefaults::DateFuture,
+                            (NowOp::Past, true) => ErrorType::DatePastBound {
+                                bound: today.to_string(),
+                                context: None,
+                            },
+                            (NowOp::Future, true) => ErrorType::DateFutureBound {
+                                bound: today.to_string(),
+                                context: None,
+                            },
                         };
                         return Err(ValError::new(error_type, input));
                     }
@@ -109,8 +125,15 @@ impl Validator for DateValidator {
 /// "exact date", e.g. has a zero time component.
 ///
 /// Ok(None) means that this is not relevant to dates (the input was not a datetime nor a string)
-fn date_from_datetime<'data>(input: &'data impl Input<'data>) -> Result<Option<EitherDate<'data>>, ValError> {
-    let either_dt = match input.validate_datetime(false, speedate::MicrosecondsPrecisionOverflowBehavior::Truncate) {
+fn date_from_datetime<'data>(
+    input: &'data impl Input<'data>,
+    timestamp_unit: TimestampUnit,
+) -> Result<Option<EitherDate<'data>>, ValError> {
+    let either_dt = match input.validate_datetime(
+        false,
+        speedate::MicrosecondsPrecisionOverflowBehavior::Truncate,
+        timestamp_unit,
+    ) {
         Ok(val_match) => val_match.into_inner(),
         // if the error was a parsing error, update the error type from DatetimeParsing to DateFromDatetimeParsing
         // and return it
@@ -1,25 +1,30 @@
+use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::once_cell::GILOnceCell;
 use pyo3::prelude::*;
-use pyo3::types::{PyDateTime, PyDict, PyString};
-use speedate::DateTime;
+use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDict, PyString};
+use speedate::{Date, DateTime};
 use std::cmp::Ordering;
+use std::str::FromStr;
 use strum::EnumMessage;
 
 use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::build_tools::{py_schema_err, schema_or_config_same};
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDateTime, Input};
+use crate::input::{DateTimeFormat, EitherDate, EitherDateTime, EitherTime, Input, TimestampUnit};
 
 use crate::tools::SchemaDict;
 
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct DateTimeValidator {
     strict: bool,
     constraints: Option<DateTimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    timestamp_unit: TimestampUnit,
+    formats: Option<InputFormats>,
+    tz_conversion: Option<TzConversion>,
 }
 
 pub(crate) fn extract_microseconds_precision(
@@ -38,6 +43,11 @@ pub(crate) fn extract_microseconds_precision(
         })
 }
 
+pub(crate) fn extract_timestamp_unit(schema: &PyDict, config: Option<&PyDict>) -> PyResult<TimestampUnit> {
+    let raw_unit: Option<&str> = schema_or_config_same(schema, config, intern!(schema.py(), "timestamp_unit"))?;
+    raw_unit.map_or_else(|| Ok(TimestampUnit::default()), TimestampUnit::from_str)
+}
+
 impl BuildValidator for DateTimeValidator {
     const EXPECTED_TYPE: &'static str = "datetime";
 
@@ -50,6 +60,9 @@ impl BuildValidator for DateTimeValidator {
             strict: is_strict(schema, config)?,
             constraints: DateTimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            timestamp_unit: extract_timestamp_unit(schema, config)?,
+            formats: InputFormats::from_py(schema)?,
+            tz_conversion: TzConversion::from_py(schema)?,
         }
         .into())
     }
@@ -65,9 +78,21 @@ impl Validator for DateTimeValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
-        let datetime = input
-            .validate_datetime(strict, self.microseconds_precision)?
-            .unpack(state);
+        let datetime = match (
+            input.validate_datetime(strict, self.microseconds_precision, self.timestamp_unit),
+            &self.formats,
+        ) {
+            (Ok(val_match), _) => val_match.unpack(state),
+            (Err(err), Some(formats)) => {
+                state.floor_exactness(Exactness::Lax);
+                formats.parse_fallback(input, err, EitherDateTime::Raw)?
+            }
+            (Err(err), None) => return Err(err),
+        };
+        let datetime = match &self.tz_conversion {
+            Some(tz_conversion) => tz_conversion.convert(py, datetime, input)?,
+            None => datetime,
+        };
         if let Some(constraints) = &self.constraints {
             // if we get an error from as_speedate, it's probably because the input datetime was invalid
             // specifically had an invalid tzinfo, hence here we return a validation error
@@ -103,17 +128,22 @@ impl Validator for DateTimeValidator {
             check_constraint!(gt, GreaterThan);
 
             if let Some(ref now_constraint) = constraints.now {
-                let offset = now_constraint.utc_offset(py)?;
-                let now = DateTime::now(offset).map_err(|e| {
-                    py_schema_error_type!("DateTime::now() error: {}", e.get_documentation().unwrap_or("unknown"))
-                })?;
+                let now = now_constraint.datetime_bound(py, state)?;
                 // `if let Some(c)` to match behaviour of gt/lt/le/ge
                 if let Some(c) = speedate_dt.partial_cmp(&now) {
                     let dt_compliant = now_constraint.op.compare(c);
                     if !dt_compliant {
-                        let error_type = match now_constraint.op {
-                            NowOp::Past => ErrorTypeDefaults::DatetimePast,
-                            NowOp::Future => ErrorTypeDefaults::DatetimeFuture,
+                        let error_type = match (&now_constraint.op, now_constraint.delta.is_some()) {
+                            (NowOp::Past, false) => ErrorTypeDefaults::DatetimePast,
+                            (NowOp::Future, false) => ErrorTypeDefaults::DatetimeFuture,
+                            (NowOp::Past, true) => ErrorType::DatetimePastBound {
+                                bound: now.to_string(),
+                                context: None,
+                            },
+                            (NowOp::Future, true) => ErrorType::DatetimeFutureBound {
+                                bound: now.to_string(),
+                                context: None,
+                            },
                         };
                         return Err(ValError::new(error_type, input));
                     }
@@ -196,6 +226,10 @@ impl NowOp {
 pub struct NowConstraint {
     pub op: NowOp,
     utc_offset: Option<i32>,
+    /// moves the bound away from now, e.g. `future` with minus 30 days means "within the last 30 days"
+    pub delta: Option<Py<PyDelta>>,
+    /// key in the validation context of a frozen now, for deterministic tests
+    context_key: Option<Py<PyString>>,
 }
 
 static TIME_LOCALTIME: GILOnceCell<PyObject> = GILOnceCell::new();
@@ -221,12 +255,103 @@ impl NowConstraint {
         }
     }
 
+    /// A frozen current date or datetime, from the `now_context_key` key of the validation context.
+    fn context_now<'s>(&self, state: &'s ValidationState) -> PyResult<Option<&'s PyAny>> {
+        let Some(ref key) = self.context_key else {
+            return Ok(None);
+        };
+        match state.extra().context.map(PyAny::downcast::<PyDict>) {
+            Some(Ok(context)) => context.get_item(key.as_ref(context.py())),
+            _ => Ok(None),
+        }
+    }
+
+    fn invalid_context_now(&self, now: &PyAny, expected: &str) -> PyErr {
+        let key = self.context_key.as_ref().map(ToString::to_string).unwrap_or_default();
+        let type_name = now.get_type().name().unwrap_or("unknown");
+        PyTypeError::new_err(format!(
+            "Invalid `{key}` in the validation context, expected {expected}, got {type_name}"
+        ))
+    }
+
+    /// The datetime to compare against, now or the frozen `now` from the context, moved by `delta`.
+    pub fn datetime_bound(&self, py: Python, state: &ValidationState) -> PyResult<DateTime> {
+        let offset = self.utc_offset(py)?;
+        let now = match self.context_now(state)? {
+            Some(now) => {
+                let now = now
+                    .downcast::<PyDateTime>()
+                    .map_err(|_| self.invalid_context_now(now, "a datetime"))?;
+                let mut now = EitherDateTime::Py(now).as_raw()?;
+                now.time.tz_offset = now.time.tz_offset.or(Some(offset));
+                now
+            }
+            None => DateTime::now(offset).map_err(|e| {
+                py_schema_error_type!("DateTime::now() error: {}", e.get_documentation().unwrap_or("unknown"))
+            })?,
+        };
+        match self.delta {
+            Some(ref delta) => {
+                let now = EitherDateTime::Raw(now).try_into_py(py)?;
+                let bound = now.call_method1(py, intern!(py, "__add__"), (delta,))?;
+                EitherDateTime::Py(bound.as_ref(py).downcast()?).as_raw()
+            }
+            None => Ok(now),
+        }
+    }
+
+    /// The date to compare against, today or the date of the frozen `now` from the context, moved by `delta`.
+    pub fn date_bound(&self, py: Python, state: &ValidationState) -> PyResult<Date> {
+        let offset = self.utc_offset(py)?;
+        let today = match self.context_now(state)? {
+            Some(now) => match now.downcast::<PyDateTime>() {
+                Ok(now) => {
+                    let now = EitherDateTime::Py(now).as_raw()?;
+                    match now.time.tz_offset {
+                        Some(_) => {
+                            now.in_timezone(offset)
+                                .map_err(|e| {
+                                    py_schema_error_type!(
+                                        "DateTime::in_timezone() error: {}",
+                                        e.get_documentation().unwrap_or("unknown")
+                                    )
+                                })?
+                                .date
+                        }
+                        None => now.date,
+                    }
+                }
+                Err(_) => {
+                    let now = now
+                        .downcast::<PyDate>()
+                        .map_err(|_| self.invalid_context_now(now, "a date or datetime"))?;
+                    EitherDate::Py(now).as_raw()?
+                }
+            },
+            None => Date::today(offset).map_err(|e| {
+                py_schema_error_type!("Date::today() error: {}", e.get_documentation().unwrap_or("unknown"))
+            })?,
+        };
+        match self.delta {
+            Some(ref delta) => {
+                let today = EitherDate::Raw(today).try_into_py(py)?;
+                let bound = today.call_method1(py, intern!(py, "__add__"), (delta,))?;
+                EitherDate::Py(bound.as_ref(py).downcast()?).as_raw()
+            }
+            None => Ok(today),
+        }
+    }
+
     pub fn from_py(schema: &PyDict) -> PyResult<Option<Self>> {
         let py = schema.py();
         match schema.get_as(intern!(py, "now_op"))? {
             Some(op) => Ok(Some(Self {
                 op: NowOp::from_str(op)?,
                 utc_offset: schema.get_as(intern!(py, "now_utc_offset"))?,
+                delta: schema.get_as::<&PyDelta>(intern!(py, "now_delta"))?.map(Into::into),
+                context_key: schema
+                    .get_as::<&PyString>(intern!(py, "now_context_key"))?
+                    .map(Into::into),
             })),
             None => Ok(None),
         }
@@ -285,3 +410,166 @@ impl TZConstraint {
         Ok(())
     }
 }
+
+/// `to_tz` and `naive_tz`: naive datetimes and times are interpreted as being in `naive_tz`, then aware values
+/// are converted to `to_tz`, both are either `'utc'` or a fixed offset in seconds.
+#[derive(Debug, Clone)]
+pub(super) struct TzConversion {
+    to_tz: Option<i32>,
+    naive_tz: Option<i32>,
+}
+
+impl TzConversion {
+    pub(super) fn from_py(schema: &PyDict) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let to_tz = Self::extract_offset(schema, intern!(py, "to_tz"))?;
+        let naive_tz = Self::extract_offset(schema, intern!(py, "naive_tz"))?;
+        if to_tz.is_some() || naive_tz.is_some() {
+            Ok(Some(Self { to_tz, naive_tz }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn extract_offset(schema: &PyDict, key: &PyString) -> PyResult<Option<i32>> {
+        let Some(value) = schema.get_item(key)? else {
+            return Ok(None);
+        };
+        let offset: i32 = match value.downcast::<PyString>() {
+            Ok(s) if s.to_str()? == "utc" => 0,
+            Ok(s) => return py_schema_err!("Invalid {} {:?}, expected \"utc\" or an offset in seconds", key, s),
+            Err(_) => value.extract()?,
+        };
+        if offset.abs() >= 86_400 {
+            return py_schema_err!("Invalid {} {}, the offset must be less than a day", key, offset);
+        }
+        Ok(Some(offset))
+    }
+
+    fn convert<'data>(
+        &self,
+        py: Python<'data>,
+        datetime: EitherDateTime<'data>,
+        input: &'data impl Input<'data>,
+    ) -> ValResult<EitherDateTime<'data>> {
+        let invalid = |error: String| ValError::new(ErrorType::DatetimeObjectInvalid { error, context: None }, input);
+        let mut dt = datetime.as_raw().map_err(|err| invalid(py_err_string(py, err)))?;
+        match (dt.time.tz_offset, self.naive_tz, self.to_tz) {
+            // aware values are left alone unless converting, so a `ZoneInfo` tzinfo isn't replaced by its offset
+            (None, None, _) | (Some(_), _, None) => return Ok(datetime),
+            (None, Some(naive_tz), _) => dt.time.tz_offset = Some(naive_tz),
+            (Some(_), _, Some(_)) => (),
+        }
+        if let Some(to_tz) = self.to_tz {
+            dt = dt
+                .in_timezone(to_tz)
+                .map_err(|err| invalid(err.get_documentation().unwrap_or_default().to_string()))?;
+        }
+        Ok(EitherDateTime::Raw(dt))
+    }
+
+    /// Times are converted by shifting the clock, wrapping around midnight.
+    pub(super) fn convert_time<'data>(&self, time: EitherTime<'data>) -> PyResult<EitherTime<'data>> {
+        let mut raw_time = time.as_raw()?;
+        let current_offset = match (raw_time.tz_offset, self.naive_tz, self.to_tz) {
+            (None, None, _) | (Some(_), _, None) => return Ok(time),
+            (Some(tz_offset), _, Some(_)) => tz_offset,
+            (None, Some(naive_tz), _) => naive_tz,
+        };
+        raw_time.tz_offset = Some(current_offset);
+        if let Some(to_tz) = self.to_tz {
+            let total_seconds = i64::from(raw_time.total_seconds()) + i64::from(to_tz - current_offset);
+            let seconds = total_seconds.rem_euclid(86_400);
+            raw_time.hour = (seconds / 3600) as u8;
+            raw_time.minute = (seconds % 3600 / 60) as u8;
+            raw_time.second = (seconds % 60) as u8;
+            raw_time.tz_offset = Some(to_tz);
+        }
+        Ok(EitherTime::Raw(raw_time))
+    }
+}
+
+/// strftime-style `formats` tried in order when a string input isn't valid ISO 8601.
+#[derive(Debug, Clone)]
+pub(super) struct InputFormats {
+    formats: Vec<DateTimeFormat>,
+}
+
+impl InputFormats {
+    pub(super) fn from_py(schema: &PyDict) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let Some(patterns) = schema.get_as::<Vec<&str>>(intern!(py, "formats"))? else {
+            return Ok(None);
+        };
+        if patterns.is_empty() {
+            return Ok(None);
+        }
+        let formats = patterns
+            .into_iter()
+            .map(|pattern| {
+                DateTimeFormat::new(pattern)
+                    .map_err(|err| py_schema_error_type!("Invalid format {:?}: {}", pattern, err))
+            })
+            .collect::<PyResult<_>>()?;
+        Ok(Some(Self { formats }))
+    }
+
+    /// Called with the error from ISO 8601 validation: if it's a parsing error, the string input is parsed with
+    /// each format in turn, if none match the parsing error is extended with the formats which were tried.
+    ///
+    /// `DateFromDatetimeInexact` also falls back since speedate reads strings of digits like `20220608`
+    /// as unix timestamps.
+    pub(super) fn parse_fallback<'d, T>(
+        &self,
+        input: &'d impl Input<'d>,
+        error: ValError,
+        pick: impl Fn(DateTime) -> T,
+    ) -> ValResult<T> {
+        let ValError::LineErrors(mut line_errors) = error else {
+            return Err(error);
+        };
+        let is_parsing_error = |error_type: &ErrorType| {
+            matches!(
+                error_type,
+                ErrorType::DateParsing { .. }
+                    | ErrorType::DateFromDatetimeParsing { .. }
+                    | ErrorType::TimeParsing { .. }
+                    | ErrorType::DatetimeParsing { .. }
+            )
+        };
+        let should_fallback = line_errors.iter().any(|line_error| {
+            is_parsing_error(&line_error.error_type)
+                || matches!(line_error.error_type, ErrorType::DateFromDatetimeInexact { .. })
+        });
+        if !should_fallback {
+            return Err(ValError::LineErrors(line_errors));
+        }
+
+        if let Ok(either_str) = input.validate_str(false, false) {
+            let input_str = either_str.into_inner();
+            let input_str = input_str.as_cow()?;
+            if let Some(dt) = self.formats.iter().find_map(|format| format.parse(&input_str)) {
+                return Ok(pick(dt));
+            }
+        }
+
+        let formats = self
+            .formats
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        for line_error in &mut line_errors {
+            match &mut line_error.error_type {
+                ErrorType::DateParsing { error, .. }
+                | ErrorType::DateFromDatetimeParsing { error, .. }
+                | ErrorType::TimeParsing { error, .. }
+                | ErrorType::DatetimeParsing { error, .. } => {
+                    *error = format!("{error}, and does not match any of the formats {formats}").into();
+                }
+                _ => (),
+            }
+        }
+        Err(ValError::LineErrors(line_errors))
+    }
+}
@@ -1,7 +1,9 @@
+use std::cmp::Ordering;
+
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{IntoPyDict, PyDict, PyTuple, PyType};
+use pyo3::types::{IntoPyDict, PyDict, PyString, PyType};
 use pyo3::{prelude::*, PyTypeInfo};
 
 use crate::build_tools::{is_strict, schema_or_config_same};
@@ -9,7 +11,7 @@ use crate::errors::ValError;
 use crate::errors::ValResult;
 use crate::errors::{ErrorType, InputValue};
 use crate::errors::{ErrorTypeDefaults, Number};
-use crate::input::Input;
+use crate::input::{DecimalValue, EitherDecimal, Input};
 use crate::tools::SchemaDict;
 
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -29,16 +31,42 @@ pub fn get_decimal_type(py: Python) -> Py<PyType> {
         .clone()
 }
 
+/// A constraint value along with its native form, the original object is kept for error messages.
+#[derive(Debug, Clone)]
+struct DecimalConstraint {
+    py_value: Py<PyAny>,
+    value: DecimalValue,
+}
+
+impl_py_gc_traverse!(DecimalConstraint { py_value });
+
+impl DecimalConstraint {
+    fn build(schema: &PyDict, key: &PyString) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        match schema.get_as::<&PyAny>(key)? {
+            Some(py_value) => {
+                // `Decimal(float)` is exact, so float constraints compare the same way they would in Python
+                let decimal = get_decimal_type(py).call1(py, (py_value,))?;
+                Ok(Some(Self {
+                    py_value: py_value.into(),
+                    value: DecimalValue::from_py_decimal(decimal.as_ref(py))?,
+                }))
+            }
+            None => Ok(None),
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct DecimalValidator {
     strict: bool,
     allow_inf_nan: bool,
     check_digits: bool,
-    multiple_of: Option<Py<PyAny>>,
-    le: Option<Py<PyAny>>,
-    lt: Option<Py<PyAny>>,
-    ge: Option<Py<PyAny>>,
-    gt: Option<Py<PyAny>>,
+    multiple_of: Option<DecimalConstraint>,
+    le: Option<DecimalConstraint>,
+    lt: Option<DecimalConstraint>,
+    ge: Option<DecimalConstraint>,
+    gt: Option<DecimalConstraint>,
     max_digits: Option<u64>,
     decimal_places: Option<u64>,
 }
@@ -64,11 +92,11 @@ impl BuildValidator for DecimalValidator {
             allow_inf_nan,
             check_digits: decimal_places.is_some() || max_digits.is_some(),
             decimal_places,
-            multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
-            le: schema.get_as(intern!(py, "le"))?,
-            lt: schema.get_as(intern!(py, "lt"))?,
-            ge: schema.get_as(intern!(py, "ge"))?,
-            gt: schema.get_as(intern!(py, "gt"))?,
+            multiple_of: DecimalConstraint::build(schema, intern!(py, "multiple_of"))?,
+            le: DecimalConstraint::build(schema, intern!(py, "le"))?,
+            lt: DecimalConstraint::build(schema, intern!(py, "lt"))?,
+            ge: DecimalConstraint::build(schema, intern!(py, "ge"))?,
+            gt: DecimalConstraint::build(schema, intern!(py, "gt"))?,
             max_digits,
         }
         .into())
@@ -83,35 +111,65 @@ impl_py_gc_traverse!(DecimalValidator {
     gt
 });
 
-fn extract_decimal_digits_info(decimal: &PyAny, normalized: bool, py: Python<'_>) -> ValResult<(u64, u64)> {
-    let mut normalized_decimal: Option<&PyAny> = None;
-    if normalized {
-        normalized_decimal = Some(decimal.call_method0(intern!(py, "normalize")).unwrap_or(decimal));
+impl DecimalValidator {
+    fn has_constraints(&self) -> bool {
+        !self.allow_inf_nan
+            || self.check_digits
+            || self.multiple_of.is_some()
+            || self.le.is_some()
+            || self.lt.is_some()
+            || self.ge.is_some()
+            || self.gt.is_some()
     }
-    let (_, digit_tuple, exponent): (&PyAny, &PyTuple, &PyAny) = normalized_decimal
-        .unwrap_or(decimal)
-        .call_method0(intern!(py, "as_tuple"))?
-        .extract()?;
 
-    // finite values have numeric exponent, we checked is_finite above
-    let exponent: i64 = exponent.extract()?;
-    let mut digits: u64 = u64::try_from(digit_tuple.len()).map_err(|e| ValError::InternalErr(e.into()))?;
-    let decimals;
-    if exponent >= 0 {
-        // A positive exponent adds that many trailing zeros.
-        digits += exponent as u64;
-        decimals = 0;
-    } else {
-        // If the absolute value of the negative exponent is larger than the
-        // number of digits, then it's the same as the number of digits,
-        // because it'll consume all the digits in digit_tuple and then
-        // add abs(exponent) - len(digit_tuple) leading zeros after the
-        // decimal point.
-        decimals = exponent.unsigned_abs();
-        digits = digits.max(decimals);
-    }
+    fn check_digits<'data>(&self, value: &DecimalValue, input: &'data impl Input<'data>) -> ValResult<()> {
+        let (Some((normalized_decimals, normalized_digits)), Some((decimals, digits))) =
+            (value.digits_info(true), value.digits_info(false))
+        else {
+            return Ok(());
+        };
+        if let Some(max_digits) = self.max_digits {
+            if (digits > max_digits) && (normalized_digits > max_digits) {
+                return Err(ValError::new(
+                    ErrorType::DecimalMaxDigits {
+                        max_digits,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+
+        if let Some(decimal_places) = self.decimal_places {
+            if (decimals > decimal_places) && (normalized_decimals > decimal_places) {
+                return Err(ValError::new(
+                    ErrorType::DecimalMaxPlaces {
+                        decimal_places,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+
+            if let Some(max_digits) = self.max_digits {
+                let whole_digits = digits.saturating_sub(decimals);
+                let max_whole_digits = max_digits.saturating_sub(decimal_places);
+
+                let normalized_whole_digits = normalized_digits.saturating_sub(normalized_decimals);
 
-    Ok((decimals, digits))
+                if (whole_digits > max_whole_digits) && (normalized_whole_digits > max_whole_digits) {
+                    return Err(ValError::new(
+                        ErrorType::DecimalWholeDigits {
+                            whole_digits: max_whole_digits,
+                            context: None,
+                        },
+                        input,
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
 }
 
 impl Validator for DecimalValidator {
@@ -122,75 +180,24 @@ impl Validator for DecimalValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let decimal = input.validate_decimal(state.strict_or(self.strict), py)?;
+        if !self.has_constraints() {
+            return Ok(decimal.try_into_py(py)?);
+        }
+        let value = decimal.as_value()?;
 
         if !self.allow_inf_nan || self.check_digits {
-            if !decimal.call_method0(intern!(py, "is_finite"))?.extract()? {
+            if !value.is_finite() {
                 return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
             }
 
             if self.check_digits {
-                if let Ok((normalized_decimals, normalized_digits)) = extract_decimal_digits_info(decimal, true, py) {
-                    if let Ok((decimals, digits)) = extract_decimal_digits_info(decimal, false, py) {
-                        if let Some(max_digits) = self.max_digits {
-                            if (digits > max_digits) & (normalized_digits > max_digits) {
-                                return Err(ValError::new(
-                                    ErrorType::DecimalMaxDigits {
-                                        max_digits,
-                                        context: None,
-                                    },
-                                    input,
-                                ));
-                            }
-                        }
-
-                        if let Some(decimal_places) = self.decimal_places {
-                            if (decimals > decimal_places) & (normalized_decimals > decimal_places) {
-                                return Err(ValError::new(
-                                    ErrorType::DecimalMaxPlaces {
-                                        decimal_places,
-                                        context: None,
-                                    },
-                                    input,
-                                ));
-                            }
-
-                            if let Some(max_digits) = self.max_digits {
-                                let whole_digits = digits.saturating_sub(decimals);
-                                let max_whole_digits = max_digits.saturating_sub(decimal_places);
-
-                                let normalized_whole_digits = normalized_digits.saturating_sub(normalized_decimals);
-                                let normalized_max_whole_digits = max_digits.saturating_sub(decimal_places);
-
-                                if (whole_digits > max_whole_digits)
-                                    & (normalized_whole_digits > normalized_max_whole_digits)
-                                {
-                                    return Err(ValError::new(
-                                        ErrorType::DecimalWholeDigits {
-                                            whole_digits: max_whole_digits,
-                                            context: None,
-                                        },
-                                        input,
-                                    ));
-                                }
-                            }
-                        }
-                    }
-                };
+                self.check_digits(&value, input)?;
             }
         }
 
         if let Some(multiple_of) = &self.multiple_of {
-            // fraction = (decimal / multiple_of) % 1
-            let fraction: &PyAny = unsafe {
-                let division = PyObject::from_owned_ptr_or_err(
-                    py,
-                    pyo3::ffi::PyNumber_TrueDivide(decimal.as_ptr(), multiple_of.as_ptr()),
-                )?;
-                let one = 1.to_object(py);
-                py.from_owned_ptr_or_err(pyo3::ffi::PyNumber_Remainder(division.as_ptr(), one.as_ptr()))?
-            };
-            let zero = 0.to_object(py);
-            if !fraction.eq(&zero)? {
+            if !value.is_multiple_of(&multiple_of.value) {
+                let multiple_of = &multiple_of.py_value;
                 return Err(ValError::new(
                     ErrorType::MultipleOf {
                         multiple_of: multiple_of.to_string().into(),
@@ -201,19 +208,10 @@ impl Validator for DecimalValidator {
             }
         }
 
-        // Decimal raises DecimalOperation when comparing NaN, so if it's necessary to compare
-        // the value to a number, we need to check for NaN first. We cache the result on the first
-        // time we check it.
-        let mut is_nan: Option<bool> = None;
-        let mut is_nan = || -> PyResult<bool> {
-            match is_nan {
-                Some(is_nan) => Ok(is_nan),
-                None => Ok(*is_nan.insert(decimal.call_method0(intern!(py, "is_nan"))?.extract()?)),
-            }
-        };
-
+        // comparisons with NaN are always invalid
         if let Some(le) = &self.le {
-            if is_nan()? || !decimal.le(le)? {
+            if !matches!(value.compare(&le.value), Some(Ordering::Less | Ordering::Equal)) {
+                let le = &le.py_value;
                 return Err(ValError::new(
                     ErrorType::LessThanEqual {
                         le: Number::String(le.to_string()),
@@ -224,7 +222,8 @@ impl Validator for DecimalValidator {
             }
         }
         if let Some(lt) = &self.lt {
-            if is_nan()? || !decimal.lt(lt)? {
+            if value.compare(&lt.value) != Some(Ordering::Less) {
+                let lt = &lt.py_value;
                 return Err(ValError::new(
                     ErrorType::LessThan {
                         lt: Number::String(lt.to_string()),
@@ -235,7 +234,8 @@ impl Validator for DecimalValidator {
             }
         }
         if let Some(ge) = &self.ge {
-            if is_nan()? || !decimal.ge(ge)? {
+            if !matches!(value.compare(&ge.value), Some(Ordering::Greater | Ordering::Equal)) {
+                let ge = &ge.py_value;
                 return Err(ValError::new(
                     ErrorType::GreaterThanEqual {
                         ge: Number::String(ge.to_string()),
@@ -246,7 +246,8 @@ impl Validator for DecimalValidator {
             }
         }
         if let Some(gt) = &self.gt {
-            if is_nan()? || !decimal.gt(gt)? {
+            if value.compare(&gt.value) != Some(Ordering::Greater) {
+                let gt = &gt.py_value;
                 return Err(ValError::new(
                     ErrorType::GreaterThan {
                         gt: Number::String(gt.to_string()),
@@ -257,7 +258,7 @@ impl Validator for DecimalValidator {
             }
         }
 
-        Ok(decimal.into())
+        Ok(decimal.try_into_py(py)?)
     }
 
     fn get_name(&self) -> &str {
@@ -265,6 +266,19 @@ impl Validator for DecimalValidator {
     }
 }
 
+/// Parse a string natively where possible, falling back to `decimal.Decimal` for anything
+/// the Rust parser doesn't handle, which includes all invalid input.
+pub(crate) fn create_decimal_from_str<'a>(
+    s: &str,
+    input: &'a impl Input<'a>,
+    py: Python<'a>,
+) -> ValResult<EitherDecimal<'a>> {
+    match DecimalValue::parse(s) {
+        Some(value) => Ok(EitherDecimal::Rust(value)),
+        None => create_decimal(PyString::new(py, s), input, py).map(EitherDecimal::Py),
+    }
+}
+
 pub(crate) fn create_decimal<'a>(arg: &'a PyAny, input: &'a impl Input<'a>, py: Python<'a>) -> ValResult<&'a PyAny> {
     let decimal_type_obj: Py<PyType> = get_decimal_type(py);
     decimal_type_obj
@@ -2,7 +2,7 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_fail_fast, is_strict};
 use crate::errors::{AsLocItem, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::{
@@ -19,6 +19,7 @@ use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuild
 #[derive(Debug)]
 pub struct DictValidator {
     strict: bool,
+    fail_fast: bool,
     key_validator: Box<CombinedValidator>,
     value_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
@@ -51,6 +52,7 @@ impl BuildValidator for DictValidator {
         );
         Ok(Self {
             strict: is_strict(schema, config)?,
+            fail_fast: is_fail_fast(schema, config)?,
             key_validator,
             value_validator,
             min_length: schema.get_as(intern!(py, "min_length"))?,
@@ -77,18 +79,30 @@ impl Validator for DictValidator {
         let dict = input.validate_dict(strict)?;
         match dict {
             GenericMapping::PyDict(py_dict) => {
-                self.validate_generic_mapping(py, input, DictGenericIterator::new(py_dict)?, state)
+                self.validate_generic_mapping(py, input, DictGenericIterator::new(py_dict)?, None, state)
             }
             GenericMapping::PyMapping(mapping) => {
                 state.floor_exactness(super::Exactness::Lax);
-                self.validate_generic_mapping(py, input, MappingGenericIterator::new(mapping)?, state)
+                self.validate_generic_mapping(py, input, MappingGenericIterator::new(mapping)?, None, state)
             }
             GenericMapping::StringMapping(dict) => {
-                self.validate_generic_mapping(py, input, StringMappingGenericIterator::new(dict)?, state)
+                self.validate_generic_mapping(py, input, StringMappingGenericIterator::new(dict)?, None, state)
             }
             GenericMapping::PyGetAttr(_, _) => unreachable!(),
             GenericMapping::JsonObject(json_object) => {
-                self.validate_generic_mapping(py, input, JsonObjectGenericIterator::new(json_object)?, state)
+                // only JSON input can be partial
+                let partial_last_index = if state.extra().allow_partial {
+                    json_object.len().checked_sub(1)
+                } else {
+                    None
+                };
+                self.validate_generic_mapping(
+                    py,
+                    input,
+                    JsonObjectGenericIterator::new(json_object)?,
+                    partial_last_index,
+                    state,
+                )
             }
         }
     }
@@ -104,36 +118,51 @@ impl DictValidator {
         py: Python<'data>,
         input: &'data impl Input<'data>,
         mapping_iter: impl Iterator<Item = ValResult<(impl BorrowInput + AsLocItem + 'data, impl BorrowInput + 'data)>>,
+        partial_last_index: Option<usize>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let output = PyDict::new(py);
         let mut errors: Vec<ValLineError> = Vec::new();
+        let fail_fast = state.fail_fast_or(self.fail_fast);
 
         let key_validator = self.key_validator.as_ref();
         let value_validator = self.value_validator.as_ref();
-        for item_result in mapping_iter {
+        for (index, item_result) in mapping_iter.enumerate() {
             let (key, value) = item_result?;
-            let output_key = match key_validator.validate(py, key.borrow_input(), state) {
+            let is_last_partial = partial_last_index == Some(index);
+            let output_key = match state.with_allow_partial(is_last_partial, |state| {
+                key_validator.validate(py, key.borrow_input(), state)
+            }) {
                 Ok(value) => Some(value),
+                Err(ValError::LineErrors(_)) if is_last_partial => continue,
                 Err(ValError::LineErrors(line_errors)) => {
                     for err in line_errors {
                         // these are added in reverse order so [key] is shunted along by the second call
                         errors.push(
-                            err.with_outer_location("[key]".into())
+                            err.with_outer_label("[key]".into())
                                 .with_outer_location(key.as_loc_item()),
                         );
                     }
+                    if fail_fast {
+                        break;
+                    }
                     None
                 }
                 Err(ValError::Omit) => continue,
                 Err(err) => return Err(err),
             };
-            let output_value = match value_validator.validate(py, value.borrow_input(), state) {
+            let output_value = match state.with_allow_partial(is_last_partial, |state| {
+                value_validator.validate(py, value.borrow_input(), state)
+            }) {
                 Ok(value) => Some(value),
+                Err(ValError::LineErrors(_)) if is_last_partial => continue,
                 Err(ValError::LineErrors(line_errors)) => {
                     for err in line_errors {
                         errors.push(err.with_outer_location(key.as_loc_item()));
                     }
+                    if fail_fast {
+                        break;
+                    }
                     None
                 }
                 Err(ValError::Omit) => continue,
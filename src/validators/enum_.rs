@@ -0,0 +1,238 @@
+// Validator for Enums, so named because "enum" is a reserved keyword in Rust.
+use std::marker::PhantomData;
+
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyFloat, PyInt, PyList, PyString, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::{safe_repr, SchemaDict};
+
+use super::is_instance::class_repr;
+use super::literal::{expected_repr_name, LiteralLookup};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+#[derive(Debug, Clone)]
+pub struct BuildEnumValidator;
+
+impl BuildValidator for BuildEnumValidator {
+    const EXPECTED_TYPE: &'static str = "enum";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let members: &PyList = schema.get_as_req(intern!(schema.py(), "members"))?;
+        if members.is_empty() {
+            return py_schema_err!("`members` should have length > 0");
+        }
+
+        let py = schema.py();
+        let value_str = intern!(py, "value");
+        let expected: Vec<(&PyAny, PyObject)> = members
+            .iter()
+            .map(|v| Ok((v.getattr(value_str)?, v.into())))
+            .collect::<PyResult<_>>()?;
+
+        let repr_args: Vec<String> = expected
+            .iter()
+            .map(|(k, _)| k.repr()?.extract())
+            .collect::<PyResult<_>>()?;
+
+        let class: &PyType = schema.get_as_req(intern!(py, "cls"))?;
+        let class_repr = class_repr(schema, class)?;
+
+        let lookup = LiteralLookup::new(py, expected.into_iter())?;
+
+        macro_rules! build {
+            ($variant:ident, $vv:ty, $name_prefix:literal) => {
+                CombinedValidator::$variant(EnumValidator {
+                    phantom: PhantomData::<$vv>,
+                    class: class.into(),
+                    lookup,
+                    missing: schema.get_as(intern!(py, "missing"))?,
+                    expected_repr: expected_repr_name(repr_args, "").0,
+                    strict: is_strict(schema, config)?,
+                    class_repr: class_repr.clone(),
+                    name: format!("{}[{class_repr}]", $name_prefix),
+                })
+            };
+        }
+
+        let sub_type: Option<&str> = schema.get_as(intern!(py, "sub_type"))?;
+        match sub_type {
+            Some("int") => Ok(build!(IntEnum, IntEnumValidator, "int-enum")),
+            Some("str") => Ok(build!(StrEnum, StrEnumValidator, "str-enum")),
+            Some("float") => Ok(build!(FloatEnum, FloatEnumValidator, "float-enum")),
+            Some(_) => py_schema_err!("`sub_type` must be one of: 'int', 'str', 'float' or None"),
+            None => Ok(build!(PlainEnum, PlainEnumValidator, "enum")),
+        }
+    }
+}
+
+pub trait EnumValidateValue: std::fmt::Debug + Clone + Send + Sync {
+    /// Find the enum member matching `input`, `Ok(None)` means no match was found
+    fn validate_value<'data>(
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        lookup: &LiteralLookup<PyObject>,
+        strict: bool,
+    ) -> ValResult<Option<PyObject>>;
+}
+
+#[derive(Debug, Clone)]
+pub struct EnumValidator<T: EnumValidateValue> {
+    phantom: PhantomData<T>,
+    class: Py<PyType>,
+    lookup: LiteralLookup<PyObject>,
+    missing: Option<PyObject>,
+    expected_repr: String,
+    strict: bool,
+    class_repr: String,
+    name: String,
+}
+
+impl<T: EnumValidateValue> crate::py_gc::PyGcTraverse for EnumValidator<T> {
+    fn py_gc_traverse(&self, visit: &pyo3::PyVisit<'_>) -> Result<(), pyo3::PyTraverseError> {
+        self.class.py_gc_traverse(visit)?;
+        self.lookup.py_gc_traverse(visit)?;
+        self.missing.py_gc_traverse(visit)?;
+        Ok(())
+    }
+}
+
+impl<T: EnumValidateValue> Validator for EnumValidator<T> {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let class = self.class.as_ref(py);
+        if input.input_is_exact_instance(class) {
+            return Ok(input.to_object(py));
+        }
+        // enums with members can't be subclassed, so this is a member of a subclass of a member-less base enum
+        if let Some(instance) = input.input_is_instance(class) {
+            state.floor_exactness(Exactness::Strict);
+            return Ok(instance.to_object(py));
+        }
+        let strict = state.strict_or(self.strict);
+        if strict && input.is_python() {
+            return Err(ValError::new(
+                ErrorType::IsInstanceOf {
+                    class: self.class_repr.clone(),
+                    context: None,
+                },
+                input,
+            ));
+        }
+
+        state.floor_exactness(Exactness::Lax);
+
+        if let Some(v) = T::validate_value(py, input, &self.lookup, strict)? {
+            return Ok(v);
+        } else if let Some(ref missing) = self.missing {
+            // exceptions raised by `_missing_` propagate as they would from `MyEnum(value)`
+            let enum_value = missing.as_ref(py).call1((input.to_object(py),))?;
+            // check enum_value is an instance of the class like
+            // https://github.com/python/cpython/blob/v3.12.0/Lib/enum.py#L1145
+            if enum_value.is_instance(class)? {
+                return Ok(enum_value.into());
+            } else if !enum_value.is_none() {
+                let type_error = PyTypeError::new_err(format!(
+                    "error in {}._missing_: returned {} instead of None or a valid member",
+                    class.name().unwrap_or("<Unknown>"),
+                    safe_repr(enum_value)
+                ));
+                return Err(type_error.into());
+            }
+        }
+        Err(ValError::new(
+            ErrorType::Enum {
+                expected: self.expected_repr.clone(),
+                context: None,
+            },
+            input,
+        ))
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlainEnumValidator;
+
+impl EnumValidateValue for PlainEnumValidator {
+    fn validate_value<'data>(
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        lookup: &LiteralLookup<PyObject>,
+        strict: bool,
+    ) -> ValResult<Option<PyObject>> {
+        match lookup.validate(py, input)? {
+            Some((_, v)) => Ok(Some(v.clone_ref(py))),
+            None => {
+                if !strict && input.is_python() {
+                    let py_input = input.to_object(py);
+                    let py_input = py_input.as_ref(py);
+                    // lax mode allows subclasses of str and int, and floats for int values
+                    if py_input.is_instance_of::<PyString>() {
+                        return Ok(lookup.validate_str(input, false)?.map(|v| v.clone_ref(py)));
+                    } else if py_input.is_instance_of::<PyInt>() || py_input.is_instance_of::<PyFloat>() {
+                        return Ok(lookup.validate_int(py, input, false)?.map(|v| v.clone_ref(py)));
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IntEnumValidator;
+
+impl EnumValidateValue for IntEnumValidator {
+    fn validate_value<'data>(
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        lookup: &LiteralLookup<PyObject>,
+        strict: bool,
+    ) -> ValResult<Option<PyObject>> {
+        Ok(lookup.validate_int(py, input, strict)?.map(|v| v.clone_ref(py)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StrEnumValidator;
+
+impl EnumValidateValue for StrEnumValidator {
+    fn validate_value<'data>(
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        lookup: &LiteralLookup<PyObject>,
+        strict: bool,
+    ) -> ValResult<Option<PyObject>> {
+        Ok(lookup.validate_str(input, strict)?.map(|v| v.clone_ref(py)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FloatEnumValidator;
+
+impl EnumValidateValue for FloatEnumValidator {
+    fn validate_value<'data>(
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        lookup: &LiteralLookup<PyObject>,
+        strict: bool,
+    ) -> ValResult<Option<PyObject>> {
+        Ok(lookup.validate_float(py, input, strict)?.map(|v| v.clone_ref(py)))
+    }
+}
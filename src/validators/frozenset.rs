@@ -14,6 +14,7 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
 #[derive(Debug)]
 pub struct FrozenSetValidator {
     strict: bool,
+    fail_fast: bool,
     item_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
@@ -50,6 +51,7 @@ impl Validator for FrozenSetValidator {
             "Frozenset",
             &self.item_validator,
             state,
+            state.fail_fast_or(self.fail_fast),
         )?;
         min_length_check!(input, "Frozenset", self.min_length, f_set);
         Ok(f_set.into_py(py))
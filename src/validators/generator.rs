@@ -209,6 +209,9 @@ pub struct InternalValidator {
     // TODO, do we need data?
     data: Option<Py<PyDict>>,
     strict: Option<bool>,
+    fail_fast: Option<bool>,
+    loc_by_alias: Option<bool>,
+    allow_partial: bool,
     from_attributes: Option<bool>,
     context: Option<PyObject>,
     self_instance: Option<PyObject>,
@@ -240,6 +243,9 @@ impl InternalValidator {
             validator,
             data: extra.data.map(|d| d.into_py(py)),
             strict: extra.strict,
+            fail_fast: extra.fail_fast,
+            loc_by_alias: extra.loc_by_alias,
+            allow_partial: extra.allow_partial,
             from_attributes: extra.from_attributes,
             context: extra.context.map(|d| d.into_py(py)),
             self_instance: extra.self_instance.map(|d| d.into_py(py)),
@@ -263,6 +269,9 @@ impl InternalValidator {
             input_type: self.validation_mode,
             data: self.data.as_ref().map(|data| data.as_ref(py)),
             strict: self.strict,
+            fail_fast: self.fail_fast,
+            loc_by_alias: self.loc_by_alias,
+            allow_partial: self.allow_partial,
             from_attributes: self.from_attributes,
             context: self.context.as_ref().map(|data| data.as_ref(py)),
             self_instance: self.self_instance.as_ref().map(|data| data.as_ref(py)),
@@ -297,6 +306,9 @@ impl InternalValidator {
             input_type: self.validation_mode,
             data: self.data.as_ref().map(|data| data.as_ref(py)),
             strict: self.strict,
+            fail_fast: self.fail_fast,
+            loc_by_alias: self.loc_by_alias,
+            allow_partial: self.allow_partial,
             from_attributes: self.from_attributes,
             context: self.context.as_ref().map(|data| data.as_ref(py)),
             self_instance: self.self_instance.as_ref().map(|data| data.as_ref(py)),
@@ -36,13 +36,7 @@ impl BuildValidator for IsInstanceValidator {
             return py_schema_err!("'cls' must be valid as the first argument to 'isinstance'");
         }
 
-        let class_repr = match schema.get_as(intern!(py, "cls_repr"))? {
-            Some(s) => s,
-            None => match class.extract::<&PyType>() {
-                Ok(t) => t.name()?.to_string(),
-                Err(_) => class.repr()?.extract()?,
-            },
-        };
+        let class_repr = class_repr(schema, class)?;
         let name = format!("{}[{class_repr}]", Self::EXPECTED_TYPE);
         Ok(Self {
             class: class.into(),
@@ -86,3 +80,13 @@ impl Validator for IsInstanceValidator {
         &self.name
     }
 }
+
+pub fn class_repr(schema: &PyDict, class: &PyAny) -> PyResult<String> {
+    match schema.get_as(intern!(schema.py(), "cls_repr"))? {
+        Some(s) => Ok(s),
+        None => match class.extract::<&PyType>() {
+            Ok(t) => Ok(t.name()?.to_string()),
+            Err(_) => Ok(class.repr()?.extract()?),
+        },
+    }
+}
@@ -8,6 +8,7 @@ use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValRes
 use crate::input::{EitherBytes, Input, InputType, ValidationMatch};
 use crate::tools::SchemaDict;
 
+use super::bytes::ValBytesMode;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -77,7 +78,7 @@ impl Validator for JsonValidator {
 }
 
 pub fn validate_json_bytes<'data>(input: &'data impl Input<'data>) -> ValResult<ValidationMatch<EitherBytes<'data>>> {
-    match input.validate_bytes(false) {
+    match input.validate_bytes(false, ValBytesMode::Utf8) {
         Ok(v_match) => Ok(v_match),
         Err(ValError::LineErrors(e)) => Err(ValError::LineErrors(
             e.into_iter().map(map_bytes_error).collect::<Vec<_>>(),
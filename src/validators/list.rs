@@ -13,6 +13,7 @@ use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuild
 #[derive(Debug)]
 pub struct ListValidator {
     strict: bool,
+    fail_fast: bool,
     item_validator: Option<Box<CombinedValidator>>,
     min_length: Option<usize>,
     max_length: Option<usize>,
@@ -104,6 +105,7 @@ impl BuildValidator for ListValidator {
         let item_validator = get_items_schema(schema, config, definitions)?.map(Box::new);
         Ok(Self {
             strict: crate::build_tools::is_strict(schema, config)?,
+            fail_fast: crate::build_tools::is_fail_fast(schema, config)?,
             item_validator,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
@@ -131,7 +133,10 @@ impl Validator for ListValidator {
         state.floor_exactness(exactness);
 
         let output = match self.item_validator {
-            Some(ref v) => seq.validate_to_vec(py, input, self.max_length, "List", v, state)?,
+            Some(ref v) => {
+                let fail_fast = state.fail_fast_or(self.fail_fast);
+                seq.validate_to_vec(py, input, self.max_length, "List", v, state, fail_fast)?
+            }
             None => match seq {
                 GenericIterable::List(list) => {
                     length_check!(input, "List", self.min_length, self.max_length, list);
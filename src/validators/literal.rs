@@ -146,6 +146,66 @@ impl<T: Debug> LiteralLookup<T> {
         };
         Ok(None)
     }
+
+    /// Look up an int value, `strict` is passed through to `validate_int` so lax inputs like `"1"` can match
+    pub fn validate_int<'data, I: Input<'data>>(
+        &self,
+        py: Python<'data>,
+        input: &'data I,
+        strict: bool,
+    ) -> ValResult<Option<&T>> {
+        if let Some(expected_ints) = &self.expected_int {
+            if let Ok(either_int) = input.validate_int(strict) {
+                let int = either_int.into_inner().into_i64(py)?;
+                if let Some(id) = expected_ints.get(&int) {
+                    return Ok(Some(&self.values[*id]));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Look up a str value, `strict` is passed through to `validate_str` so lax inputs like `b"foo"` can match
+    pub fn validate_str<'data, I: Input<'data>>(&self, input: &'data I, strict: bool) -> ValResult<Option<&T>> {
+        if let Some(expected_strings) = &self.expected_str {
+            if let Ok(either_str) = input.validate_str(strict, false) {
+                let either_str = either_str.into_inner();
+                let cow = either_str.as_cow()?;
+                if let Some(id) = expected_strings.get(cow.as_ref()) {
+                    return Ok(Some(&self.values[*id]));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Look up a float value, floats aren't given a specialized lookup so they're found in `expected_py`,
+    /// integral floats are also checked against `expected_int`
+    pub fn validate_float<'data, I: Input<'data>>(
+        &self,
+        py: Python<'data>,
+        input: &'data I,
+        strict: bool,
+    ) -> ValResult<Option<&T>> {
+        let float = match input.validate_float(strict) {
+            Ok(either_float) => either_float.into_inner().as_f64(),
+            Err(_) => return Ok(None),
+        };
+        if let Some(expected_ints) = &self.expected_int {
+            if float.fract() == 0.0 && float >= i64::MIN as f64 && float <= i64::MAX as f64 {
+                if let Some(id) = expected_ints.get(&(float as i64)) {
+                    return Ok(Some(&self.values[*id]));
+                }
+            }
+        }
+        if let Some(expected_py) = &self.expected_py {
+            if let Some(v) = expected_py.as_ref(py).get_item(float)? {
+                let id: usize = v.extract().unwrap();
+                return Ok(Some(&self.values[id]));
+            }
+        }
+        Ok(None)
+    }
 }
 
 impl<T: PyGcTraverse + Debug> PyGcTraverse for LiteralLookup<T> {
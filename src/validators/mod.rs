@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::fmt::Debug;
 
 use enum_dispatch::enum_dispatch;
@@ -11,7 +12,7 @@ use pyo3::{intern, PyTraverseError, PyVisit};
 use crate::build_tools::{py_schema_err, py_schema_error_type, SchemaError};
 use crate::definitions::{Definitions, DefinitionsBuilder};
 use crate::errors::{LocItem, ValError, ValResult, ValidationError};
-use crate::input::{Input, InputType, StringMapping};
+use crate::input::{complete_partial_json, Input, InputType, StringMapping};
 use crate::py_gc::PyGcTraverse;
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
@@ -19,10 +20,11 @@ use crate::tools::SchemaDict;
 mod any;
 mod arguments;
 mod bool;
-mod bytes;
+pub(crate) mod bytes;
 mod call;
 mod callable;
 mod chain;
+mod complex;
 mod custom_error;
 mod dataclass;
 mod date;
@@ -30,6 +32,7 @@ mod datetime;
 pub(crate) mod decimal;
 mod definitions;
 mod dict;
+mod enum_;
 mod float;
 mod frozenset;
 mod function;
@@ -50,6 +53,7 @@ mod set;
 mod string;
 mod time;
 mod timedelta;
+mod timezone;
 mod tuple;
 mod typed_dict;
 mod union;
@@ -153,7 +157,8 @@ impl SchemaValidator {
         Ok((cls, init_args))
     }
 
-    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, fail_fast=None, loc_by_alias=None))]
     pub fn validate_python(
         &self,
         py: Python,
@@ -162,6 +167,8 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
+        fail_fast: Option<bool>,
+        loc_by_alias: Option<bool>,
     ) -> PyResult<PyObject> {
         self._validate(
             py,
@@ -171,11 +178,15 @@ impl SchemaValidator {
             from_attributes,
             context,
             self_instance,
+            fail_fast,
+            loc_by_alias,
+            false,
         )
         .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
     }
 
-    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, fail_fast=None))]
     pub fn isinstance_python(
         &self,
         py: Python,
@@ -184,6 +195,7 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
+        fail_fast: Option<bool>,
     ) -> PyResult<bool> {
         match self._validate(
             py,
@@ -193,6 +205,9 @@ impl SchemaValidator {
             from_attributes,
             context,
             self_instance,
+            fail_fast,
+            None,
+            false,
         ) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
@@ -202,7 +217,8 @@ impl SchemaValidator {
         }
     }
 
-    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None, fail_fast=None, loc_by_alias=None, allow_partial=false, error_positions=false))]
     pub fn validate_json(
         &self,
         py: Python,
@@ -210,40 +226,78 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
+        fail_fast: Option<bool>,
+        loc_by_alias: Option<bool>,
+        allow_partial: bool,
+        error_positions: bool,
     ) -> PyResult<PyObject> {
-        let r = match json::validate_json_bytes(input) {
-            Ok(v_match) => self._validate_json(
-                py,
-                input,
-                v_match.into_inner().as_slice(),
-                strict,
-                context,
-                self_instance,
-            ),
-            Err(err) => Err(err),
+        let json_bytes = json::validate_json_bytes(input)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Json))?
+            .into_inner();
+        let json_data = if allow_partial {
+            complete_partial_json(json_bytes.as_slice())
+        } else {
+            Cow::Borrowed(json_bytes.as_slice())
         };
-        r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
+        self._validate_json(
+            py,
+            input,
+            &json_data,
+            strict,
+            context,
+            self_instance,
+            fail_fast,
+            loc_by_alias,
+            allow_partial,
+        )
+        .map_err(|e| {
+            if error_positions {
+                ValidationError::from_json_val_error(
+                    py,
+                    self.title.clone_ref(py),
+                    e,
+                    &json_data,
+                    self.hide_input_in_errors,
+                    self.validation_error_cause,
+                )
+            } else {
+                self.prepare_validation_err(py, e, InputType::Json)
+            }
+        })
     }
 
-    #[pyo3(signature = (input, *, strict=None, context=None))]
+    #[pyo3(signature = (input, *, strict=None, context=None, fail_fast=None, loc_by_alias=None))]
     pub fn validate_strings(
         &self,
         py: Python,
         input: &PyAny,
         strict: Option<bool>,
         context: Option<&PyAny>,
+        fail_fast: Option<bool>,
+        loc_by_alias: Option<bool>,
     ) -> PyResult<PyObject> {
         let t = InputType::String;
         let string_mapping = StringMapping::new_value(input).map_err(|e| self.prepare_validation_err(py, e, t))?;
 
-        match self._validate(py, &string_mapping, t, strict, None, context, None) {
+        match self._validate(
+            py,
+            &string_mapping,
+            t,
+            strict,
+            None,
+            context,
+            None,
+            fail_fast,
+            loc_by_alias,
+            false,
+        ) {
             Ok(r) => Ok(r),
             Err(e) => Err(self.prepare_validation_err(py, e, t)),
         }
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (obj, field_name, field_value, *, strict=None, from_attributes=None, context=None))]
+    #[pyo3(signature = (obj, field_name, field_value, *, strict=None, from_attributes=None, context=None, fail_fast=None))]
     pub fn validate_assignment(
         &self,
         py: Python,
@@ -253,11 +307,15 @@ impl SchemaValidator {
         strict: Option<bool>,
         from_attributes: Option<bool>,
         context: Option<&PyAny>,
+        fail_fast: Option<bool>,
     ) -> PyResult<PyObject> {
         let extra = Extra {
             input_type: InputType::Python,
             data: None,
             strict,
+            fail_fast,
+            loc_by_alias: None,
+            allow_partial: false,
             from_attributes,
             context,
             self_instance: None,
@@ -276,6 +334,9 @@ impl SchemaValidator {
             input_type: InputType::Python,
             data: None,
             strict,
+            fail_fast: None,
+            loc_by_alias: None,
+            allow_partial: false,
             from_attributes: None,
             context,
             self_instance: None,
@@ -322,18 +383,31 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&'data PyAny>,
         self_instance: Option<&PyAny>,
+        fail_fast: Option<bool>,
+        loc_by_alias: Option<bool>,
+        allow_partial: bool,
     ) -> ValResult<PyObject>
     where
         's: 'data,
     {
         let mut recursion_guard = RecursionGuard::default();
         let mut state = ValidationState::new(
-            Extra::new(strict, from_attributes, context, self_instance, input_type),
+            Extra::new(
+                strict,
+                fail_fast,
+                loc_by_alias,
+                allow_partial,
+                from_attributes,
+                context,
+                self_instance,
+                input_type,
+            ),
             &mut recursion_guard,
         );
         self.validator.validate(py, input, &mut state)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn _validate_json(
         &self,
         py: Python,
@@ -342,10 +416,24 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&PyAny>,
         self_instance: Option<&PyAny>,
+        fail_fast: Option<bool>,
+        loc_by_alias: Option<bool>,
+        allow_partial: bool,
     ) -> ValResult<PyObject> {
         let json_value =
             jiter::JsonValue::parse(json_data, true).map_err(|e| json::map_json_err(input, e, json_data))?;
-        self._validate(py, &json_value, InputType::Json, strict, None, context, self_instance)
+        self._validate(
+            py,
+            &json_value,
+            InputType::Json,
+            strict,
+            None,
+            context,
+            self_instance,
+            fail_fast,
+            loc_by_alias,
+            allow_partial,
+        )
     }
 
     fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
@@ -380,7 +468,7 @@ impl<'py> SelfValidator<'py> {
     pub fn validate_schema(&self, py: Python<'py>, schema: &'py PyAny, strict: Option<bool>) -> PyResult<&'py PyAny> {
         let mut recursion_guard = RecursionGuard::default();
         let mut state = ValidationState::new(
-            Extra::new(strict, None, None, None, InputType::Python),
+            Extra::new(strict, None, None, false, None, None, None, InputType::Python),
             &mut recursion_guard,
         );
         match self.validator.validator.validate(py, schema, &mut state) {
@@ -490,9 +578,10 @@ pub fn build_validator<'a>(
         float::FloatBuilder,
         // decimals
         decimal::DecimalValidator,
+        // complex numbers
+        complex::ComplexValidator,
         // tuples
-        tuple::TuplePositionalValidator,
-        tuple::TupleVariableValidator,
+        tuple::TupleValidator,
         // list/arrays
         list::ListValidator,
         // sets - unique lists
@@ -510,6 +599,8 @@ pub fn build_validator<'a>(
         call::CallValidator,
         // literals
         literal::LiteralValidator,
+        // enums
+        enum_::BuildEnumValidator,
         // any
         any::AnyValidator,
         // bytes
@@ -524,6 +615,8 @@ pub fn build_validator<'a>(
         frozenset::FrozenSetValidator,
         // timedelta
         timedelta::TimeDeltaValidator,
+        // timezones
+        timezone::TimezoneValidator,
         // introspection types
         is_instance::IsInstanceValidator,
         is_subclass::IsSubclassValidator,
@@ -565,6 +658,12 @@ pub struct Extra<'a> {
     pub data: Option<&'a PyDict>,
     /// whether we're in strict or lax mode
     pub strict: Option<bool>,
+    /// whether to stop at the first error rather than collecting all errors
+    pub fail_fast: Option<bool>,
+    /// Validation time setting of `loc_by_alias`, overrides the config for error locations of fields with aliases
+    pub loc_by_alias: Option<bool>,
+    /// whether the input may be truncated, in which case errors in the last item of collections are ignored
+    pub allow_partial: bool,
     /// Validation time setting of `from_attributes`
     pub from_attributes: Option<bool>,
     /// context used in validator functions
@@ -574,8 +673,12 @@ pub struct Extra<'a> {
 }
 
 impl<'a> Extra<'a> {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         strict: Option<bool>,
+        fail_fast: Option<bool>,
+        loc_by_alias: Option<bool>,
+        allow_partial: bool,
         from_attributes: Option<bool>,
         context: Option<&'a PyAny>,
         self_instance: Option<&'a PyAny>,
@@ -585,6 +688,9 @@ impl<'a> Extra<'a> {
             input_type,
             data: None,
             strict,
+            fail_fast,
+            loc_by_alias,
+            allow_partial,
             from_attributes,
             context,
             self_instance,
@@ -598,6 +704,9 @@ impl<'a> Extra<'a> {
             input_type: self.input_type,
             data: self.data,
             strict: Some(true),
+            fail_fast: self.fail_fast,
+            loc_by_alias: self.loc_by_alias,
+            allow_partial: self.allow_partial,
             from_attributes: self.from_attributes,
             context: self.context,
             self_instance: self.self_instance,
@@ -634,13 +743,14 @@ pub enum CombinedValidator {
     ConstrainedFloat(float::ConstrainedFloatValidator),
     // decimals
     Decimal(decimal::DecimalValidator),
+    // complex numbers
+    Complex(complex::ComplexValidator),
     // lists
     List(list::ListValidator),
     // sets - unique lists
     Set(set::SetValidator),
     // tuples
-    TuplePositional(tuple::TuplePositionalValidator),
-    TupleVariable(tuple::TupleVariableValidator),
+    Tuple(tuple::TupleValidator),
     // dicts/objects (recursive)
     Dict(dict::DictValidator),
     // None/null
@@ -654,6 +764,11 @@ pub enum CombinedValidator {
     FunctionCall(call::CallValidator),
     // literals
     Literal(literal::LiteralValidator),
+    // enums
+    PlainEnum(enum_::EnumValidator<enum_::PlainEnumValidator>),
+    IntEnum(enum_::EnumValidator<enum_::IntEnumValidator>),
+    StrEnum(enum_::EnumValidator<enum_::StrEnumValidator>),
+    FloatEnum(enum_::EnumValidator<enum_::FloatEnumValidator>),
     // any
     Any(any::AnyValidator),
     // bytes
@@ -669,6 +784,8 @@ pub enum CombinedValidator {
     FrozenSet(frozenset::FrozenSetValidator),
     // timedelta
     Timedelta(timedelta::TimeDeltaValidator),
+    // timezones
+    Timezone(timezone::TimezoneValidator),
     // introspection types
     IsInstance(is_instance::IsInstanceValidator),
     IsSubclass(is_subclass::IsSubclassValidator),
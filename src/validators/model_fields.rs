@@ -6,7 +6,7 @@ use pyo3::types::{PyDict, PySet, PyString, PyType};
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{is_fail_fast, is_strict, schema_or_config_same, ExtraBehavior};
 use crate::errors::{AsLocItem, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{
     AttributesGenericIterator, BorrowInput, DictGenericIterator, GenericMapping, Input, JsonObjectGenericIterator,
@@ -39,6 +39,7 @@ pub struct ModelFieldsValidator {
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
     strict: bool,
+    fail_fast: bool,
     from_attributes: bool,
     loc_by_alias: bool,
 }
@@ -53,6 +54,7 @@ impl BuildValidator for ModelFieldsValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let strict = is_strict(schema, config)?;
+        let fail_fast = is_fail_fast(schema, config)?;
 
         let from_attributes = schema_or_config_same(schema, config, intern!(py, "from_attributes"))?.unwrap_or(false);
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
@@ -105,6 +107,7 @@ impl BuildValidator for ModelFieldsValidator {
             extra_behavior,
             extras_validator,
             strict,
+            fail_fast,
             from_attributes,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
         }
@@ -125,6 +128,8 @@ impl Validator for ModelFieldsValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
+        let fail_fast = state.fail_fast_or(self.fail_fast);
+        let loc_by_alias = state.loc_by_alias_or(self.loc_by_alias);
         let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
 
         // we convert the DictType error to a ModelType error
@@ -163,6 +168,12 @@ impl Validator for ModelFieldsValidator {
             _ => None,
         };
 
+        // only the last key of partial JSON input may be incomplete
+        let partial_last_key: Option<&str> = match (&dict, state.extra().allow_partial) {
+            (GenericMapping::JsonObject(d), true) => d.iter().last().map(|(key, _)| key.as_str()),
+            _ => None,
+        };
+
         macro_rules! control_flow {
             ($e: expr) => {
                 match $e {
@@ -179,6 +190,9 @@ impl Validator for ModelFieldsValidator {
                     ..*state.extra()
                 }, |state| {
                     for field in &self.fields {
+                        if fail_fast && !errors.is_empty() {
+                            break;
+                        }
                         let op_key_value = match field.lookup_key.$get_method($dict $(, $kwargs )? ) {
                             Ok(v) => v,
                             Err(ValError::LineErrors(line_errors)) => {
@@ -195,23 +209,30 @@ impl Validator for ModelFieldsValidator {
                                 // extra logic either way
                                 used_keys.insert(lookup_path.first_key());
                             }
-                            match field.validator.validate(py, value.borrow_input(), state) {
+                            let is_last_partial = partial_last_key == Some(lookup_path.first_key());
+                            match state.with_allow_partial(is_last_partial, |state| {
+                                field.validator.validate(py, value.borrow_input(), state)
+                            }) {
                                 Ok(value) => {
                                     control_flow!(model_dict.set_item(&field.name_py, value))?;
                                     fields_set_vec.push(field.name_py.clone_ref(py));
+                                    continue;
                                 }
                                 Err(ValError::Omit) => continue,
+                                // the last field of partial input may be incomplete, a model can't omit the
+                                // field so instead it's treated as missing and the default is used
+                                Err(ValError::LineErrors(_)) if is_last_partial => {}
                                 Err(ValError::LineErrors(line_errors)) => {
                                     for err in line_errors {
                                         errors.push(
-                                            lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)
+                                            lookup_path.apply_error_loc(err, loc_by_alias, &field.name)
 
                                         );
                                     }
+                                    continue;
                                 }
                                 Err(err) => return ControlFlow::Break(err),
                             }
-                            continue;
                         }
 
                         match field.validator.default_value(py, Some(field.name.as_str()), state) {
@@ -224,7 +245,7 @@ impl Validator for ModelFieldsValidator {
                                 errors.push(field.lookup_key.error(
                                     ErrorTypeDefaults::Missing,
                                     input,
-                                    self.loc_by_alias,
+                                    loc_by_alias,
                                     &field.name
                                 ));
                             },
@@ -250,6 +271,9 @@ impl Validator for ModelFieldsValidator {
                 if let Some(ref mut used_keys) = used_keys {
                     let model_extra_dict = PyDict::new(py);
                     for item_result in <$iter>::new($dict)? {
+                        if fail_fast && !errors.is_empty() {
+                            break;
+                        }
                         let (raw_key, value) = item_result?;
                         let either_str = match raw_key.validate_str(true, false).map(ValidationMatch::into_inner) {
                             Ok(k) => k,
@@ -287,11 +311,15 @@ impl Validator for ModelFieldsValidator {
                             ExtraBehavior::Allow => {
                             let py_key = either_str.as_py_string(py);
                                 if let Some(ref validator) = self.extras_validator {
-                                    match validator.validate(py, value, state) {
+                                    let is_last_partial = partial_last_key == Some(cow.as_ref());
+                                    match state.with_allow_partial(is_last_partial, |state| {
+                                        validator.validate(py, value, state)
+                                    }) {
                                         Ok(value) => {
                                             model_extra_dict.set_item(py_key, value)?;
                                             fields_set_vec.push(py_key.into_py(py));
                                         }
+                                        Err(ValError::LineErrors(_)) if is_last_partial => {}
                                         Err(ValError::LineErrors(line_errors)) => {
                                             for err in line_errors {
                                                 errors.push(err.with_outer_location(raw_key.as_loc_item()));
@@ -12,6 +12,7 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationSta
 #[derive(Debug)]
 pub struct SetValidator {
     strict: bool,
+    fail_fast: bool,
     item_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
@@ -39,6 +40,7 @@ macro_rules! set_build {
             let name = format!("{}[{}]", Self::EXPECTED_TYPE, inner_name);
             Ok(Self {
                 strict: crate::build_tools::is_strict(schema, config)?,
+                fail_fast: crate::build_tools::is_fail_fast(schema, config)?,
                 item_validator,
                 min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
                 max_length,
@@ -72,7 +74,16 @@ impl Validator for SetValidator {
         };
         state.floor_exactness(exactness);
         let set = PySet::empty(py)?;
-        collection.validate_to_set(py, set, input, self.max_length, "Set", &self.item_validator, state)?;
+        collection.validate_to_set(
+            py,
+            set,
+            input,
+            self.max_length,
+            "Set",
+            &self.item_validator,
+            state,
+            state.fail_fast_or(self.fail_fast),
+        )?;
         min_length_check!(input, "Set", self.min_length, set);
         Ok(set.into_py(py))
     }
@@ -6,18 +6,21 @@ use speedate::Time;
 
 use crate::build_tools::is_strict;
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{EitherTime, Input};
+use crate::input::{EitherTime, Input, TimestampUnit};
 use crate::tools::SchemaDict;
 
-use super::datetime::extract_microseconds_precision;
-use super::datetime::TZConstraint;
-use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::datetime::{extract_microseconds_precision, extract_timestamp_unit};
+use super::datetime::{InputFormats, TZConstraint, TzConversion};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct TimeValidator {
     strict: bool,
     constraints: Option<TimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    timestamp_unit: TimestampUnit,
+    formats: Option<InputFormats>,
+    tz_conversion: Option<TzConversion>,
 }
 
 impl BuildValidator for TimeValidator {
@@ -32,6 +35,9 @@ impl BuildValidator for TimeValidator {
             strict: is_strict(schema, config)?,
             constraints: TimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            timestamp_unit: extract_timestamp_unit(schema, config)?,
+            formats: InputFormats::from_py(schema)?,
+            tz_conversion: TzConversion::from_py(schema)?,
         };
         Ok(s.into())
     }
@@ -46,9 +52,25 @@ impl Validator for TimeValidator {
         input: &'data impl Input<'data>,
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
-        let time = input
-            .validate_time(state.strict_or(self.strict), self.microseconds_precision)?
-            .unpack(state);
+        let time = match (
+            input.validate_time(
+                state.strict_or(self.strict),
+                self.microseconds_precision,
+                self.timestamp_unit,
+            ),
+            &self.formats,
+        ) {
+            (Ok(val_match), _) => val_match.unpack(state),
+            (Err(err), Some(formats)) => {
+                state.floor_exactness(Exactness::Lax);
+                formats.parse_fallback(input, err, |dt| EitherTime::Raw(dt.time))?
+            }
+            (Err(err), None) => return Err(err),
+        };
+        let time = match &self.tz_conversion {
+            Some(tz_conversion) => tz_conversion.convert_time(time)?,
+            None => time,
+        };
         if let Some(constraints) = &self.constraints {
             let raw_time = time.as_raw()?;
 
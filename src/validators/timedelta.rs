@@ -1,10 +1,14 @@
+use std::fmt::Write;
+use std::str::FromStr;
+
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDelta, PyDeltaAccess, PyDict};
+use pyo3::types::PyDict;
 use speedate::Duration;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, schema_or_config_same};
 use crate::errors::{ErrorType, ValError, ValResult};
-use crate::input::{duration_as_pytimedelta, EitherTimedelta, Input};
+use crate::input::{DurationParsing, DurationUnit, EitherTimedelta, Input};
 
 use super::datetime::extract_microseconds_precision;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -14,6 +18,7 @@ pub struct TimeDeltaValidator {
     strict: bool,
     constraints: Option<TimedeltaConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    duration_parsing: DurationParsing,
 }
 
 #[derive(Debug, Clone)]
@@ -34,6 +39,17 @@ fn get_constraint(schema: &PyDict, key: &str) -> PyResult<Option<Duration>> {
     }
 }
 
+fn extract_duration_parsing(schema: &PyDict, config: Option<&PyDict>) -> PyResult<DurationParsing> {
+    let py = schema.py();
+    let raw_unit: Option<&str> = schema_or_config_same(schema, config, intern!(py, "duration_unit"))?;
+    Ok(DurationParsing {
+        unit: raw_unit.map_or_else(|| Ok(DurationUnit::default()), DurationUnit::from_str)?,
+        human_strings: schema_or_config_same(schema, config, intern!(py, "allow_human_durations"))?.unwrap_or(false),
+        forbid_calendar_units: !schema_or_config_same(schema, config, intern!(py, "allow_calendar_units"))?
+            .unwrap_or(true),
+    })
+}
+
 impl BuildValidator for TimeDeltaValidator {
     const EXPECTED_TYPE: &'static str = "timedelta";
 
@@ -57,6 +73,7 @@ impl BuildValidator for TimeDeltaValidator {
                 || constraints.gt.is_some())
             .then_some(constraints),
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            duration_parsing: extract_duration_parsing(schema, config)?,
         }
         .into())
     }
@@ -72,7 +89,11 @@ impl Validator for TimeDeltaValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let timedelta = input
-            .validate_timedelta(state.strict_or(self.strict), self.microseconds_precision)?
+            .validate_timedelta(
+                state.strict_or(self.strict),
+                self.microseconds_precision,
+                self.duration_parsing,
+            )?
             .unpack(state);
         let py_timedelta = timedelta.try_into_py(py)?;
         if let Some(constraints) = &self.constraints {
@@ -85,8 +106,7 @@ impl Validator for TimeDeltaValidator {
                             return Err(ValError::new(
                                 ErrorType::$error {
                                     context: None,
-                                    $constraint: pydelta_to_human_readable(duration_as_pytimedelta(py, constraint)?)
-                                        .into(),
+                                    $constraint: duration_to_string(constraint).into(),
                                 },
                                 py_timedelta.as_ref(),
                             ));
@@ -107,43 +127,19 @@ impl Validator for TimeDeltaValidator {
         Self::EXPECTED_TYPE
     }
 }
-fn pydelta_to_human_readable(py_delta: &PyDelta) -> String {
-    let total_seconds = py_delta.get_seconds();
-    let hours = total_seconds / 3600;
-    let minutes = (total_seconds % 3600) / 60;
-    let seconds = total_seconds % 60;
-    let microseconds = py_delta.get_microseconds();
-    let days = py_delta.get_days();
-
-    let mut formatted_duration = Vec::new();
-
-    if days != 0 {
-        formatted_duration.push(format!("{} day{}", days, if days != 1 { "s" } else { "" }));
-    }
 
-    if hours != 0 {
-        formatted_duration.push(format!("{} hour{}", hours, if hours != 1 { "s" } else { "" }));
+/// Like speedate's ISO 8601 rendering of durations, but in days rather than (365 day) years, e.g. `P749DT3661.1S`.
+fn duration_to_string(duration: &Duration) -> String {
+    let mut s = String::from(if duration.positive { "P" } else { "-P" });
+    if duration.day != 0 {
+        write!(s, "{}D", duration.day).unwrap();
     }
-
-    if minutes != 0 {
-        formatted_duration.push(format!("{} minute{}", minutes, if minutes != 1 { "s" } else { "" }));
-    }
-
-    if seconds != 0 {
-        formatted_duration.push(format!("{} second{}", seconds, if seconds != 1 { "s" } else { "" }));
-    }
-
-    if microseconds != 0 {
-        formatted_duration.push(format!(
-            "{} microsecond{}",
-            microseconds,
-            if microseconds != 1 { "s" } else { "" }
-        ));
-    }
-
-    if formatted_duration.is_empty() {
-        formatted_duration.push("0 seconds".to_string());
+    if duration.second != 0 || duration.microsecond != 0 || duration.day == 0 {
+        write!(s, "T{}", duration.second).unwrap();
+        if duration.microsecond != 0 {
+            write!(s, ".{}", format!("{:06}", duration.microsecond).trim_end_matches('0')).unwrap();
+        }
+        s.push('S');
     }
-
-    formatted_duration.join(" and ")
+    s
 }
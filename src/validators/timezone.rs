@@ -0,0 +1,75 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{get_zoneinfo, get_zoneinfo_type, Input, InputType};
+
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+/// Validates IANA timezone names like `America/New_York` into `zoneinfo.ZoneInfo` instances.
+#[derive(Debug, Clone)]
+pub struct TimezoneValidator {
+    strict: bool,
+}
+
+impl BuildValidator for TimezoneValidator {
+    const EXPECTED_TYPE: &'static str = "timezone";
+
+    fn build(
+        schema: &PyDict,
+        config: Option<&PyDict>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        if get_zoneinfo_type(schema.py()).is_err() {
+            return py_schema_err!("The `timezone` schema requires the `zoneinfo` module, available from Python 3.9");
+        }
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(TimezoneValidator {});
+
+impl Validator for TimezoneValidator {
+    fn validate<'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+    ) -> ValResult<PyObject> {
+        let class = get_zoneinfo_type(py)?;
+        if let Some(py_input) = input.input_is_instance(class) {
+            return Ok(py_input.to_object(py));
+        }
+        let from_python = state.extra().input_type == InputType::Python;
+        if state.strict_or(self.strict) && from_python {
+            return Err(ValError::new(
+                ErrorType::IsInstanceOf {
+                    class: class.name().unwrap_or("ZoneInfo").to_string(),
+                    context: None,
+                },
+                input,
+            ));
+        }
+        // like UUIDs, a string is an exact match in JSON but a coercion from python
+        if from_python {
+            state.floor_exactness(Exactness::Lax);
+        }
+        let either_str = input
+            .validate_str(false, false)
+            .map_err(|_| ValError::new(ErrorTypeDefaults::TimezoneType, input))?
+            .into_inner();
+        let name = either_str.as_cow()?;
+        match get_zoneinfo(py, &name) {
+            Ok(zoneinfo) => Ok(zoneinfo.to_object(py)),
+            Err(_) => Err(ValError::new(ErrorTypeDefaults::TimezoneName, input)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
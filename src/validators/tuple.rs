@@ -1,39 +1,66 @@
+use std::collections::VecDeque;
+
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
 
-use crate::build_tools::is_strict;
-use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_fail_fast, is_strict, py_schema_err};
+use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{GenericIterable, Input};
 use crate::tools::SchemaDict;
 use crate::validators::Exactness;
 
-use super::list::{get_items_schema, min_length_check};
+use super::list::min_length_check;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
+/// Validates tuples made up of a fixed "prefix" of items, optionally followed by any number of items
+/// matching the variadic item schema, followed by a fixed "suffix" of items, e.g. `tuple[int, *tuple[str, ...], float]`.
 #[derive(Debug)]
-pub struct TupleVariableValidator {
+pub struct TupleValidator {
     strict: bool,
-    item_validator: Option<Box<CombinedValidator>>,
+    fail_fast: bool,
+    validators: Vec<CombinedValidator>,
+    variadic_item_index: Option<usize>,
     min_length: Option<usize>,
     max_length: Option<usize>,
     name: String,
 }
 
-impl BuildValidator for TupleVariableValidator {
-    const EXPECTED_TYPE: &'static str = "tuple-variable";
+impl BuildValidator for TupleValidator {
+    const EXPECTED_TYPE: &'static str = "tuple";
     fn build(
         schema: &PyDict,
         config: Option<&PyDict>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        let item_validator = get_items_schema(schema, config, definitions)?.map(Box::new);
-        let inner_name = item_validator.as_ref().map_or("any", |v| v.get_name());
-        let name = format!("tuple[{inner_name}, ...]");
+        let validators: Vec<CombinedValidator> = match schema.get_as::<&PyList>(intern!(py, "items_schema"))? {
+            Some(items) => items
+                .iter()
+                .map(|item| build_validator(item, config, definitions))
+                .collect::<PyResult<_>>()?,
+            None => Vec::new(),
+        };
+
+        let variadic_item_index: Option<usize> = schema.get_as(intern!(py, "variadic_item_index"))?;
+        let mut descr: Vec<&str> = validators.iter().map(Validator::get_name).collect();
+        if let Some(index) = variadic_item_index {
+            if index >= validators.len() {
+                return py_schema_err!(
+                    "`variadic_item_index` {} is out of range for `items_schema` of length {}",
+                    index,
+                    validators.len()
+                );
+            }
+            descr.insert(index + 1, "...");
+        }
+        let name = format!("tuple[{}]", descr.join(", "));
+
         Ok(Self {
             strict: is_strict(schema, config)?,
-            item_validator,
+            fail_fast: is_fail_fast(schema, config)?,
+            validators,
+            variadic_item_index,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
             name,
@@ -42,145 +69,192 @@ impl BuildValidator for TupleVariableValidator {
     }
 }
 
-impl_py_gc_traverse!(TupleVariableValidator { item_validator });
+impl_py_gc_traverse!(TupleValidator { validators });
 
-impl Validator for TupleVariableValidator {
-    fn validate<'data>(
-        &self,
+/// Output of tuple validation as it's built up, keeps track of errors and the `max_length` constraint.
+struct TupleOutput<'a, INPUT> {
+    output: Vec<PyObject>,
+    errors: Vec<ValLineError>,
+    length: usize,
+    max_length: Option<usize>,
+    input: &'a INPUT,
+    actual_length: Option<usize>,
+    fail_fast: bool,
+}
+
+impl<'a, INPUT: Input<'a>> TupleOutput<'a, INPUT> {
+    fn validate_item<'data>(
+        &mut self,
         py: Python<'data>,
-        input: &'data impl Input<'data>,
+        validator: &CombinedValidator,
+        item: &'data impl Input<'data>,
+        index: usize,
         state: &mut ValidationState,
-    ) -> ValResult<PyObject> {
-        let seq = input.validate_tuple(state.strict_or(self.strict))?;
-        let exactness = match &seq {
-            GenericIterable::Tuple(_) | GenericIterable::JsonArray(_) => Exactness::Exact,
-            GenericIterable::List(_) => Exactness::Strict,
-            _ => Exactness::Lax,
-        };
-        state.floor_exactness(exactness);
-
-        let output = match self.item_validator {
-            Some(ref v) => seq.validate_to_vec(py, input, self.max_length, "Tuple", v, state)?,
-            None => seq.to_vec(py, input, "Tuple", self.max_length)?,
-        };
-        min_length_check!(input, "Tuple", self.min_length, output);
-        Ok(PyTuple::new(py, &output).into_py(py))
+        is_last_partial: bool,
+    ) -> ValResult<()> {
+        match state.with_allow_partial(is_last_partial, |state| validator.validate(py, item, state)) {
+            Ok(value) => self.output.push(value),
+            Err(ValError::LineErrors(_)) if is_last_partial => return Ok(()),
+            Err(ValError::LineErrors(line_errors)) => {
+                self.errors
+                    .extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
+            }
+            Err(ValError::Omit) => return Ok(()),
+            Err(err) => return Err(err),
+        }
+        self.length += 1;
+        match self.max_length {
+            Some(max_length) if self.length > max_length => Err(ValError::new(
+                ErrorType::TooLong {
+                    field_type: "Tuple".to_string(),
+                    max_length,
+                    actual_length: self.actual_length,
+                    context: None,
+                },
+                self.input,
+            )),
+            _ => Ok(()),
+        }
     }
 
-    fn get_name(&self) -> &str {
-        &self.name
+    fn missing_item(
+        &mut self,
+        py: Python,
+        validator: &CombinedValidator,
+        index: usize,
+        state: &mut ValidationState,
+    ) -> ValResult<()> {
+        if let Some(value) = validator.default_value(py, Some(index), state)? {
+            self.output.push(value);
+        } else {
+            self.errors.push(ValLineError::new_with_loc(
+                ErrorTypeDefaults::Missing,
+                self.input,
+                index,
+            ));
+        }
+        Ok(())
     }
-}
 
-#[derive(Debug)]
-pub struct TuplePositionalValidator {
-    strict: bool,
-    items_validators: Vec<CombinedValidator>,
-    extras_validator: Option<Box<CombinedValidator>>,
-    name: String,
-}
+    fn stop(&self) -> bool {
+        self.fail_fast && !self.errors.is_empty()
+    }
 
-impl BuildValidator for TuplePositionalValidator {
-    const EXPECTED_TYPE: &'static str = "tuple-positional";
-    fn build(
-        schema: &PyDict,
-        config: Option<&PyDict>,
-        definitions: &mut DefinitionsBuilder<CombinedValidator>,
-    ) -> PyResult<CombinedValidator> {
-        let py = schema.py();
-        let items: &PyList = schema.get_as_req(intern!(py, "items_schema"))?;
-        let validators: Vec<CombinedValidator> = items
-            .iter()
-            .map(|item| build_validator(item, config, definitions))
-            .collect::<PyResult<_>>()?;
-
-        let descr = validators
-            .iter()
-            .map(Validator::get_name)
-            .collect::<Vec<_>>()
-            .join(", ");
-        Ok(Self {
-            strict: is_strict(schema, config)?,
-            items_validators: validators,
-            extras_validator: match schema.get_item(intern!(py, "extras_schema"))? {
-                Some(v) => Some(Box::new(build_validator(v, config, definitions)?)),
-                None => None,
-            },
-            name: format!("tuple[{descr}]"),
+    fn finish(self) -> ValResult<Vec<PyObject>> {
+        if self.errors.is_empty() {
+            Ok(self.output)
+        } else {
+            Err(ValError::LineErrors(self.errors))
         }
-        .into())
     }
 }
 
-#[allow(clippy::too_many_arguments)]
-fn validate_tuple_positional<'s, 'data, T: Iterator<Item = PyResult<&'data I>>, I: Input<'data> + 'data>(
-    py: Python<'data>,
-    input: &'data impl Input<'data>,
-    state: &mut ValidationState,
-    output: &mut Vec<PyObject>,
-    errors: &mut Vec<ValLineError>,
-    extras_validator: &Option<Box<CombinedValidator>>,
-    items_validators: &[CombinedValidator],
-    collection_iter: &mut T,
-    actual_length: Option<usize>,
-) -> ValResult<()> {
-    for (index, validator) in items_validators.iter().enumerate() {
-        match collection_iter.next() {
-            Some(result) => match validator.validate(py, result?, state) {
-                Ok(item) => output.push(item),
-                Err(ValError::LineErrors(line_errors)) => {
-                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index.into())));
-                }
-                Err(err) => return Err(err),
-            },
-            None => {
-                if let Some(value) = validator.default_value(py, Some(index), state)? {
-                    output.push(value);
-                } else {
-                    errors.push(ValLineError::new_with_loc(ErrorTypeDefaults::Missing, input, index));
+impl TupleValidator {
+    fn validate_items<'data, I: Input<'data> + 'data>(
+        &self,
+        py: Python<'data>,
+        input: &'data impl Input<'data>,
+        state: &mut ValidationState,
+        iter: impl Iterator<Item = PyResult<&'data I>>,
+        actual_length: Option<usize>,
+    ) -> ValResult<Vec<PyObject>> {
+        let mut out = TupleOutput {
+            output: Vec::with_capacity(actual_length.unwrap_or(self.validators.len())),
+            errors: Vec::new(),
+            length: 0,
+            max_length: self.max_length,
+            input,
+            actual_length,
+            fail_fast: state.fail_fast_or(self.fail_fast),
+        };
+        let mut iter = iter.enumerate().map(|(index, item_result)| {
+            item_result.map(|item| (index, item)).map_err(|err| {
+                ValError::new_with_loc(
+                    ErrorType::IterationError {
+                        error: py_err_string(py, err),
+                        context: None,
+                    },
+                    input,
+                    index,
+                )
+            })
+        });
+
+        let (head, variadic) = match self.variadic_item_index {
+            Some(index) => {
+                let (head, rest) = self.validators.split_at(index);
+                (head, rest.split_first())
+            }
+            None => (self.validators.as_slice(), None),
+        };
+
+        for (index, validator) in head.iter().enumerate() {
+            match iter.next() {
+                Some(item_result) => {
+                    let (_, item) = item_result?;
+                    out.validate_item(py, validator, item, index, state, false)?;
                 }
+                None => out.missing_item(py, validator, index, state)?,
+            }
+            if out.stop() {
+                return out.finish();
             }
         }
-    }
-    for (index, result) in collection_iter.enumerate() {
-        let item = result?;
-        match extras_validator {
-            Some(ref extras_validator) => match extras_validator.validate(py, item, state) {
-                Ok(item) => output.push(item),
-                Err(ValError::LineErrors(line_errors)) => {
-                    errors.extend(
-                        line_errors
-                            .into_iter()
-                            .map(|err| err.with_outer_location((index + items_validators.len()).into())),
-                    );
+
+        match variadic {
+            Some((variadic_validator, tail)) => {
+                let partial_last_index = match (state.extra().allow_partial, tail.is_empty()) {
+                    (true, true) => actual_length.and_then(|len| len.checked_sub(1)),
+                    _ => None,
+                };
+                // the last `tail.len()` items are held back until we know they aren't variadic items
+                let mut buffer: VecDeque<(usize, &I)> = VecDeque::with_capacity(tail.len() + 1);
+                let mut consumed = head.len();
+                for item_result in iter.by_ref() {
+                    buffer.push_back(item_result?);
+                    consumed += 1;
+                    if buffer.len() > tail.len() {
+                        if let Some((index, item)) = buffer.pop_front() {
+                            let is_last_partial = partial_last_index == Some(index);
+                            out.validate_item(py, variadic_validator, item, index, state, is_last_partial)?;
+                            if out.stop() {
+                                return out.finish();
+                            }
+                        }
+                    }
                 }
-                Err(ValError::Omit) => (),
-                Err(err) => return Err(err),
-            },
+
+                let tail_start = consumed - buffer.len();
+                for (tail_index, validator) in tail.iter().enumerate() {
+                    match buffer.pop_front() {
+                        Some((index, item)) => out.validate_item(py, validator, item, index, state, false)?,
+                        None => out.missing_item(py, validator, tail_start + tail_index, state)?,
+                    }
+                    if out.stop() {
+                        return out.finish();
+                    }
+                }
+            }
             None => {
-                errors.push(ValLineError::new(
-                    ErrorType::TooLong {
-                        field_type: "Tuple".to_string(),
-                        max_length: items_validators.len(),
-                        actual_length,
-                        context: None,
-                    },
-                    input,
-                ));
-                // no need to continue through further items
-                break;
+                if let Some(item_result) = iter.next() {
+                    item_result?;
+                    out.errors.push(ValLineError::new(
+                        ErrorType::TooLong {
+                            field_type: "Tuple".to_string(),
+                            max_length: self.validators.len(),
+                            actual_length,
+                            context: None,
+                        },
+                        input,
+                    ));
+                }
             }
         }
+        out.finish()
     }
-    Ok(())
 }
 
-impl_py_gc_traverse!(TuplePositionalValidator {
-    items_validators,
-    extras_validator
-});
-
-impl Validator for TuplePositionalValidator {
+impl Validator for TupleValidator {
     fn validate<'data>(
         &self,
         py: Python<'data>,
@@ -188,7 +262,7 @@ impl Validator for TuplePositionalValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let collection = input.validate_tuple(state.strict_or(self.strict))?;
-        let exactness: crate::validators::Exactness = match &collection {
+        let exactness = match &collection {
             GenericIterable::Tuple(_) | GenericIterable::JsonArray(_) => Exactness::Exact,
             GenericIterable::List(_) => Exactness::Strict,
             _ => Exactness::Lax,
@@ -196,42 +270,20 @@ impl Validator for TuplePositionalValidator {
         state.floor_exactness(exactness);
 
         let actual_length = collection.generic_len();
-        let expected_length = if self.extras_validator.is_some() {
-            actual_length.unwrap_or(self.items_validators.len())
-        } else {
-            self.items_validators.len()
+        let output = match collection {
+            GenericIterable::List(collection_iter) => {
+                self.validate_items(py, input, state, collection_iter.iter().map(Ok), actual_length)?
+            }
+            GenericIterable::Tuple(collection_iter) => {
+                self.validate_items(py, input, state, collection_iter.iter().map(Ok), actual_length)?
+            }
+            GenericIterable::JsonArray(collection_iter) => {
+                self.validate_items(py, input, state, collection_iter.iter().map(Ok), actual_length)?
+            }
+            other => self.validate_items(py, input, state, other.as_sequence_iterator(py)?, actual_length)?,
         };
-
-        let mut output: Vec<PyObject> = Vec::with_capacity(expected_length);
-        let mut errors: Vec<ValLineError> = Vec::new();
-
-        macro_rules! iter {
-            ($collection_iter:expr) => {{
-                validate_tuple_positional(
-                    py,
-                    input,
-                    state,
-                    &mut output,
-                    &mut errors,
-                    &self.extras_validator,
-                    &self.items_validators,
-                    &mut $collection_iter,
-                    actual_length,
-                )?
-            }};
-        }
-
-        match collection {
-            GenericIterable::List(collection_iter) => iter!(collection_iter.iter().map(Ok)),
-            GenericIterable::Tuple(collection_iter) => iter!(collection_iter.iter().map(Ok)),
-            GenericIterable::JsonArray(collection_iter) => iter!(collection_iter.iter().map(Ok)),
-            other => iter!(other.as_sequence_iterator(py)?),
-        }
-        if errors.is_empty() {
-            Ok(PyTuple::new(py, &output).into_py(py))
-        } else {
-            Err(ValError::LineErrors(errors))
-        }
+        min_length_check!(input, "Tuple", self.min_length, output);
+        Ok(PyTuple::new(py, &output).into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -7,7 +7,7 @@ use pyo3::types::{PyDict, PyString};
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{is_fail_fast, is_strict, schema_or_config, schema_or_config_same, ExtraBehavior};
 use crate::errors::{AsLocItem, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{
     AttributesGenericIterator, BorrowInput, DictGenericIterator, GenericMapping, Input, JsonObjectGenericIterator,
@@ -37,6 +37,7 @@ pub struct TypedDictValidator {
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
     strict: bool,
+    fail_fast: bool,
     loc_by_alias: bool,
 }
 
@@ -54,6 +55,7 @@ impl BuildValidator for TypedDictValidator {
         let config = schema.get_as(intern!(py, "config"))?;
 
         let strict = is_strict(schema, config)?;
+        let fail_fast = is_fail_fast(schema, config)?;
 
         let total =
             schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?.unwrap_or(true);
@@ -131,6 +133,7 @@ impl BuildValidator for TypedDictValidator {
             extra_behavior,
             extras_validator,
             strict,
+            fail_fast,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
         }
         .into())
@@ -150,6 +153,8 @@ impl Validator for TypedDictValidator {
         state: &mut ValidationState,
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
+        let fail_fast = state.fail_fast_or(self.fail_fast);
+        let loc_by_alias = state.loc_by_alias_or(self.loc_by_alias);
         let dict = input.validate_dict(strict)?;
 
         let output_dict = PyDict::new(py);
@@ -163,6 +168,12 @@ impl Validator for TypedDictValidator {
             _ => None,
         };
 
+        // only the last key of partial JSON input may be incomplete
+        let partial_last_key: Option<&str> = match (&dict, state.extra().allow_partial) {
+            (GenericMapping::JsonObject(d), true) => d.iter().last().map(|(key, _)| key.as_str()),
+            _ => None,
+        };
+
         macro_rules! control_flow {
             ($e: expr) => {
                 match $e {
@@ -179,6 +190,9 @@ impl Validator for TypedDictValidator {
                     ..*state.extra()
                 }, |state| {
                     for field in &self.fields {
+                        if fail_fast && !errors.is_empty() {
+                            break;
+                        }
                         let op_key_value = match field.lookup_key.$get_method($dict $(, $kwargs )? ) {
                             Ok(v) => v,
                             Err(ValError::LineErrors(line_errors)) => {
@@ -195,16 +209,22 @@ impl Validator for TypedDictValidator {
                                 // extra logic either way
                                 used_keys.insert(lookup_path.first_key());
                             }
-                            match field.validator.validate(py, value.borrow_input(), state) {
+                            let is_last_partial = partial_last_key == Some(lookup_path.first_key());
+                            match state.with_allow_partial(is_last_partial, |state| {
+                                field.validator.validate(py, value.borrow_input(), state)
+                            }) {
                                 Ok(value) => {
                                     control_flow!(output_dict.set_item(&field.name_py, value))?;
                                 }
                                 Err(ValError::Omit) => continue,
+                                // the last field of partial input may be incomplete, so it's dropped
+                                // rather than reported
+                                Err(ValError::LineErrors(_)) if is_last_partial => {}
                                 Err(ValError::LineErrors(line_errors)) => {
                                     for err in line_errors {
                                         errors.push(
                                             lookup_path
-                                            .apply_error_loc(err, self.loc_by_alias, &field.name)
+                                            .apply_error_loc(err, loc_by_alias, &field.name)
                                         );
                                     }
                                 }
@@ -224,7 +244,7 @@ impl Validator for TypedDictValidator {
                                     errors.push(field.lookup_key.error(
                                         ErrorTypeDefaults::Missing,
                                         input,
-                                        self.loc_by_alias,
+                                        loc_by_alias,
                                         &field.name
                                     ));
                                 }
@@ -250,6 +270,9 @@ impl Validator for TypedDictValidator {
 
                 if let Some(ref mut used_keys) = used_keys {
                     for item_result in <$iter>::new($dict)? {
+                        if fail_fast && !errors.is_empty() {
+                            break;
+                        }
                         let (raw_key, value) = item_result?;
                         let either_str = match raw_key.validate_str(true, false).map(ValidationMatch::into_inner) {
                             Ok(k) => k,
@@ -287,10 +310,14 @@ impl Validator for TypedDictValidator {
                             ExtraBehavior::Allow => {
                             let py_key = either_str.as_py_string(py);
                                 if let Some(ref validator) = self.extras_validator {
-                                    match validator.validate(py, value, state) {
+                                    let is_last_partial = partial_last_key == Some(cow.as_ref());
+                                    match state.with_allow_partial(is_last_partial, |state| {
+                                        validator.validate(py, value, state)
+                                    }) {
                                         Ok(value) => {
                                             output_dict.set_item(py_key, value)?;
                                         }
+                                        Err(ValError::LineErrors(_)) if is_last_partial => {}
                                         Err(ValError::LineErrors(line_errors)) => {
                                             for err in line_errors {
                                                 errors.push(
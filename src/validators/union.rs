@@ -263,7 +263,7 @@ impl<'a> MaybeErrors<'a> {
                          }| {
                             line_errors.into_iter().map(move |err| {
                                 let case_label = label.unwrap_or(choice.get_name());
-                                err.with_outer_location(case_label.into())
+                                err.with_outer_label(case_label.into())
                             })
                         },
                     )
@@ -456,30 +456,19 @@ impl TaggedUnionValidator {
         };
         let tag_cow = either_tag.as_cow()?;
         let tag = tag_cow.as_ref();
-        // custom logic to distinguish between different function and tuple schemas
-        if tag == "function" || tag == "tuple" {
+        // custom logic to distinguish between different function schemas
+        if tag == "function" {
             let mode = match dict {
                 GenericMapping::PyDict(dict) => match dict.get_item(intern!(py, "mode"))? {
-                    Some(m) => Some(m.validate_str(true, false)?.into_inner()),
-                    None => None,
+                    Some(m) => m.validate_str(true, false)?.into_inner(),
+                    None => return Err(self.tag_not_found(input)),
                 },
                 _ => unreachable!(),
             };
-            if tag == "function" {
-                let mode = mode.ok_or_else(|| self.tag_not_found(input))?;
-                match mode.as_cow()?.as_ref() {
-                    "plain" => Ok(intern!(py, "function-plain")),
-                    "wrap" => Ok(intern!(py, "function-wrap")),
-                    _ => Ok(intern!(py, "function")),
-                }
-            } else {
-                // tag == "tuple"
-                if let Some(mode) = mode {
-                    if mode.as_cow()?.as_ref() == "positional" {
-                        return Ok(intern!(py, "tuple-positional"));
-                    }
-                }
-                Ok(intern!(py, "tuple-variable"))
+            match mode.as_cow()?.as_ref() {
+                "plain" => Ok(intern!(py, "function-plain")),
+                "wrap" => Ok(intern!(py, "function-wrap")),
+                _ => Ok(intern!(py, "function")),
             }
         } else {
             Ok(PyString::new(py, tag))
@@ -496,7 +485,7 @@ impl TaggedUnionValidator {
         if let Ok(Some((tag, validator))) = self.lookup.validate(py, tag) {
             return match validator.validate(py, input, state) {
                 Ok(res) => Ok(res),
-                Err(err) => Err(err.with_outer_location(tag.as_loc_item())),
+                Err(err) => Err(err.with_outer_label(tag.as_loc_item())),
             };
         }
         match self.custom_error {
@@ -66,6 +66,26 @@ impl<'a> ValidationState<'a> {
         self.extra.strict.unwrap_or(default)
     }
 
+    pub fn fail_fast_or(&self, default: bool) -> bool {
+        self.extra.fail_fast.unwrap_or(default)
+    }
+
+    pub fn loc_by_alias_or(&self, default: bool) -> bool {
+        self.extra.loc_by_alias.unwrap_or(default)
+    }
+
+    /// Call `f` with `allow_partial` set as given, used since only the last item of a collection may be
+    /// incomplete when validating partial input. Callers drop errors from that last item rather than
+    /// reporting them, since the input it was parsed from may have been cut off.
+    pub fn with_allow_partial<R>(&mut self, allow_partial: bool, f: impl FnOnce(&mut Self) -> R) -> R {
+        if self.extra.allow_partial == allow_partial {
+            f(self)
+        } else {
+            let mut state = self.rebind_extra(|extra| extra.allow_partial = allow_partial);
+            f(&mut state)
+        }
+    }
+
     /// Sets the exactness to the lower of the current exactness
     /// and the given exactness.
     ///
@@ -187,6 +187,19 @@ def test_any_config_timedelta_float():
     assert s.to_json({h2: 'foo'}) == b'{"7200":"foo"}'
 
 
+def test_any_config_datetime_timestamp():
+    s = SchemaSerializer(core_schema.any_schema(), config={'ser_json_datetime': 'timestamp'})
+    dt = datetime(2022, 6, 8, 12, 13, 14, 250_000, tzinfo=timezone.utc)
+    assert s.to_python(dt) == dt
+    assert s.to_python(dt, mode='json') == 1654690394.25
+    assert s.to_json(dt) == b'1654690394.25'
+
+    assert s.to_python({dt: 'foo'}, mode='json') == {'1654690394.25': 'foo'}
+    assert s.to_json({dt: 'foo'}) == b'{"1654690394.25":"foo"}'
+    # dates and times aren't affected
+    assert s.to_json(dt.date()) == b'"2022-06-08"'
+
+
 def test_any_config_timedelta_float_faction():
     s = SchemaSerializer(core_schema.any_schema(), config={'ser_json_timedelta': 'float'})
     one_half_s = timedelta(seconds=1.5)
@@ -418,6 +431,18 @@ def test_base64():
         (lambda: datetime(2032, 1, 1, 1, 1, tzinfo=timezone.utc), {}, b'"2032-01-01T01:01:00Z"'),
         (lambda: datetime(2032, 1, 1, 1, 1, tzinfo=timezone(timedelta(hours=2))), {}, b'"2032-01-01T01:01:00+02:00"'),
         (lambda: datetime(2032, 1, 1), {}, b'"2032-01-01T00:00:00"'),
+        (lambda: datetime(2032, 1, 1, 1, 1, 1, 500_000), dict(datetime_mode='timestamp'), b'1956531661.5'),
+        (
+            lambda: datetime(2032, 1, 1, 1, 1, 1, 500_000),
+            dict(datetime_mode='timestamp', timestamp_unit='ms'),
+            b'1956531661500',
+        ),
+        (lambda: datetime(2032, 1, 1, 1, 1, 1, 500_000), dict(datetime_mode='timestamp_ms'), b'1956531661500'),
+        (
+            lambda: datetime(2032, 1, 1, 3, 1, tzinfo=timezone(timedelta(hours=2))),
+            dict(datetime_mode='timestamp', timestamp_unit='ms'),
+            b'1956531660000',
+        ),
         (lambda: time(12, 34, 56), {}, b'"12:34:56"'),
         (lambda: timedelta(days=12, seconds=34, microseconds=56), {}, b'"P12DT34.000056S"'),
         (lambda: timedelta(days=12, seconds=34, microseconds=56), dict(timedelta_mode='float'), b'1036834.000056'),
@@ -0,0 +1,63 @@
+import math
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (complex(1, 2), '1+2j'),
+        (complex(-1.5, -2.5), '-1.5-2.5j'),
+        (complex(0, 3), '3j'),
+        (complex(4, 0), '4+0j'),
+        (complex(1e20, 1e-10), '1e+20+1e-10j'),
+        (complex(float('inf'), float('nan')), 'inf+nanj'),
+    ],
+)
+def test_complex_json(value, expected):
+    s = SchemaSerializer(core_schema.complex_schema())
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == f'"{expected}"'.encode()
+
+    # the string representation can be validated back to the same value
+    v = SchemaValidator(core_schema.complex_schema())
+    c = v.validate_json(s.to_json(value))
+    if math.isnan(value.imag):
+        assert math.isnan(c.imag)
+    else:
+        assert c == value
+
+
+def test_complex_subclass():
+    class MyComplex(complex):
+        def __repr__(self):
+            return 'MyComplex'
+
+    s = SchemaSerializer(core_schema.complex_schema())
+    assert s.to_json(MyComplex(1, 2)) == b'"1+2j"'
+
+
+def test_complex_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.complex_schema(), core_schema.complex_schema()))
+    assert s.to_python({1 + 2j: 3j}) == {1 + 2j: 3j}
+    assert s.to_python({1 + 2j: 3j}, mode='json') == {'1+2j': '3j'}
+    assert s.to_json({1 + 2j: 3j}) == b'{"1+2j":"3j"}'
+
+
+def test_complex_fallback():
+    s = SchemaSerializer(core_schema.complex_schema())
+    with pytest.warns(UserWarning, match='Expected `complex` but got `int` - serialized value may not be as expected'):
+        assert s.to_python(123, mode='json') == 123
+
+    with pytest.warns(UserWarning, match='Expected `complex` but got `int` - serialized value may not be as expected'):
+        assert s.to_json(123) == b'123'
+
+
+def test_any_complex():
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_python(1 + 2j) == 1 + 2j
+    assert s.to_python({1 + 2j: 3j}, mode='json') == {'1+2j': '3j'}
+    assert s.to_json([1 + 2j]) == b'["1+2j"]'
@@ -192,3 +192,59 @@ def test_extra_custom_serializer():
     m = v.validate_python({'extra': 'extra'})
 
     assert s.to_python(m) == {'extra': 'extra bam!'}
+
+
+def test_serialize_as_any():
+    @dataclasses.dataclass
+    class Parent:
+        a: int
+
+    @dataclasses.dataclass
+    class Child(Parent):
+        b: str
+
+    def dataclass_schema(cls, *names, **kwargs):
+        return core_schema.dataclass_schema(
+            cls,
+            core_schema.dataclass_args_schema(
+                cls.__name__, [core_schema.dataclass_field(name=n, schema=core_schema.any_schema()) for n in names]
+            ),
+            list(names),
+            **kwargs,
+        )
+
+    Child.__pydantic_serializer__ = SchemaSerializer(dataclass_schema(Child, 'a', 'b'))
+
+    s = SchemaSerializer(dataclass_schema(Parent, 'a'))
+    assert s.to_python(Child(a=1, b='x')) == {'a': 1}
+    assert s.to_python(Child(a=1, b='x'), serialize_as_any=True) == {'a': 1, 'b': 'x'}
+    assert s.to_json(Child(a=1, b='x'), serialize_as_any=True) == b'{"a":1,"b":"x"}'
+
+    s = SchemaSerializer(dataclass_schema(Parent, 'a', serialize_as_any=True))
+    assert s.to_python(Child(a=1, b='x')) == {'a': 1, 'b': 'x'}
+    assert s.to_json(Child(a=1, b='x')) == b'{"a":1,"b":"x"}'
+
+
+def test_exclude_if():
+    @dataclasses.dataclass
+    class Foo:
+        a: int
+        b: str
+
+    schema = core_schema.dataclass_schema(
+        Foo,
+        core_schema.dataclass_args_schema(
+            'Foo',
+            [
+                core_schema.dataclass_field(name='a', schema=core_schema.int_schema()),
+                core_schema.dataclass_field(
+                    name='b', schema=core_schema.str_schema(), serialization_exclude_if='empty'
+                ),
+            ],
+        ),
+        ['a', 'b'],
+    )
+    s = SchemaSerializer(schema)
+    assert s.to_python(Foo(a=1, b='x')) == {'a': 1, 'b': 'x'}
+    assert s.to_python(Foo(a=1, b='')) == {'a': 1}
+    assert s.to_json(Foo(a=1, b='')) == b'{"a":1}'
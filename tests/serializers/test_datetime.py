@@ -1,8 +1,9 @@
+import json
 from datetime import date, datetime, time, timedelta, timezone
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 def test_datetime():
@@ -107,3 +108,91 @@ def test_date_datetime_union():
     assert v.to_python(datetime(2022, 12, 2, 1)) == datetime(2022, 12, 2, 1)
     assert v.to_python(datetime(2022, 12, 2, 1), mode='json') == '2022-12-02T01:00:00'
     assert v.to_json(datetime(2022, 12, 2, 1)) == b'"2022-12-02T01:00:00"'
+
+
+@pytest.mark.parametrize(
+    'unit,value,expected',
+    [
+        ('s', datetime(2022, 6, 8, 12, 13, 14, 123456, tzinfo=timezone.utc), 1654690394),
+        ('ms', datetime(2022, 6, 8, 12, 13, 14, 123456, tzinfo=timezone.utc), 1654690394123),
+        ('us', datetime(2022, 6, 8, 12, 13, 14, 123456, tzinfo=timezone.utc), 1654690394123456),
+        ('ns', datetime(2022, 6, 8, 12, 13, 14, 123456, tzinfo=timezone.utc), 1654690394123456000),
+        ('ms', datetime(2022, 6, 8, 14, 13, 14, tzinfo=tz(hours=2)), 1654690394000),
+        ('ms', datetime(2022, 6, 8, 12, 13, 14), 1654690394000),
+        ('ms', datetime(1969, 12, 31, 23, 59, 59, 500_000, tzinfo=timezone.utc), -500),
+    ],
+)
+def test_datetime_timestamp_unit(unit, value, expected):
+    v = SchemaSerializer(core_schema.datetime_schema(ser_json_timestamp_unit=unit))
+    assert v.to_python(value) == value
+    assert v.to_python(value, mode='json') == expected
+    assert v.to_json(value) == str(expected).encode()
+
+
+def test_datetime_timestamp_unit_config():
+    v = SchemaSerializer(core_schema.datetime_schema(), {'ser_json_timestamp_unit': 'ms'})
+    dt = datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)
+    assert v.to_json(dt) == b'1654690394000'
+
+    schema = core_schema.dict_schema(core_schema.datetime_schema(), core_schema.int_schema())
+    v = SchemaSerializer(schema, {'ser_json_timestamp_unit': 's'})
+    assert v.to_json({dt: 1}) == b'{"1654690394":1}'
+    assert v.to_python({dt: 1}, mode='json') == {'1654690394': 1}
+
+
+def test_datetime_timestamp_unit_auto():
+    with pytest.raises(SchemaError, match='Invalid serialization timestamp unit: `auto`'):
+        SchemaSerializer({'type': 'datetime', 'ser_json_timestamp_unit': 'auto'})
+
+
+@pytest.mark.parametrize(
+    'mode,expected',
+    [
+        ('iso8601', '2022-06-08T12:13:14.123456Z'),
+        ('timestamp', 1654690394.123456),
+        ('timestamp_ms', 1654690394123),
+    ],
+)
+def test_datetime_mode(mode, expected):
+    v = SchemaSerializer(core_schema.datetime_schema(), {'ser_json_datetime': mode})
+    dt = datetime(2022, 6, 8, 12, 13, 14, 123456, tzinfo=timezone.utc)
+    assert v.to_python(dt) == dt
+    assert v.to_python(dt, mode='json') == expected
+    assert json.loads(v.to_json(dt)) == expected
+
+
+def test_datetime_mode_timestamp_unit():
+    # `ser_json_timestamp_unit` on the schema takes precedence over `ser_json_datetime`
+    v = SchemaSerializer(core_schema.datetime_schema(ser_json_timestamp_unit='s'), {'ser_json_datetime': 'timestamp'})
+    assert v.to_json(datetime(2022, 6, 8, 12, 13, 14, 123456, tzinfo=timezone.utc)) == b'1654690394'
+
+
+def test_datetime_mode_timestamp_unit_config():
+    v = SchemaSerializer(
+        core_schema.datetime_schema(), {'ser_json_datetime': 'timestamp', 'ser_json_timestamp_unit': 'ms'}
+    )
+    assert v.to_json(datetime(2022, 6, 8, 12, 13, 14, 123456, tzinfo=timezone.utc)) == b'1654690394123'
+
+
+def test_datetime_mode_invalid():
+    with pytest.raises(SchemaError, match='Invalid datetime serialization mode: `epoch`, expected `iso8601`'):
+        SchemaSerializer(core_schema.datetime_schema(), {'ser_json_datetime': 'epoch'})
+
+
+def test_datetime_mode_timestamp_ms_unit():
+    v = SchemaSerializer(
+        core_schema.datetime_schema(), {'ser_json_datetime': 'timestamp_ms', 'ser_json_timestamp_unit': 'ms'}
+    )
+    assert v.to_json(datetime(2022, 6, 8, 12, 13, 14, 123456, tzinfo=timezone.utc)) == b'1654690394123'
+
+    with pytest.raises(SchemaError, match='`timestamp_ms` datetime serialization mode can only be used with the `ms`'):
+        SchemaSerializer(
+            core_schema.datetime_schema(), {'ser_json_datetime': 'timestamp_ms', 'ser_json_timestamp_unit': 's'}
+        )
+
+
+def test_datetime_mode_iso8601_timestamp_unit():
+    with pytest.raises(SchemaError, match='A timestamp unit cannot be used with the `iso8601` datetime serialization'):
+        SchemaSerializer(
+            core_schema.datetime_schema(), {'ser_json_datetime': 'iso8601', 'ser_json_timestamp_unit': 'ms'}
+        )
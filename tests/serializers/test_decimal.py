@@ -41,6 +41,13 @@ def test_decimal_key():
         (Decimal('Infinity'), 'Infinity'),
         (Decimal('-Infinity'), '-Infinity'),
         (Decimal('NaN'), 'NaN'),
+        (Decimal('-sNaN12'), '-sNaN12'),
+        (Decimal('0.000001'), '0.000001'),
+        (Decimal('0.0000001'), '1E-7'),
+        (Decimal('-0.00'), '-0.00'),
+        (Decimal('1.5E+3'), '1.5E+3'),
+        (Decimal('12e-10'), '1.2E-9'),
+        (Decimal('100'), '100'),
     ],
 )
 def test_decimal_json(value, expected):
@@ -55,3 +62,13 @@ def test_any_decimal_key():
 
     assert v.to_python(input_value, mode='json') == {'123.456': 1}
     assert v.to_json(input_value) == b'{"123.456":1}'
+
+
+def test_decimal_subclass_str():
+    class MyDecimal(Decimal):
+        def __str__(self):
+            return 'custom'
+
+    v = SchemaSerializer(core_schema.decimal_schema())
+    assert v.to_python(MyDecimal('1.5'), mode='json') == 'custom'
+    assert v.to_json(MyDecimal('1.5')) == b'"custom"'
@@ -0,0 +1,78 @@
+from enum import Enum
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+def test_plain_enum():
+    class MyEnum(Enum):
+        a = 1
+        b = 2
+
+    v = SchemaSerializer(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values())))
+
+    # debug(v)
+    assert v.to_python(MyEnum.a) is MyEnum.a
+    assert v.to_python(MyEnum.a, mode='json') == 1
+    assert v.to_json(MyEnum.a) == b'1'
+
+    with pytest.warns(UserWarning, match='Expected `enum` but got `int` - serialized value may not be as expected'):
+        assert v.to_python(1) == 1
+    with pytest.warns(UserWarning, match='Expected `enum` but got `int` - serialized value may not be as expected'):
+        assert v.to_json(1) == b'1'
+
+
+def test_int_enum():
+    class MyEnum(int, Enum):
+        a = 1
+        b = 2
+
+    v = SchemaSerializer(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='int'))
+
+    assert v.to_python(MyEnum.a) is MyEnum.a
+    assert v.to_python(MyEnum.a, mode='json') == 1
+    assert type(v.to_python(MyEnum.a, mode='json')) is int
+    assert v.to_json(MyEnum.a) == b'1'
+
+
+def test_str_enum():
+    class MyEnum(str, Enum):
+        a = 'a'
+        b = 'b'
+
+    v = SchemaSerializer(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='str'))
+
+    assert v.to_python(MyEnum.a) is MyEnum.a
+    assert v.to_python(MyEnum.a, mode='json') == 'a'
+    assert type(v.to_python(MyEnum.a, mode='json')) is str
+    assert v.to_json(MyEnum.a) == b'"a"'
+
+
+def test_float_enum():
+    class MyEnum(float, Enum):
+        a = 1.5
+        b = 2.5
+
+    v = SchemaSerializer(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='float'))
+
+    assert v.to_python(MyEnum.a) is MyEnum.a
+    assert v.to_python(MyEnum.a, mode='json') == 1.5
+    assert v.to_json(MyEnum.b) == b'2.5'
+
+
+def test_dict_keys():
+    class MyEnum(str, Enum):
+        a = 'x'
+        b = 'y'
+
+    v = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='str'),
+            core_schema.int_schema(),
+        )
+    )
+
+    assert v.to_python({MyEnum.a: 1}) == {MyEnum.a: 1}
+    assert v.to_python({MyEnum.a: 1}, mode='json') == {'x': 1}
+    assert v.to_json({MyEnum.a: 1, MyEnum.b: 2}) == b'{"x":1,"y":2}'
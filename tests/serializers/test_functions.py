@@ -212,24 +212,24 @@ def test_function_args_str():
         )
     )
     assert s.to_python(123) == (
-        "123 info=SerializationInfo(include=None, exclude=None, mode='python', by_alias=True, exclude_unset=False, "
-        'exclude_defaults=False, exclude_none=False, round_trip=False)'
+        "123 info=SerializationInfo(include=None, exclude=None, context=None, mode='python', by_alias=True, "
+        'exclude_unset=False, exclude_defaults=False, exclude_none=False, round_trip=False)'
     )
     assert s.to_python(123, mode='other') == (
-        "123 info=SerializationInfo(include=None, exclude=None, mode='other', by_alias=True, exclude_unset=False, "
-        'exclude_defaults=False, exclude_none=False, round_trip=False)'
+        "123 info=SerializationInfo(include=None, exclude=None, context=None, mode='other', by_alias=True, "
+        'exclude_unset=False, exclude_defaults=False, exclude_none=False, round_trip=False)'
     )
     assert s.to_python(123, include={'x'}) == (
-        "123 info=SerializationInfo(include={'x'}, exclude=None, mode='python', by_alias=True, exclude_unset=False, "
-        'exclude_defaults=False, exclude_none=False, round_trip=False)'
+        "123 info=SerializationInfo(include={'x'}, exclude=None, context=None, mode='python', by_alias=True, "
+        'exclude_unset=False, exclude_defaults=False, exclude_none=False, round_trip=False)'
     )
     assert s.to_python(123, mode='json', exclude={1: {2}}) == (
-        "123 info=SerializationInfo(include=None, exclude={1: {2}}, mode='json', by_alias=True, exclude_unset=False, "
-        'exclude_defaults=False, exclude_none=False, round_trip=False)'
+        "123 info=SerializationInfo(include=None, exclude={1: {2}}, context=None, mode='json', by_alias=True, "
+        'exclude_unset=False, exclude_defaults=False, exclude_none=False, round_trip=False)'
     )
     assert s.to_json(123) == (
-        b"\"123 info=SerializationInfo(include=None, exclude=None, mode='json', by_alias=True, exclude_unset=False, "
-        b'exclude_defaults=False, exclude_none=False, round_trip=False)"'
+        b"\"123 info=SerializationInfo(include=None, exclude=None, context=None, mode='json', by_alias=True, "
+        b'exclude_unset=False, exclude_defaults=False, exclude_none=False, round_trip=False)"'
     )
 
 
@@ -320,6 +320,53 @@ def test_function_wrap():
     assert s.to_json('foo') == b'"result=3 repr=SerializationCallable(serializer=int)"'
 
 
+def test_function_context():
+    def f(value, info):
+        return f'{value} context={info.context!r}'
+
+    s = SchemaSerializer(
+        core_schema.int_schema(serialization=core_schema.plain_serializer_function_ser_schema(f, info_arg=True))
+    )
+    assert s.to_python(1) == '1 context=None'
+    assert s.to_python(1, context={'tenant': 'a'}) == "1 context={'tenant': 'a'}"
+    assert s.to_python(1, mode='json', context='ctx') == "1 context='ctx'"
+    assert s.to_json(1, context=['x']) == b'"1 context=[\'x\']"'
+
+
+def test_function_context_info():
+    f_info = None
+
+    def f(value, info):
+        nonlocal f_info
+        f_info = info
+        return value
+
+    s = SchemaSerializer(
+        core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(f, info_arg=True))
+    )
+    context = {'locale': 'fr'}
+    assert s.to_python(1, context=context) == 1
+    assert f_info.context is context
+    assert vars(f_info)['context'] is context
+    assert repr(f_info) == (
+        "SerializationInfo(include=None, exclude=None, context={'locale': 'fr'}, mode='python', by_alias=True, "
+        'exclude_unset=False, exclude_defaults=False, exclude_none=False, round_trip=False)'
+    )
+
+
+def test_function_wrap_context():
+    def f(value, serializer, info):
+        return f'{serializer(value)} context={info.context}'
+
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.int_schema(), serialization=core_schema.wrap_serializer_function_ser_schema(f, info_arg=True)
+        )
+    )
+    assert s.to_python([1, 2], context='redact') == '[1, 2] context=redact'
+    assert s.to_json([1, 2], context='redact') == b'"[1, 2] context=redact"'
+
+
 def test_function_wrap_return_scheam():
     def f(value, serializer):
         if value == 42:
@@ -575,6 +622,26 @@ def test_raise_unexpected():
         assert s.to_json('foo') == b'"foo"'
 
 
+def test_raise_unexpected_warnings_mode():
+    def raise_unexpected(_value):
+        raise PydanticSerializationUnexpectedValue('unexpected')
+
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(raise_unexpected))
+        )
+    )
+    with pytest.warns(UserWarning, match=r'PydanticSerializationUnexpectedValue\(unexpected\) at `0`'):
+        assert s.to_python(['foo']) == ['foo']
+
+    with pytest.raises(PydanticSerializationUnexpectedValue, match=r'^unexpected at `0`$'):
+        s.to_python(['foo'], warnings='error')
+    with pytest.raises(PydanticSerializationUnexpectedValue, match=r'^unexpected at `0`$'):
+        s.to_json(['foo'], warnings='error')
+
+    assert s.to_python(['foo'], warnings='none') == ['foo']
+
+
 def test_pydantic_serialization_unexpected_value():
     v = PydanticSerializationUnexpectedValue('abc')
     assert str(v) == 'abc'
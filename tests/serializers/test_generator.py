@@ -90,12 +90,12 @@ def test_generator_int():
     with pytest.raises(ValueError, match='oops'):
         s.to_json(gen_error(1, 2))
 
-    with pytest.warns(UserWarning, match='Expected `int` but got `str` - serialized value may not be as expected'):
+    with pytest.warns(UserWarning, match='Expected `int` but got `str` at `1` - serialized value may not be'):
         s.to_json(gen_ok(1, 'a'))
 
     gen = s.to_python(gen_ok(1, 'a'))
     assert next(gen) == 1
-    with pytest.warns(UserWarning, match='Expected `int` but got `str` - serialized value may not be as expected'):
+    with pytest.warns(UserWarning, match='Expected `int` but got `str` at `1` - serialized value may not be'):
         assert next(gen) == 'a'
     with pytest.warns(UserWarning, match='Expected `generator` but got `tuple` - serialized value may not.+'):
         s.to_python((1, 2, 3))
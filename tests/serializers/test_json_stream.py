@@ -0,0 +1,95 @@
+import io
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+class ChunkWriter:
+    def __init__(self):
+        self.chunks = []
+
+    def write(self, data: bytes) -> int:
+        self.chunks.append(data)
+        return len(data)
+
+
+def test_to_json_stream():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    f = io.BytesIO()
+    assert s.to_json_stream([1, 2, 3], f) is None
+    assert f.getvalue() == b'[1,2,3]'
+
+
+def test_to_json_stream_matches_to_json():
+    s = SchemaSerializer(
+        core_schema.list_schema(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    )
+    value = [{'a': i, 'b': i * 2} for i in range(1000)]
+    f = io.BytesIO()
+    s.to_json_stream(value, f, indent=2, exclude={0})
+    assert f.getvalue() == s.to_json(value, indent=2, exclude={0})
+
+
+@pytest.mark.parametrize('buffer_size', [1, 16, 100])
+def test_buffer_size(buffer_size):
+    s = SchemaSerializer(core_schema.list_schema(core_schema.str_schema()))
+    value = ['x' * 10] * 20
+    writer = ChunkWriter()
+    s.to_json_stream(value, writer, buffer_size=buffer_size)
+    assert b''.join(writer.chunks) == s.to_json(value)
+    assert len(writer.chunks) > 1
+    assert max(len(chunk) for chunk in writer.chunks) <= max(buffer_size, 12)
+
+
+def test_partial_writes():
+    class PartialWriter(ChunkWriter):
+        def write(self, data: bytes) -> int:
+            return super().write(data[:3])
+
+    s = SchemaSerializer(core_schema.str_schema())
+    writer = PartialWriter()
+    s.to_json_stream('hello world', writer)
+    assert b''.join(writer.chunks) == b'"hello world"'
+
+
+def test_write_error():
+    class BrokenWriter:
+        def write(self, data: bytes) -> int:
+            raise OSError('disk full')
+
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(OSError, match='disk full'):
+        s.to_json_stream(1, BrokenWriter())
+
+    with pytest.raises(TypeError):
+        s.to_json_stream(1, io.StringIO())
+
+
+def test_serialization_error_not_flushed():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.any_schema()))
+    writer = ChunkWriter()
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        s.to_json_stream([1, object()], writer)
+    assert writer.chunks == []
+
+
+def test_context():
+    def f(value, info):
+        return info.context
+
+    s = SchemaSerializer(
+        core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(f, info_arg=True))
+    )
+    f = io.BytesIO()
+    s.to_json_stream(1, f, context={'a': 1})
+    assert f.getvalue() == b'{"a":1}'
+
+
+def test_sort_keys():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.set_schema()))
+    value = {'b': {3, 1, 2}, 'a': {'z', 'y'}}
+    f = io.BytesIO()
+    s.to_json_stream(value, f, indent=2, sort_keys=True, buffer_size=8)
+    assert f.getvalue() == s.to_json(value, indent=2, sort_keys=True)
+    assert s.to_json(value, sort_keys=True) == b'{"a":["y","z"],"b":[1,2,3]}'
@@ -48,9 +48,9 @@ def test_list_str_fallback():
         assert v.to_json([1, 2, 3]) == b'[1,2,3]'
     assert [w.message.args[0] for w in warning_info.list] == [
         'Pydantic serializer warnings:\n'
-        '  Expected `str` but got `int` - serialized value may not be as expected\n'
-        '  Expected `str` but got `int` - serialized value may not be as expected\n'
-        '  Expected `str` but got `int` - serialized value may not be as expected'
+        '  Expected `str` but got `int` at `0` - serialized value may not be as expected\n'
+        '  Expected `str` but got `int` at `1` - serialized value may not be as expected\n'
+        '  Expected `str` but got `int` at `2` - serialized value may not be as expected'
     ]
 
 
@@ -329,9 +329,7 @@ def test_filter_list_of_dicts():
 
 
 def test_positional_tuple():
-    s = SchemaSerializer(
-        {'type': 'tuple-positional', 'items_schema': [{'type': 'int'}, {'type': 'bytes'}, {'type': 'float'}]}
-    )
+    s = SchemaSerializer({'type': 'tuple', 'items_schema': [{'type': 'int'}, {'type': 'bytes'}, {'type': 'float'}]})
     assert s.to_python((1, b'2', 3.0)) == (1, b'2', 3.0)
     assert s.to_python((1, b'2', 3.0, 123)) == (1, b'2', 3.0, 123)
     assert s.to_python((1, b'2')) == (1, b'2')
@@ -351,7 +349,7 @@ def test_function_positional_tuple():
 
     s = SchemaSerializer(
         {
-            'type': 'tuple-positional',
+            'type': 'tuple',
             'items_schema': [
                 core_schema.any_schema(
                     serialization=core_schema.plain_serializer_function_ser_schema(partial(f, 'a'), info_arg=True)
@@ -359,10 +357,11 @@ def test_function_positional_tuple():
                 core_schema.any_schema(
                     serialization=core_schema.plain_serializer_function_ser_schema(partial(f, 'b'), info_arg=True)
                 ),
+                core_schema.any_schema(
+                    serialization=core_schema.plain_serializer_function_ser_schema(partial(f, 'extra'), info_arg=True)
+                ),
             ],
-            'extras_schema': core_schema.any_schema(
-                serialization=core_schema.plain_serializer_function_ser_schema(partial(f, 'extra'), info_arg=True)
-            ),
+            'variadic_item_index': 2,
         }
     )
     assert s.to_python((1,)) == ('a1',)
@@ -378,6 +377,32 @@ def test_function_positional_tuple():
     assert s.to_json((1, 2, 3)) == b'["a1","b2","extra3"]'
 
 
+def test_variadic_middle_tuple():
+    def f(prefix, value, _info):
+        return f'{prefix}{value}'
+
+    s = SchemaSerializer(
+        core_schema.tuple_schema(
+            [
+                core_schema.any_schema(
+                    serialization=core_schema.plain_serializer_function_ser_schema(partial(f, 'a'), info_arg=True)
+                ),
+                core_schema.any_schema(
+                    serialization=core_schema.plain_serializer_function_ser_schema(partial(f, 'v'), info_arg=True)
+                ),
+                core_schema.any_schema(
+                    serialization=core_schema.plain_serializer_function_ser_schema(partial(f, 'z'), info_arg=True)
+                ),
+            ],
+            variadic_item_index=1,
+        )
+    )
+    assert s.to_python((1, 2)) == ('a1', 'z2')
+    assert s.to_python((1, 2, 3)) == ('a1', 'v2', 'z3')
+    assert s.to_python((1, 2, 3, 4), mode='json') == ['a1', 'v2', 'v3', 'z4']
+    assert s.to_json((1, 2, 3, 4)) == b'["a1","v2","v3","z4"]'
+
+
 def test_list_dict_key():
     s = SchemaSerializer(core_schema.dict_schema(core_schema.list_schema(), core_schema.int_schema()))
     with pytest.warns(UserWarning, match=r'Expected `list\[any\]` but got `str`'):
@@ -12,7 +12,14 @@ except ImportError:
 import pytest
 from dirty_equals import IsJson
 
-from pydantic_core import PydanticSerializationError, SchemaSerializer, SchemaValidator, core_schema
+from pydantic_core import (
+    PydanticSerializationError,
+    PydanticSerializationUnexpectedValue,
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    core_schema,
+)
 
 from ..conftest import plain_repr
 
@@ -241,6 +248,75 @@ def test_model_wrong_warn():
         assert s.to_python({'foo': 1, 'bar': b'more'}) == {'foo': 1, 'bar': b'more'}
 
 
+def test_model_warnings_mode():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(core_schema.int_schema()),
+                    'bar': core_schema.model_field(
+                        core_schema.list_schema(
+                            core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+                        )
+                    ),
+                }
+            ),
+        )
+    )
+    m = BasicModel(foo='x', bar=[{'a': 1}, {'b': 'y'}])
+    msg = 'Expected `int` but got `str` at `{}` - serialized value may not be as expected'
+
+    with pytest.warns(UserWarning) as warning_info:
+        assert s.to_python(m, warnings='warn') == {'foo': 'x', 'bar': [{'a': 1}, {'b': 'y'}]}
+    assert [str(w.message) for w in warning_info] == [
+        f'Pydantic serializer warnings:\n  {msg.format("foo")}\n  {msg.format("bar.1.b")}'
+    ]
+
+    with pytest.raises(PydanticSerializationUnexpectedValue) as exc_info:
+        s.to_python(m, warnings='error')
+    assert str(exc_info.value) == msg.format('foo')
+
+    m = BasicModel(foo=1, bar=[{'a': 1}, {'b': 'y'}])
+    for mode in ('python', 'json'):
+        with pytest.raises(PydanticSerializationUnexpectedValue) as exc_info:
+            s.to_python(m, mode=mode, warnings='error')
+        assert str(exc_info.value) == msg.format('bar.1.b')
+    with pytest.raises(PydanticSerializationUnexpectedValue) as exc_info:
+        s.to_json(m, warnings='error')
+    assert str(exc_info.value) == msg.format('bar.1.b')
+
+    assert s.to_json(m, warnings='none') == b'{"foo":1,"bar":[{"a":1},{"b":"y"}]}'
+    assert s.to_json(m, warnings=False) == b'{"foo":1,"bar":[{"a":1},{"b":"y"}]}'
+
+
+def test_model_warnings_path_inferred():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel, core_schema.model_fields_schema({'foo': core_schema.model_field(core_schema.int_schema())})
+        )
+    )
+    m = BasicModel(foo='x')
+    m.__pydantic_serializer__ = s
+    msg = 'Expected `int` but got `str` at `{}` - serialized value may not be as expected'
+    any_serializer = SchemaSerializer(core_schema.any_schema())
+
+    with pytest.warns(UserWarning) as warning_info:
+        any_serializer.to_json([{'a': (m,)}, (x for x in [1, m])])
+    assert [str(w.message) for w in warning_info] == [
+        f'Pydantic serializer warnings:\n  {msg.format("0.a.0.foo")}\n  {msg.format("1.1.foo")}'
+    ]
+
+    with pytest.raises(PydanticSerializationUnexpectedValue) as exc_info:
+        any_serializer.to_python({'a': [1, m]}, mode='json', warnings='error')
+    assert str(exc_info.value) == msg.format('a.1.foo')
+
+    s = SchemaSerializer(core_schema.int_schema())
+    msg = 'Invalid warnings parameter `ignore`, expected `none`, `warn`, `error` or a boolean'
+    with pytest.raises(ValueError, match=msg):
+        s.to_python(1, warnings='ignore')
+
+
 def test_exclude_none():
     s = SchemaSerializer(
         core_schema.model_schema(
@@ -266,6 +342,56 @@ def test_exclude_none():
     assert s.to_json(BasicModel(foo=None, bar=b'more'), exclude_none=True) == b'{"bar":"more"}'
 
 
+@pytest.mark.parametrize(
+    'exclude_if,value,excluded',
+    [
+        ('none', None, True),
+        ('none', 0, False),
+        ('falsy', 0, True),
+        ('falsy', [], True),
+        ('falsy', 1, False),
+        ('default', 42, True),
+        ('default', 1, False),
+        ('empty', [], True),
+        ('empty', '', True),
+        ('empty', [0], False),
+        ('empty', 0, False),
+        (lambda v: v == 7, 7, True),
+        (lambda v: v == 7, 8, False),
+    ],
+)
+def test_exclude_if(exclude_if, value, excluded):
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'foo': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.any_schema(), default=42),
+                        serialization_exclude_if=exclude_if,
+                    ),
+                    'bar': core_schema.model_field(core_schema.int_schema()),
+                }
+            ),
+        )
+    )
+    expected = {'bar': 1} if excluded else {'foo': value, 'bar': 1}
+    assert s.to_python(BasicModel(foo=value, bar=1)) == expected
+    assert json.loads(s.to_json(BasicModel(foo=value, bar=1))) == expected
+
+
+def test_exclude_if_invalid():
+    with pytest.raises(SchemaError, match='Invalid serialization_exclude_if: `never`'):
+        SchemaSerializer(
+            core_schema.model_schema(
+                BasicModel,
+                core_schema.model_fields_schema(
+                    {'foo': core_schema.model_field(core_schema.int_schema(), serialization_exclude_if='never')}
+                ),
+            )
+        )
+
+
 class FieldsSetModel:
     __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
 
@@ -574,6 +700,32 @@ def test_property():
     assert s.to_json(Model(width=3, height=4), round_trip=True) == b'{"width":3,"height":4}'
 
 
+def test_sort_keys():
+    class Model(BasicModel):
+        @property
+        def area(self) -> int:
+            return self.width * self.height
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'width': core_schema.model_field(core_schema.int_schema(), serialization_alias='w'),
+                    'height': core_schema.model_field(core_schema.int_schema()),
+                    'tags': core_schema.model_field(core_schema.frozenset_schema(core_schema.str_schema())),
+                },
+                extra_behavior='allow',
+                computed_fields=[core_schema.computed_field('area', core_schema.int_schema())],
+            ),
+            extra_behavior='allow',
+        )
+    )
+    m = Model(width=3, height=4, tags=frozenset({'y', 'x'}), __pydantic_extra__={'c': 1})
+    assert s.to_json(m, sort_keys=True) == b'{"area":12,"c":1,"height":4,"tags":["x","y"],"w":3}'
+    assert s.to_json(m, sort_keys=True, by_alias=False) == b'{"area":12,"c":1,"height":4,"tags":["x","y"],"width":3}'
+
+
 def test_property_alias():
     @dataclasses.dataclass
     class Model:
@@ -608,6 +760,38 @@ def test_property_alias():
     assert s.to_json(Model(3, 4)) == b'{"width":3,"height":4,"Area":12,"volume":48}'
 
 
+def test_computed_field_context():
+    @dataclasses.dataclass
+    class Model:
+        price: int
+
+        @property
+        def display_price(self) -> int:
+            return self.price
+
+    def localise(value, info):
+        return f'{value} {info.context["currency"]}'
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {'price': core_schema.model_field(core_schema.int_schema())},
+                computed_fields=[
+                    core_schema.computed_field(
+                        'display_price',
+                        core_schema.int_schema(
+                            serialization=core_schema.plain_serializer_function_ser_schema(localise, info_arg=True)
+                        ),
+                    )
+                ],
+            ),
+        )
+    )
+    assert s.to_python(Model(3), context={'currency': 'EUR'}) == {'price': 3, 'display_price': '3 EUR'}
+    assert s.to_json(Model(3), context={'currency': 'GBP'}) == b'{"price":3,"display_price":"3 GBP"}'
+
+
 def test_computed_field_exclude_none():
     @dataclasses.dataclass
     class Model:
@@ -944,3 +1128,79 @@ def test_extra_custom_serializer():
     m.__pydantic_extra__ = {'extra': 'extra'}
 
     assert s.to_python(m) == {'extra': 'extra bam!'}
+
+
+def _model_fields_schema(**fields):
+    return core_schema.model_fields_schema({k: core_schema.model_field(v) for k, v in fields.items()})
+
+
+def test_serialize_as_any():
+    class Parent(BasicModel):
+        pass
+
+    class Child(Parent):
+        pass
+
+    class Unrelated(BasicModel):
+        pass
+
+    parent_schema = core_schema.model_schema(Parent, _model_fields_schema(a=core_schema.int_schema()))
+    Parent.__pydantic_serializer__ = SchemaSerializer(parent_schema)
+    Child.__pydantic_serializer__ = SchemaSerializer(
+        core_schema.model_schema(Child, _model_fields_schema(a=core_schema.int_schema(), b=core_schema.str_schema()))
+    )
+    Unrelated.__pydantic_serializer__ = SchemaSerializer(
+        core_schema.model_schema(Unrelated, _model_fields_schema(c=core_schema.int_schema()))
+    )
+    s = SchemaSerializer(
+        core_schema.model_schema(BasicModel, _model_fields_schema(item=parent_schema, items=core_schema.list_schema()))
+    )
+    m = BasicModel(item=Child(a=1, b='x'), items=[])
+
+    assert s.to_python(m) == {'item': {'a': 1}, 'items': []}
+    assert s.to_python(m, serialize_as_any=True) == {'item': {'a': 1, 'b': 'x'}, 'items': []}
+    assert s.to_json(m, serialize_as_any=True) == b'{"item":{"a":1,"b":"x"},"items":[]}'
+    assert s.to_python(m, mode='json', serialize_as_any=True) == {'item': {'a': 1, 'b': 'x'}, 'items': []}
+
+    # the flag is passed on to the subclass serializer
+    m = BasicModel(item=Parent(a=1), items=[Child(a=2, b='y', other=3)])
+    assert s.to_python(m, serialize_as_any=True) == {'item': {'a': 1}, 'items': [{'a': 2, 'b': 'y'}]}
+
+    # only subclass instances use their own serializer
+    m = BasicModel(item=Unrelated(a=1, c=2), items=[])
+    assert s.to_python(m, serialize_as_any=True) == {'item': {'a': 1}, 'items': []}
+
+
+def test_serialize_as_any_schema_and_config():
+    class Parent(BasicModel):
+        pass
+
+    class Child(Parent):
+        pass
+
+    class Inherited(Parent):
+        pass
+
+    Child.__pydantic_serializer__ = SchemaSerializer(
+        core_schema.model_schema(Child, _model_fields_schema(a=core_schema.int_schema(), b=core_schema.int_schema()))
+    )
+
+    s = SchemaSerializer(
+        core_schema.model_schema(Parent, _model_fields_schema(a=core_schema.int_schema()), serialize_as_any=True)
+    )
+    assert s.to_python(Child(a=1, b=2)) == {'a': 1, 'b': 2}
+    assert s.to_json(Child(a=1, b=2)) == b'{"a":1,"b":2}'
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Parent,
+            _model_fields_schema(a=core_schema.int_schema()),
+            config=core_schema.CoreConfig(serialize_as_any=True),
+        )
+    )
+    assert s.to_python(Child(a=1, b=2)) == {'a': 1, 'b': 2}
+
+    # a subclass sharing its parent's serializer is serialized with the schema
+    Parent.__pydantic_serializer__ = s
+    assert s.to_python(Inherited(a=1, b=2)) == {'a': 1}
+    assert s.to_json(Inherited(a=1, b=2)) == b'{"a":1}'
@@ -0,0 +1,23 @@
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+ZoneInfo = pytest.importorskip('zoneinfo').ZoneInfo
+
+
+def test_timezone():
+    v = SchemaSerializer(core_schema.timezone_schema())
+    tz = ZoneInfo('America/New_York')
+    assert v.to_python(tz) is tz
+    assert v.to_python(tz, mode='json') == 'America/New_York'
+    assert v.to_json(tz) == b'"America/New_York"'
+
+    with pytest.warns(UserWarning, match='Expected `timezone` but got `int` - serialized value may not be as expected'):
+        assert v.to_python(123, mode='json') == 123
+
+
+def test_timezone_key():
+    v = SchemaSerializer(core_schema.dict_schema(core_schema.timezone_schema(), core_schema.int_schema()))
+    tz = ZoneInfo('Europe/Paris')
+    assert v.to_python({tz: 1}, mode='json') == {'Europe/Paris': 1}
+    assert v.to_json({tz: 1}) == b'{"Europe/Paris":1}'
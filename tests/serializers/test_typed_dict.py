@@ -170,6 +170,23 @@ def test_exclude_default():
     assert v.to_json({'foo': 1, 'bar': b'[default]'}, exclude_defaults=True) == b'{"foo":1}'
 
 
+def test_exclude_if():
+    v = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'foo': core_schema.typed_dict_field(core_schema.int_schema(), serialization_exclude_if='falsy'),
+                'bar': core_schema.typed_dict_field(
+                    core_schema.list_schema(), serialization_exclude_if=lambda v: len(v) > 1
+                ),
+            }
+        )
+    )
+    assert v.to_python({'foo': 1, 'bar': [1]}) == {'foo': 1, 'bar': [1]}
+    assert v.to_python({'foo': 0, 'bar': [1, 2]}) == {}
+    assert v.to_json({'foo': 0, 'bar': [1]}) == b'{"bar":[1]}'
+    assert v.to_json({'foo': 1, 'bar': [1, 2]}) == b'{"foo":1}'
+
+
 def test_function_plain_field_serializer_to_python():
     class Model(TypedDict):
         x: int
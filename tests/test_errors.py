@@ -318,6 +318,11 @@ all_errors = [
     ('bytes_too_short', 'Data should have at least 1 byte', {'min_length': 1}),
     ('bytes_too_long', 'Data should have at most 42 bytes', {'max_length': 42}),
     ('bytes_too_long', 'Data should have at most 1 byte', {'max_length': 1}),
+    (
+        'bytes_invalid_encoding',
+        'Data should be valid hex: Odd number of digits',
+        {'encoding': 'hex', 'encoding_error': 'Odd number of digits'},
+    ),
     ('value_error', 'Value error, foobar', {'error': ValueError('foobar')}),
     ('assertion_error', 'Assertion failed, foobar', {'error': AssertionError('foobar')}),
     ('literal_error', 'Input should be foo', {'expected': 'foo'}),
@@ -328,6 +333,8 @@ all_errors = [
     ('date_from_datetime_inexact', 'Datetimes provided to dates should have zero time - e.g. be exact dates', None),
     ('date_past', 'Date should be in the past', None),
     ('date_future', 'Date should be in the future', None),
+    ('date_past_bound', 'Date should be before 2000-01-01', {'bound': '2000-01-01'}),
+    ('date_future_bound', 'Date should be after 2000-01-01', {'bound': '2000-01-01'}),
     ('time_type', 'Input should be a valid time', None),
     ('time_parsing', 'Input should be in a valid time format, foobar', {'error': 'foobar'}),
     ('datetime_type', 'Input should be a valid datetime', None),
@@ -335,9 +342,13 @@ all_errors = [
     ('datetime_object_invalid', 'Invalid datetime object, got foobar', {'error': 'foobar'}),
     ('datetime_past', 'Input should be in the past', None),
     ('datetime_future', 'Input should be in the future', None),
+    ('datetime_past_bound', 'Input should be before 2000-01-01T00:00:00Z', {'bound': '2000-01-01T00:00:00Z'}),
+    ('datetime_future_bound', 'Input should be after 2000-01-01T00:00:00Z', {'bound': '2000-01-01T00:00:00Z'}),
     ('timezone_naive', 'Input should not have timezone info', None),
     ('timezone_aware', 'Input should have timezone info', None),
     ('timezone_offset', 'Timezone offset of 0 required, got 60', {'tz_expected': 0, 'tz_actual': 60}),
+    ('timezone_type', 'Input should be a string or ZoneInfo object', None),
+    ('timezone_name', 'Input should be a valid IANA timezone name', None),
     ('time_delta_type', 'Input should be a valid timedelta', None),
     ('time_delta_parsing', 'Input should be a valid timedelta, foobar', {'error': 'foobar'}),
     ('frozen_set_type', 'Input should be a valid frozenset', None),
@@ -382,6 +393,18 @@ all_errors = [
         'Decimal input should have no more than 1 digit before the decimal point',
         {'whole_digits': 1},
     ),
+    (
+        'complex_type',
+        'Input should be a valid python complex object, a number, or a valid complex string '
+        'following the rules at https://docs.python.org/3/library/functions.html#complex',
+        None,
+    ),
+    (
+        'complex_str_parsing',
+        'Input should be a valid complex string following the rules at '
+        'https://docs.python.org/3/library/functions.html#complex',
+        None,
+    ),
 ]
 
 
@@ -1089,3 +1112,30 @@ def test_validation_error_pickle() -> None:
     original = exc_info.value
     roundtripped = pickle.loads(pickle.dumps(original))
     assert original.errors() == roundtripped.errors()
+
+
+def test_field_loc_json_and_pickle() -> None:
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'field_a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='fieldA')}
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'fieldA': 'x'})
+
+    original = exc_info.value
+    assert original.json(include_url=False, include_field_loc=True) == IsJson(
+        [
+            {
+                'type': 'int_parsing',
+                'loc': ['fieldA'],
+                'field_loc': ['field_a'],
+                'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                'input': 'x',
+            }
+        ]
+    )
+    assert 'field_loc' not in original.json()
+
+    roundtripped = pickle.loads(pickle.dumps(original))
+    assert roundtripped.errors(include_field_loc=True) == original.errors(include_field_loc=True)
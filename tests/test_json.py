@@ -1,6 +1,8 @@
+import io
 import json
 import platform
 import re
+import struct
 from typing import List
 
 import pytest
@@ -14,6 +16,7 @@ from pydantic_core import (
     ValidationError,
     core_schema,
     to_json,
+    to_json_stream,
     to_jsonable_python,
 )
 
@@ -221,6 +224,102 @@ def test_to_json():
         to_json([1, 2], 2)
 
 
+def test_to_json_sort_keys():
+    value = {'b': 1, 'a': {'d': [{'z': 1, 'y': 2}], 'c': None}, 'é': 0, 'A': 2}
+    assert to_json(value) == b'{"b":1,"a":{"d":[{"z":1,"y":2}],"c":null},"\xc3\xa9":0,"A":2}'
+    assert to_json(value, sort_keys=True) == b'{"A":2,"a":{"c":null,"d":[{"y":2,"z":1}]},"b":1,"\xc3\xa9":0}'
+    expected = json.dumps(value, sort_keys=True, indent=2, ensure_ascii=False).encode()
+    assert to_json(value, sort_keys=True, indent=2) == expected
+    assert to_json({2: 'a', 10: 'b'}, sort_keys=True) == b'{"10":"b","2":"a"}'
+    assert to_json({'b': {}, 'a': []}, sort_keys=True, indent=2) == b'{\n  "a": [],\n  "b": {}\n}'
+
+
+def test_to_json_sort_keys_sets():
+    assert to_json({10, 3, -1, 2.5}, sort_keys=True) == b'[-1,2.5,3,10]'
+    assert to_json(frozenset({'b', 'c', 'a'}), sort_keys=True) == b'["a","b","c"]'
+    assert to_json({None, 'x', 1, (2, 1)}, sort_keys=True) == b'["x",1,[2,1],null]'
+    assert to_json({'s': {'b', 'a'}}, sort_keys=True, indent=2) == b'{\n  "s": [\n    "a",\n    "b"\n  ]\n}'
+
+
+def test_to_json_canonical():
+    # the example from RFC 8785 section 3.2.2
+    value = {
+        'numbers': [333333333.33333329, 1e30, 4.50, 2e-3, 0.000000000000000000000000001],
+        'string': '€$\u000f\u000aA\'B"\\\\"/',
+        'literals': [None, True, False],
+    }
+    assert to_json(value, canonical=True) == (
+        b'{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],'
+        b'"string":"\xe2\x82\xac$\\u000f\\nA\'B\\"\\\\\\\\\\"/"}'
+    )
+
+
+def test_to_json_canonical_key_order():
+    # the example from RFC 8785 section 3.2.3, keys are sorted by UTF-16 code units not code points
+    value = {'€': 0, '\r': 1, 'דּ': 2, '1': 3, '\U0001f600': 4, '\u0080': 5, 'ö': 6}
+    output = to_json(value, canonical=True).decode()
+    assert list(json.loads(output)) == ['\r', '1', '\u0080', 'ö', '€', '\U0001f600', 'דּ']
+    value = {'b': {3, 1}, 'a': {'d': 1, 'c': [2.0, -0.0]}}
+    assert to_json(value, canonical=True) == b'{"a":{"c":[2,0],"d":1},"b":[1,3]}'
+
+
+@pytest.mark.parametrize(
+    'ieee_hex,expected',
+    [
+        # test vectors from RFC 8785 appendix B
+        ('0000000000000000', b'0'),
+        ('8000000000000000', b'0'),
+        ('0000000000000001', b'5e-324'),
+        ('8000000000000001', b'-5e-324'),
+        ('7fefffffffffffff', b'1.7976931348623157e+308'),
+        ('ffefffffffffffff', b'-1.7976931348623157e+308'),
+        ('4340000000000000', b'9007199254740992'),
+        ('c340000000000000', b'-9007199254740992'),
+        ('4430000000000000', b'295147905179352830000'),
+        ('44b52d02c7e14af5', b'9.999999999999997e+22'),
+        ('44b52d02c7e14af6', b'1e+23'),
+        ('44b52d02c7e14af7', b'1.0000000000000001e+23'),
+        ('444b1ae4d6e2ef4e', b'999999999999999700000'),
+        ('444b1ae4d6e2ef4f', b'999999999999999900000'),
+        ('444b1ae4d6e2ef50', b'1e+21'),
+        ('3eb0c6f7a0b5ed8c', b'9.999999999999997e-7'),
+        ('3eb0c6f7a0b5ed8d', b'0.000001'),
+        ('41b3de4355555553', b'333333333.3333332'),
+        ('41b3de4355555554', b'333333333.33333325'),
+        ('41b3de4355555555', b'333333333.3333333'),
+        ('41b3de4355555556', b'333333333.3333334'),
+        ('41b3de4355555557', b'333333333.33333343'),
+        ('becbf647612f3696', b'-0.0000033333333333333333'),
+        ('43143ff3c1cb0959', b'1424953923781206.2'),
+    ],
+)
+def test_to_json_canonical_numbers(ieee_hex: str, expected: bytes):
+    value = struct.unpack('>d', bytes.fromhex(ieee_hex))[0]
+    assert to_json(value, canonical=True) == expected
+
+
+def test_to_json_canonical_ints():
+    assert to_json([0, -1, 2**53, -(2**60), 10**21, 2**70], canonical=True) == (
+        b'[0,-1,9007199254740992,-1152921504606847000,1e+21,1.1805916207174113e+21]'
+    )
+    with pytest.raises(PydanticSerializationError, match="Integer 9007199254740993 can't be represented exactly"):
+        to_json(2**53 + 1, canonical=True)
+    with pytest.raises(PydanticSerializationError, match=r"Integer 10{30} can't be represented exactly"):
+        to_json(10**30, canonical=True)
+
+
+def test_to_json_canonical_errors():
+    with pytest.raises(PydanticSerializationError, match="`NaN` can't be represented in canonical JSON"):
+        to_json(float('nan'), canonical=True)
+    with pytest.raises(PydanticSerializationError, match="`-Infinity` can't be represented in canonical JSON"):
+        to_json([float('-inf')], canonical=True)
+    with pytest.raises(ValueError, match="`indent` can't be used with `canonical=True`"):
+        to_json({'a': 1}, canonical=True, indent=2)
+
+    s = SchemaSerializer(core_schema.float_schema(), core_schema.CoreConfig(ser_json_inf_nan='null'))
+    assert s.to_json(float('inf'), canonical=True) == b'null'
+
+
 def test_to_json_fallback():
     with pytest.raises(PydanticSerializationError, match=r'Unable to serialize unknown type: <.+\.Foobar'):
         to_json(Foobar())
@@ -230,6 +329,27 @@ def test_to_json_fallback():
     assert to_json(Foobar(), fallback=fallback_func) == b'"fallback:Foobar"'
 
 
+def test_to_json_stream():
+    f = io.BytesIO()
+    assert to_json_stream([1, 2, {'x': b'y'}], f) is None
+    assert f.getvalue() == b'[1,2,{"x":"y"}]'
+
+    f = io.BytesIO()
+    to_json_stream({'a': [1, 2]}, f, indent=2, buffer_size=4)
+    assert f.getvalue() == to_json({'a': [1, 2]}, indent=2)
+
+    with pytest.raises(PydanticSerializationError, match=r'Unable to serialize unknown type: <.+\.Foobar'):
+        to_json_stream(Foobar(), io.BytesIO())
+
+    f = io.BytesIO()
+    to_json_stream({'b': {'y', 'x'}, 'a': 1}, f, sort_keys=True)
+    assert f.getvalue() == b'{"a":1,"b":["x","y"]}'
+
+    f = io.BytesIO()
+    to_json_stream({'b': 1e21, 'a': {'\ufb33': 1, '\U0001f600': 2}}, f, canonical=True, buffer_size=4)
+    assert f.getvalue() == b'{"a":{"\xf0\x9f\x98\x80":2,"\xef\xac\xb3":1},"b":1e+21}'
+
+
 def test_to_jsonable_python():
     assert to_jsonable_python([1, 2]) == [1, 2]
     assert to_jsonable_python({1, 2}) == IsList(1, 2, check_order=False)
@@ -285,6 +405,23 @@ def test_to_jsonable_python_schema_serializer():
     assert to_json(instance, by_alias=False) == b'{"my_foo":1,"my_inners":[{"my_foo":2,"my_inners":[]}]}'
 
 
+def test_to_jsonable_python_context():
+    class Redacted:
+        def __init__(self, secret: str):
+            self.secret = secret
+
+    def redact(value, info):
+        return '***' if info.context == 'redact' else value.secret
+
+    Redacted.__pydantic_serializer__ = SchemaSerializer(
+        core_schema.any_schema(serialization=core_schema.plain_serializer_function_ser_schema(redact, info_arg=True))
+    )
+
+    assert to_jsonable_python([Redacted('a')]) == ['a']
+    assert to_jsonable_python([Redacted('a')], context='redact') == ['***']
+    assert to_json({'x': Redacted('a')}, context='redact') == b'{"x":"***"}'
+
+
 def test_cycle_same():
     def fallback_func_passthrough(obj):
         return obj
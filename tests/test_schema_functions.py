@@ -1,6 +1,7 @@
 import dataclasses
 import re
 from datetime import date
+from enum import Enum
 from typing import Any
 
 import pytest
@@ -20,6 +21,11 @@ class MyModel:
     __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
 
 
+class MyEnum(Enum):
+    a = 1
+    b = 2
+
+
 @dataclasses.dataclass
 class MyDataclass:
     x: int
@@ -54,8 +60,36 @@ all_schema_functions = [
     (core_schema.str_schema, args(min_length=5, max_length=10), {'type': 'str', 'min_length': 5, 'max_length': 10}),
     (core_schema.bytes_schema, args(), {'type': 'bytes'}),
     (core_schema.bytes_schema, args(min_length=5, ref='xx'), {'type': 'bytes', 'min_length': 5, 'ref': 'xx'}),
+    (core_schema.bytes_schema, args(val_json_bytes='base64'), {'type': 'bytes', 'val_json_bytes': 'base64'}),
     (core_schema.date_schema, args(), {'type': 'date'}),
     (core_schema.date_schema, args(gt=date(2020, 1, 1)), {'type': 'date', 'gt': date(2020, 1, 1)}),
+    (core_schema.date_schema, args(formats=['%d/%m/%Y']), {'type': 'date', 'formats': ['%d/%m/%Y']}),
+    (core_schema.date_schema, args(timestamp_unit='ms'), {'type': 'date', 'timestamp_unit': 'ms'}),
+    (
+        core_schema.time_schema,
+        args(tz_constraint='aware', to_tz=0),
+        {'type': 'time', 'microseconds_precision': 'truncate', 'tz_constraint': 'aware', 'to_tz': 0},
+    ),
+    (
+        core_schema.datetime_schema,
+        args(to_tz='utc', naive_tz=3600),
+        {'type': 'datetime', 'microseconds_precision': 'truncate', 'to_tz': 'utc', 'naive_tz': 3600},
+    ),
+    (
+        core_schema.time_schema,
+        args(timestamp_unit='us'),
+        {'type': 'time', 'microseconds_precision': 'truncate', 'timestamp_unit': 'us'},
+    ),
+    (
+        core_schema.datetime_schema,
+        args(timestamp_unit='ms', ser_json_timestamp_unit='s'),
+        {
+            'type': 'datetime',
+            'microseconds_precision': 'truncate',
+            'timestamp_unit': 'ms',
+            'ser_json_timestamp_unit': 's',
+        },
+    ),
     (core_schema.time_schema, args(), {'type': 'time', 'microseconds_precision': 'truncate'}),
     (core_schema.datetime_schema, args(), {'type': 'datetime', 'microseconds_precision': 'truncate'}),
     (core_schema.timedelta_schema, args(), {'type': 'timedelta', 'microseconds_precision': 'truncate'}),
@@ -74,21 +108,58 @@ all_schema_functions = [
         args(microseconds_precision='error'),
         {'type': 'timedelta', 'microseconds_precision': 'error'},
     ),
+    (
+        core_schema.timedelta_schema,
+        args(duration_unit='ms', allow_human_durations=True, allow_calendar_units=False),
+        {
+            'type': 'timedelta',
+            'microseconds_precision': 'truncate',
+            'duration_unit': 'ms',
+            'allow_human_durations': True,
+            'allow_calendar_units': False,
+        },
+    ),
     (core_schema.literal_schema, args(['a', 'b']), {'type': 'literal', 'expected': ['a', 'b']}),
+    (
+        core_schema.enum_schema,
+        args(MyEnum, list(MyEnum.__members__.values())),
+        {'type': 'enum', 'cls': MyEnum, 'members': [MyEnum.a, MyEnum.b]},
+    ),
     (core_schema.is_instance_schema, args(int), {'type': 'is-instance', 'cls': int}),
     (core_schema.callable_schema, args(), {'type': 'callable'}),
     (core_schema.list_schema, args(), {'type': 'list'}),
     (core_schema.list_schema, args({'type': 'int'}), {'type': 'list', 'items_schema': {'type': 'int'}}),
+    (core_schema.tuple_schema, args([{'type': 'int'}]), {'type': 'tuple', 'items_schema': [{'type': 'int'}]}),
+    (
+        core_schema.tuple_schema,
+        args([{'type': 'int'}, {'type': 'str'}], variadic_item_index=1, max_length=5),
+        {
+            'type': 'tuple',
+            'items_schema': [{'type': 'int'}, {'type': 'str'}],
+            'variadic_item_index': 1,
+            'max_length': 5,
+        },
+    ),
     (
         core_schema.tuple_positional_schema,
         args([{'type': 'int'}]),
-        {'type': 'tuple-positional', 'items_schema': [{'type': 'int'}]},
+        {'type': 'tuple', 'items_schema': [{'type': 'int'}]},
+    ),
+    (core_schema.tuple_positional_schema, args([]), {'type': 'tuple', 'items_schema': []}),
+    (
+        core_schema.tuple_positional_schema,
+        args([{'type': 'int'}], extras_schema={'type': 'str'}),
+        {'type': 'tuple', 'items_schema': [{'type': 'int'}, {'type': 'str'}], 'variadic_item_index': 1},
     ),
-    (core_schema.tuple_positional_schema, args([]), {'type': 'tuple-positional', 'items_schema': []}),
     (
         core_schema.tuple_variable_schema,
         args({'type': 'int'}),
-        {'type': 'tuple-variable', 'items_schema': {'type': 'int'}},
+        {'type': 'tuple', 'items_schema': [{'type': 'int'}], 'variadic_item_index': 0},
+    ),
+    (
+        core_schema.tuple_variable_schema,
+        args(),
+        {'type': 'tuple', 'items_schema': [{'type': 'any'}], 'variadic_item_index': 0},
     ),
     (
         core_schema.set_schema,
@@ -289,6 +360,10 @@ all_schema_functions = [
     (core_schema.uuid_schema, args(), {'type': 'uuid'}),
     (core_schema.decimal_schema, args(), {'type': 'decimal'}),
     (core_schema.decimal_schema, args(multiple_of=5, gt=1.2), {'type': 'decimal', 'multiple_of': 5, 'gt': 1.2}),
+    (core_schema.complex_schema, args(), {'type': 'complex'}),
+    (core_schema.complex_schema, args(strict=True), {'type': 'complex', 'strict': True}),
+    (core_schema.timezone_schema, args(), {'type': 'timezone'}),
+    (core_schema.timezone_schema, args(strict=True), {'type': 'timezone', 'strict': True}),
 ]
 
 
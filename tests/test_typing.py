@@ -77,9 +77,14 @@ def test_schema_typing() -> None:
     SchemaValidator(schema)
     schema: CoreSchema = {'type': 'set', 'items_schema': {'type': 'str'}, 'max_length': 3}
     SchemaValidator(schema)
-    schema: CoreSchema = {'type': 'tuple-variable', 'items_schema': {'type': 'str'}, 'max_length': 3}
+    schema: CoreSchema = {
+        'type': 'tuple',
+        'items_schema': [{'type': 'str'}],
+        'variadic_item_index': 0,
+        'max_length': 3,
+    }
     SchemaValidator(schema)
-    schema: CoreSchema = {'type': 'tuple-positional', 'items_schema': [{'type': 'str'}, {'type': 'int'}]}
+    schema: CoreSchema = {'type': 'tuple', 'items_schema': [{'type': 'str'}, {'type': 'int'}]}
     SchemaValidator(schema)
     schema: CoreSchema = {'type': 'frozenset', 'items_schema': {'type': 'str'}, 'max_length': 3}
     SchemaValidator(schema)
@@ -236,8 +241,8 @@ def test_ser_function_plain():
         )
     )
     assert s.to_python(123) == (
-        "SerializationInfo(include=None, exclude=None, mode='python', by_alias=True, exclude_unset=False, "
-        'exclude_defaults=False, exclude_none=False, round_trip=False)'
+        "SerializationInfo(include=None, exclude=None, context=None, mode='python', by_alias=True, "
+        'exclude_unset=False, exclude_defaults=False, exclude_none=False, round_trip=False)'
     )
 
 
@@ -257,8 +262,8 @@ def test_ser_function_wrap():
     # insert_assert(s.to_python(123, mode='json'))
     assert s.to_python(123, mode='json') == (
         'SerializationCallable(serializer=str) '
-        "SerializationInfo(include=None, exclude=None, mode='json', by_alias=True, exclude_unset=False, "
-        'exclude_defaults=False, exclude_none=False, round_trip=False)'
+        "SerializationInfo(include=None, exclude=None, context=None, mode='json', by_alias=True, "
+        'exclude_unset=False, exclude_defaults=False, exclude_none=False, round_trip=False)'
     )
 
 
@@ -0,0 +1,139 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('[1, 2, 3]', [1, 2, 3]),
+        ('[1, 2, 3', [1, 2, 3]),
+        ('[1, 2, ', [1, 2]),
+        ('[1, 2,', [1, 2]),
+        ('[1, 2', [1, 2]),
+        ('[1, "2', [1, 2]),
+        ('[1, "x', [1]),
+        ('[1, tr', [1]),
+        ('[1, 2.', [1]),
+        ('[', []),
+        ('  [  ', []),
+    ],
+)
+def test_list(input_value, expected):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_json(input_value, allow_partial=True) == expected
+
+
+def test_list_errors():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "x", 3')
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'json_invalid'
+
+    # only errors in the last item are ignored
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "x", "y', allow_partial=True)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    # errors in the last item are ignored even if the input is complete
+    assert v.validate_json('[1, 2, "x"]', allow_partial=True) == [1, 2]
+
+
+def test_nested_list():
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+    assert v.validate_json('[[1, 2], [3, "', allow_partial=True) == [[1, 2], [3]]
+    assert v.validate_json('[[1, 2], [3, 4', allow_partial=True) == [[1, 2], [3, 4]]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[[1, "x"], [3, 4', allow_partial=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0, 1)]
+
+
+def test_strings():
+    v = SchemaValidator(core_schema.list_schema(core_schema.str_schema()))
+    assert v.validate_json('["foo", "ba', allow_partial=True) == ['foo', 'ba']
+    assert v.validate_json('["foo", "bar\\', allow_partial=True) == ['foo', 'bar']
+    assert v.validate_json('["foo", "bar\\u00', allow_partial=True) == ['foo', 'bar']
+    assert v.validate_json('["foo", "\\u00e9', allow_partial=True) == ['foo', 'é']
+    assert v.validate_json(b'["foo", "caf\xc3', allow_partial=True) == ['foo', 'caf']
+    assert SchemaValidator(core_schema.str_schema()).validate_json('"foo', allow_partial=True) == 'foo'
+
+
+def test_dict():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    assert v.validate_json('{"a": 1, "b": 2', allow_partial=True) == {'a': 1, 'b': 2}
+    assert v.validate_json('{"a": 1, "b": ', allow_partial=True) == {'a': 1}
+    assert v.validate_json('{"a": 1, "b"', allow_partial=True) == {'a': 1}
+    assert v.validate_json('{"a": 1, "b', allow_partial=True) == {'a': 1}
+    assert v.validate_json('{"a": 1, "b": "x', allow_partial=True) == {'a': 1}
+    assert v.validate_json('{', allow_partial=True) == {}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": "x", "b": "y', allow_partial=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',)]
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'c': core_schema.typed_dict_field(core_schema.int_schema(), required=False),
+            }
+        )
+    )
+    assert v.validate_json('{"a": 1, "b": [1, 2], "c": 3}', allow_partial=True) == {'a': 1, 'b': [1, 2], 'c': 3}
+    assert v.validate_json('{"a": 1, "b": [1, 2], "c": "', allow_partial=True) == {'a': 1, 'b': [1, 2]}
+    assert v.validate_json('{"a": 1, "b": [1, 2], "c', allow_partial=True) == {'a': 1, 'b': [1, 2]}
+    assert v.validate_json('{"a": 1, "b": [1, "', allow_partial=True) == {'a': 1, 'b': [1]}
+
+    # missing required fields are still reported
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1, "b', allow_partial=True)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 1}}
+    ]
+
+
+def test_model_fields():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(
+                    core_schema.with_default_schema(core_schema.str_schema(min_length=3), default='default')
+                ),
+            }
+        )
+    )
+    # an invalid last field is treated as missing
+    model_dict, _, fields_set = v.validate_json('{"a": 1, "b": "xy', allow_partial=True)
+    assert model_dict == {'a': 1, 'b': 'default'}
+    assert fields_set == {'a'}
+
+    model_dict, _, fields_set = v.validate_json('{"a": 1, "b": "xyz', allow_partial=True)
+    assert model_dict == {'a': 1, 'b': 'xyz'}
+    assert fields_set == {'a', 'b'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": "x", "b": "xy', allow_partial=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',)]
+
+
+def test_invalid_json():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+
+    for input_value in ['', '[1, 2]]', '[1 2', '[1, 2] x']:
+        with pytest.raises(ValidationError, match='type=json_invalid'):
+            v.validate_json(input_value, allow_partial=True)
@@ -1026,3 +1026,25 @@ def test_error_display(pydantic_version):
         '[{"type":"missing_argument","loc":["b"],"msg":"Missing required argument",'
         '"input":"ArgsKwargs((), {\'a\': 1})"}]'
     )
+
+
+def test_arguments_fail_fast():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema()),
+                core_schema.arguments_parameter('b', core_schema.int_schema()),
+            ]
+        ),
+        {'fail_fast': True},
+    )
+
+    assert v.validate_python(ArgsKwargs((1, 2))) == ((1, 2), {})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x', 'y', 3), {'c': 4}))
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0,)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs(('x', 'y', 3), {'c': 4}), fail_fast=False)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0,), (1,), (2,), ('c',)]
@@ -3,7 +3,7 @@ from typing import Any, Dict
 
 import pytest
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -121,3 +121,55 @@ def test_length_ctx():
             'ctx': {'max_length': 3},
         }
     ]
+
+
+@pytest.mark.parametrize(
+    'mode,input_value,expected',
+    [
+        ('utf8', 'foo', b'foo'),
+        ('base64', 'Zm9vYmFy', b'foobar'),
+        ('base64', 'Zm9vYg==', b'foob'),
+        ('base64', 'Zm9vYg', b'foob'),
+        ('base64', '+/8=', b'\xfb\xff'),
+        ('base64', '-_8=', b'\xfb\xff'),
+        ('base64url', '-_8=', b'\xfb\xff'),
+        ('hex', '', b''),
+        ('hex', '00ff10', b'\x00\xff\x10'),
+        ('hex', 'DEADbeef', b'\xde\xad\xbe\xef'),
+        ('base64', 'Zm9v!', Err('Data should be valid base64: Invalid byte 33, offset 4.')),
+        ('base64url', '+/8=', Err('Data should be valid base64url: Invalid byte 43, offset 0.')),
+        ('hex', 'abc', Err('Data should be valid hex: Odd number of digits')),
+        ('hex', 'zz', Err("Data should be valid hex: Invalid character 'z' at position 0")),
+    ],
+)
+def test_val_json_bytes(mode, input_value, expected):
+    v = SchemaValidator(core_schema.bytes_schema(), {'val_json_bytes': mode})
+    json_input = f'"{input_value}"'
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_json(json_input)
+        assert exc_info.value.errors(include_url=False)[0]['type'] == 'bytes_invalid_encoding'
+    else:
+        assert v.validate_json(json_input) == expected
+        # python input isn't affected
+        assert v.validate_python(input_value) == input_value.encode()
+
+
+def test_val_json_bytes_schema_overrides_config():
+    v = SchemaValidator(core_schema.bytes_schema(val_json_bytes='hex', max_length=2), {'val_json_bytes': 'base64'})
+    assert v.validate_json('"abcd"') == b'\xab\xcd'
+    with pytest.raises(ValidationError, match='Data should have at most 2 bytes'):
+        v.validate_json('"abcdef"')
+
+
+@pytest.mark.parametrize('mode', ['base64', 'hex'])
+def test_val_json_bytes_round_trip(mode):
+    schema = core_schema.bytes_schema()
+    config = {'ser_json_bytes': mode, 'val_json_bytes': mode}
+    data = bytes(range(256))
+    assert SchemaValidator(schema, config).validate_json(SchemaSerializer(schema, config).to_json(data)) == data
+
+
+def test_val_json_bytes_invalid_mode():
+    with pytest.raises(SchemaError, match='Invalid bytes validation mode: `foobar`'):
+        SchemaValidator(core_schema.bytes_schema(), {'val_json_bytes': 'foobar'})
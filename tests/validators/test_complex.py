@@ -0,0 +1,163 @@
+import math
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err
+
+EXPECTED_PARSE_ERROR_MESSAGE = (
+    'Input should be a valid complex string following the rules at '
+    'https://docs.python.org/3/library/functions.html#complex'
+)
+EXPECTED_TYPE_ERROR_MESSAGE = (
+    'Input should be a valid python complex object, a number, or a valid complex string following the rules at '
+    'https://docs.python.org/3/library/functions.html#complex'
+)
+
+
+class ComplexSubclass(complex):
+    pass
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (complex(2, 4), complex(2, 4)),
+        ('2', complex(2, 0)),
+        ('2j', complex(0, 2)),
+        ('+1.23e-4-5.67e+8J', complex(1.23e-4, -5.67e8)),
+        ('1.5-j', complex(1.5, -1)),
+        ('-j', complex(0, -1)),
+        ('j', complex(0, 1)),
+        ('(1+2j)', complex(1, 2)),
+        (' 1+2j ', complex(1, 2)),
+        (b'3+4j', complex(3, 4)),
+        (3, complex(3, 0)),
+        (2.5, complex(2.5, 0)),
+        ('infj', complex(0, float('inf'))),
+        ('1 + 2j', Err(EXPECTED_PARSE_ERROR_MESSAGE)),
+        ('foobar', Err(EXPECTED_PARSE_ERROR_MESSAGE)),
+        ('', Err(EXPECTED_PARSE_ERROR_MESSAGE)),
+        (True, Err(EXPECTED_TYPE_ERROR_MESSAGE)),
+        ([1, 2], Err(EXPECTED_TYPE_ERROR_MESSAGE)),
+        ({'real': 1, 'imag': 2}, Err(EXPECTED_TYPE_ERROR_MESSAGE)),
+        (None, Err(EXPECTED_TYPE_ERROR_MESSAGE)),
+    ],
+    ids=repr,
+)
+def test_complex_cases(input_value, expected):
+    v = SchemaValidator(core_schema.complex_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is complex
+
+
+def test_complex_subclass():
+    v = SchemaValidator(core_schema.complex_schema())
+    output = v.validate_python(ComplexSubclass(1, 2))
+    assert output == complex(1, 2)
+    assert type(output) is complex
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (complex(2, 4), complex(2, 4)),
+        (ComplexSubclass(2, 4), complex(2, 4)),
+        ('2+4j', Err(EXPECTED_TYPE_ERROR_MESSAGE)),
+        (2, Err(EXPECTED_TYPE_ERROR_MESSAGE)),
+        (2.5, Err(EXPECTED_TYPE_ERROR_MESSAGE)),
+    ],
+    ids=repr,
+)
+def test_complex_strict(input_value, expected):
+    v = SchemaValidator(core_schema.complex_schema(strict=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('"1+2j"', complex(1, 2)),
+        ('"-3.5j"', complex(0, -3.5)),
+        ('[1, 2]', complex(1, 2)),
+        ('[1.5, -2.5]', complex(1.5, -2.5)),
+        ('{"real": 1, "imag": 2}', complex(1, 2)),
+        ('{"imag": 2.5, "real": 0}', complex(0, 2.5)),
+        ('3', complex(3, 0)),
+        ('1.5', complex(1.5, 0)),
+        ('"foobar"', Err(EXPECTED_PARSE_ERROR_MESSAGE)),
+        ('[1, 2, 3]', Err('complex_type')),
+        ('[1, "2"]', Err('complex_type')),
+        ('{"real": 1}', Err('complex_type')),
+        ('{"real": 1, "imag": 2, "other": 3}', Err('complex_type')),
+        ('true', Err('complex_type')),
+        ('null', Err('complex_type')),
+    ],
+)
+def test_complex_json(input_value, expected):
+    v = SchemaValidator(core_schema.complex_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_json(input_value)
+    else:
+        assert v.validate_json(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('"1+2j"', complex(1, 2)),
+        ('[1, 2]', complex(1, 2)),
+        ('{"real": 1, "imag": 2}', complex(1, 2)),
+        ('3', Err('complex_type')),
+        ('1.5', Err('complex_type')),
+    ],
+)
+def test_complex_json_strict(input_value, expected):
+    v = SchemaValidator(core_schema.complex_schema(strict=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_json(input_value)
+    else:
+        assert v.validate_json(input_value) == expected
+
+
+def test_json_error_message():
+    v = SchemaValidator(core_schema.complex_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('true')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'complex_type',
+            'loc': (),
+            'msg': (
+                'Input should be a valid complex string, an array of [real, imag] '
+                'or an object with "real" and "imag" keys'
+            ),
+            'input': True,
+        }
+    ]
+
+
+def test_validate_strings():
+    v = SchemaValidator(core_schema.complex_schema())
+    assert v.validate_strings('1+2j') == complex(1, 2)
+
+
+def test_nan_inf():
+    v = SchemaValidator(core_schema.complex_schema())
+    c = v.validate_python('nan+infj')
+    assert math.isnan(c.real)
+    assert math.isinf(c.imag)
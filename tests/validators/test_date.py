@@ -290,6 +290,120 @@ def test_date_past_future_today():
     assert v.isinstance_python(today + timedelta(days=1)) is True
 
 
+def test_date_now_delta():
+    v = SchemaValidator(
+        core_schema.date_schema(now_op='future', now_utc_offset=0, now_delta=timedelta(days=-30), now_context_key='now')
+    )
+    context = {'now': date(2023, 6, 15)}
+    assert v.validate_python('2023-05-17', context=context) == date(2023, 5, 17)
+    assert v.validate_python('2023-07-01', context=context) == date(2023, 7, 1)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('2023-05-16', context=context)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'date_future_bound',
+            'loc': (),
+            'msg': 'Date should be after 2023-05-16',
+            'input': '2023-05-16',
+            'ctx': {'bound': '2023-05-16'},
+        }
+    ]
+
+    v = SchemaValidator(
+        core_schema.date_schema(now_op='past', now_utc_offset=0, now_delta=timedelta(weeks=1), now_context_key='now')
+    )
+    assert v.validate_python(date(2023, 6, 21), context=context) == date(2023, 6, 21)
+    with pytest.raises(ValidationError, match=r'Date should be before 2023-06-22 \[type=date_past_bound,'):
+        v.validate_python(date(2023, 6, 22), context=context)
+
+
+def test_date_now_from_context():
+    v = SchemaValidator(core_schema.date_schema(now_op='past', now_utc_offset=0, now_context_key='now'))
+    assert v.validate_python('2000-01-01', context={'now': date(2000, 1, 2)}) == date(2000, 1, 1)
+    with pytest.raises(ValidationError, match=r'Date should be in the past \[type=date_past,'):
+        v.validate_python('2000-01-01', context={'now': date(2000, 1, 1)})
+    # aware datetimes are moved to `now_utc_offset` before taking the date
+    now = datetime(2000, 1, 1, 23, tzinfo=timezone(timedelta(hours=-2)))
+    assert v.validate_python('2000-01-01', context={'now': now}) == date(2000, 1, 1)
+    # contexts without `now` use the current date
+    assert v.validate_python('2000-01-01', context={'other': 1}) == date(2000, 1, 1)
+
+    # the context is only read with `now_context_key`
+    v = SchemaValidator(core_schema.date_schema(now_op='past', now_utc_offset=0))
+    assert v.validate_python('2000-01-01', context={'now': date(2000, 1, 1)}) == date(2000, 1, 1)
+
+
+@pytest.mark.parametrize('now', ['tomorrow', 12, None])
+def test_date_now_from_context_invalid(now):
+    v = SchemaValidator(core_schema.date_schema(now_op='past', now_context_key='frozen_today'))
+    msg = 'Invalid `frozen_today` in the validation context, expected a date or datetime, got '
+    with pytest.raises(TypeError, match=re.escape(msg + type(now).__name__)):
+        v.validate_python('2000-01-01', context={'frozen_today': now})
+
+
 def test_offset_too_large():
     with pytest.raises(SchemaError, match=r'Input should be less than 86400 \[type=less_than,'):
         validate_core_schema(core_schema.date_schema(now_op='past', now_utc_offset=24 * 3600))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2022-06-08', date(2022, 6, 8)),
+        ('08/06/2022', date(2022, 6, 8)),
+        ('8/6/2022', date(2022, 6, 8)),
+        ('20220608', date(2022, 6, 8)),
+        ('8 Jun 2022', date(2022, 6, 8)),
+        ('8 JUNE 2022', date(2022, 6, 8)),
+        (b'08/06/2022', date(2022, 6, 8)),
+        (
+            '31/06/2022',
+            Err(
+                "Input should be a valid date or datetime, invalid character in year, "
+                "and does not match any of the formats '%d/%m/%Y', '%Y%m%d', '%d %b %Y' "
+                "[type=date_from_datetime_parsing, input_value='31/06/2022', input_type=str]"
+            ),
+        ),
+        ('2022-06-08T12:00', Err('type=date_from_datetime_inexact')),
+    ],
+)
+def test_date_formats(input_value, expected):
+    v = SchemaValidator(core_schema.date_schema(formats=['%d/%m/%Y', '%Y%m%d', '%d %b %Y']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_date_formats_json_strict():
+    v = SchemaValidator(core_schema.date_schema(formats=['%d.%m.%Y'], strict=True))
+    assert v.validate_json('"08.06.2022"') == date(2022, 6, 8)
+    # formats only apply to strings which failed ISO 8601 parsing, not to inputs of the wrong type
+    with pytest.raises(ValidationError, match='Input should be a valid date \\[type=date_type'):
+        v.validate_python('08.06.2022')
+
+
+def test_date_formats_invalid():
+    with pytest.raises(SchemaError, match='Invalid format "%d/%Q": unsupported directive `%Q`'):
+        SchemaValidator(core_schema.date_schema(formats=['%d/%Q']))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (1654646400000, date(2022, 6, 8)),
+        ('1654646400000', date(2022, 6, 8)),
+        (1654646400000.0, date(2022, 6, 8)),
+        (1654646400001, Err('type=date_from_datetime_inexact')),
+        ('1654646400001', Err('type=date_from_datetime_inexact')),
+        ('2022-06-08', date(2022, 6, 8)),
+    ],
+)
+def test_date_timestamp_unit(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.date_schema(timestamp_unit='ms'))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
@@ -13,6 +13,11 @@ from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_sc
 
 from ..conftest import Err, PyAndJson
 
+try:
+    from zoneinfo import ZoneInfo
+except ImportError:
+    ZoneInfo = None
+
 
 @pytest.mark.parametrize(
     'input_value,expected',
@@ -386,6 +391,64 @@ def test_mock_utc_offset_8_hours(mocker):
     assert not v.isinstance_python(future)
 
 
+def test_datetime_now_delta():
+    v = SchemaValidator(
+        core_schema.datetime_schema(
+            now_op='future', now_utc_offset=0, now_delta=timedelta(hours=1), now_context_key='now'
+        )
+    )
+    context = {'now': datetime(2023, 6, 15, 12, tzinfo=timezone.utc)}
+    assert v.validate_python('2023-06-15T13:00:01Z', context=context) == datetime(
+        2023, 6, 15, 13, 0, 1, tzinfo=timezone.utc
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('2023-06-15T12:30:00Z', context=context)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'datetime_future_bound',
+            'loc': (),
+            'msg': 'Input should be after 2023-06-15T13:00:00Z',
+            'input': '2023-06-15T12:30:00Z',
+            'ctx': {'bound': '2023-06-15T13:00:00Z'},
+        }
+    ]
+
+    v = SchemaValidator(
+        core_schema.datetime_schema(
+            now_op='past', now_utc_offset=0, now_delta=timedelta(days=-30), now_context_key='now'
+        )
+    )
+    assert v.isinstance_python(datetime(2023, 5, 16, 11, tzinfo=timezone.utc), context=context)
+    msg = r'Input should be before 2023-05-16T12:00:00Z \[type=datetime_past_bound,'
+    with pytest.raises(ValidationError, match=msg):
+        v.validate_python('2023-05-16T12:00:00Z', context=context)
+
+
+def test_datetime_now_from_context():
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past', now_utc_offset=3600, now_context_key='now'))
+    now = datetime(2000, 1, 1, 12, tzinfo=timezone.utc)
+    assert v.validate_python('2000-01-01T11:59:59Z', context={'now': now}) == datetime(
+        2000, 1, 1, 11, 59, 59, tzinfo=timezone.utc
+    )
+    with pytest.raises(ValidationError, match=r'Input should be in the past \[type=datetime_past,'):
+        v.validate_python('2000-01-01T12:00:00Z', context={'now': now})
+    # naive `now` values are in `now_utc_offset`
+    assert v.isinstance_python('2000-01-01T10:59:59Z', context={'now': datetime(2000, 1, 1, 12)})
+    assert not v.isinstance_python('2000-01-01T11:00:00Z', context={'now': datetime(2000, 1, 1, 12)})
+
+    # the context is only read with `now_context_key`
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past'))
+    assert v.isinstance_python('2000-01-01T12:00:00Z', context={'now': now})
+
+
+@pytest.mark.parametrize('now', ['tomorrow', 12, date(2000, 1, 1)])
+def test_datetime_now_from_context_invalid(now):
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past', now_context_key='frozen_now'))
+    msg = 'Invalid `frozen_now` in the validation context, expected a datetime, got '
+    with pytest.raises(TypeError, match=re.escape(msg + type(now).__name__)):
+        v.validate_python('2000-01-01T12:00:00Z', context={'frozen_now': now})
+
+
 def test_offset_too_large():
     with pytest.raises(SchemaError, match=r'Input should be greater than -86400 \[type=greater_than,'):
         validate_core_schema(core_schema.datetime_schema(now_op='past', now_utc_offset=-24 * 3600))
@@ -503,3 +566,219 @@ def test_tz_cmp() -> None:
 
     assert validated1 > validated2
     assert validated2 < validated1
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2022-06-08T12:13:14', datetime(2022, 6, 8, 12, 13, 14)),
+        ('08/06/2022 12:13', datetime(2022, 6, 8, 12, 13)),
+        ('08/06/2022   12:13', datetime(2022, 6, 8, 12, 13)),
+        ('Wed, 08 Jun 2022 12:13:14 Z', datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)),
+        (
+            'Wednesday, 08 Jun 2022 12:13:14 -05:00',
+            datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(hours=-5))),
+        ),
+        ('2024/060', datetime(2024, 2, 29)),
+        ('2023/060', datetime(2023, 3, 1)),
+        ('29/02/2023 12:00', Err('does not match any of the formats')),
+        ('08/06/2022 12:13 extra', Err('does not match any of the formats')),
+        ('2024/367', Err('does not match any of the formats')),
+        (
+            'foobar',
+            Err(
+                "Input should be a valid datetime, input is too short, and does not match any of the formats "
+                "'%d/%m/%Y %H:%M', '%a, %d %b %Y %H:%M:%S %z', '%Y/%j' [type=datetime_parsing,"
+            ),
+        ),
+    ],
+)
+def test_datetime_formats(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(formats=['%d/%m/%Y %H:%M', '%a, %d %b %Y %H:%M:%S %z', '%Y/%j']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        assert exc_info.value.errors(include_url=False)[0]['type'] == 'datetime_parsing'
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_datetime_formats_constraints():
+    v = SchemaValidator(core_schema.datetime_schema(formats=['%d/%m/%Y'], gt=datetime(2022, 1, 1)))
+    assert v.validate_python('02/01/2022') == datetime(2022, 1, 2)
+    with pytest.raises(ValidationError, match='Input should be greater than 2022-01-01T00:00:00'):
+        v.validate_python('01/01/2022')
+
+
+@pytest.mark.parametrize(
+    'unit,input_value,expected',
+    [
+        ('ms', 1654646400123, datetime(2022, 6, 8, 0, 0, 0, 123000, tzinfo=timezone.utc)),
+        ('ms', '1654646400123', datetime(2022, 6, 8, 0, 0, 0, 123000, tzinfo=timezone.utc)),
+        ('ms', 1654646400123.5, datetime(2022, 6, 8, 0, 0, 0, 123500, tzinfo=timezone.utc)),
+        ('ms', 1000, datetime(1970, 1, 1, 0, 0, 1, tzinfo=timezone.utc)),
+        ('ms', -1000, datetime(1969, 12, 31, 23, 59, 59, tzinfo=timezone.utc)),
+        ('us', 1654646400123456, datetime(2022, 6, 8, 0, 0, 0, 123456, tzinfo=timezone.utc)),
+        ('ns', 1654646400123456789, datetime(2022, 6, 8, 0, 0, 0, 123456, tzinfo=timezone.utc)),
+        ('ns', '1654646400123456789', datetime(2022, 6, 8, 0, 0, 0, 123456, tzinfo=timezone.utc)),
+        ('s', 1654646400, datetime(2022, 6, 8, tzinfo=timezone.utc)),
+        ('s', 32503680000, datetime(3000, 1, 1, tzinfo=timezone.utc)),
+        ('s', '2022-06-08T12:13:14', datetime(2022, 6, 8, 12, 13, 14)),
+        ('auto', 1654646400123, datetime(2022, 6, 8, 0, 0, 0, 123000, tzinfo=timezone.utc)),
+    ],
+)
+def test_datetime_timestamp_unit(py_and_json: PyAndJson, unit, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(timestamp_unit=unit))
+    assert v.validate_test(input_value) == expected
+
+
+def test_datetime_timestamp_unit_config():
+    v = SchemaValidator(core_schema.datetime_schema(), {'timestamp_unit': 'ms'})
+    assert v.validate_python(1000) == datetime(1970, 1, 1, 0, 0, 1, tzinfo=timezone.utc)
+
+    v = SchemaValidator(core_schema.datetime_schema(timestamp_unit='s'), {'timestamp_unit': 'ms'})
+    assert v.validate_python(1000) == datetime(1970, 1, 1, 0, 16, 40, tzinfo=timezone.utc)
+
+
+def test_datetime_timestamp_unit_invalid():
+    msg = 'Invalid timestamp unit: `minutes`, expected `auto`, `s`, `ms`, `us` or `ns`'
+    with pytest.raises(SchemaError, match=msg):
+        SchemaValidator({'type': 'datetime', 'timestamp_unit': 'minutes'})
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        (dict(to_tz='utc'), '2022-06-08T12:13:14+02:00', datetime(2022, 6, 8, 10, 13, 14, tzinfo=timezone.utc)),
+        (dict(to_tz='utc'), '2022-06-08T12:13:14', datetime(2022, 6, 8, 12, 13, 14)),
+        (dict(to_tz=3600), '2022-06-08T00:30:00Z', datetime(2022, 6, 8, 1, 30, tzinfo=timezone(timedelta(hours=1)))),
+        (dict(to_tz=-7200), '2022-06-08T01:00:00Z', datetime(2022, 6, 7, 23, tzinfo=timezone(timedelta(hours=-2)))),
+        (dict(naive_tz='utc'), '2022-06-08T12:13:14', datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone.utc)),
+        (
+            dict(naive_tz=3600),
+            '2022-06-08T12:13:14+02:00',
+            datetime(2022, 6, 8, 12, 13, 14, tzinfo=timezone(timedelta(hours=2))),
+        ),
+        (
+            dict(naive_tz=3600, to_tz='utc'),
+            '2022-06-08T12:13:14',
+            datetime(2022, 6, 8, 11, 13, 14, tzinfo=timezone.utc),
+        ),
+    ],
+)
+def test_datetime_tz_conversion(py_and_json: PyAndJson, kwargs, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema(**kwargs))
+    output = v.validate_test(input_value)
+    assert output == expected
+    assert output.utcoffset() == expected.utcoffset()
+
+
+def test_datetime_to_tz_python():
+    v = SchemaValidator(core_schema.datetime_schema(to_tz='utc'))
+    output = v.validate_python(datetime(2022, 6, 8, 12, 13, 14, 123, tzinfo=timezone(timedelta(hours=2))))
+    assert output == datetime(2022, 6, 8, 10, 13, 14, 123, tzinfo=timezone.utc)
+    assert repr(output.tzinfo) == 'TzInfo(UTC)'
+
+
+@pytest.mark.skipif(ZoneInfo is None, reason='zoneinfo is only available in Python >= 3.9')
+def test_datetime_naive_tz_keeps_aware_zoneinfo():
+    v = SchemaValidator(core_schema.datetime_schema(naive_tz='utc'))
+    value = datetime(2022, 6, 8, 12, 13, 14, tzinfo=ZoneInfo('Europe/Paris'))
+    output = v.validate_python(value)
+    assert output is value
+    assert output.tzinfo is value.tzinfo
+
+
+def test_datetime_naive_tz_constraints():
+    # conversion is applied before constraints, so naive inputs pass `tz_constraint='aware'`
+    v = SchemaValidator(
+        core_schema.datetime_schema(naive_tz='utc', tz_constraint='aware', gt=datetime(2022, 1, 1, tzinfo=timezone.utc))
+    )
+    assert v.validate_python('2022-06-08T12:00') == datetime(2022, 6, 8, 12, tzinfo=timezone.utc)
+    with pytest.raises(ValidationError, match='Input should be greater than 2022-01-01T00:00:00Z'):
+        v.validate_python('2021-06-08T12:00')
+
+
+@pytest.mark.parametrize(
+    'to_tz,message',
+    [
+        ('local', 'Invalid to_tz \'local\', expected "utc" or an offset in seconds'),
+        (86_400, 'Invalid to_tz 86400, the offset must be less than a day'),
+    ],
+)
+def test_datetime_to_tz_invalid(to_tz, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator({'type': 'datetime', 'to_tz': to_tz})
+
+
+@pytest.mark.skipif(ZoneInfo is None, reason='zoneinfo is only available in Python >= 3.9')
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2024-03-01T10:00[Europe/Paris]', (2024, 3, 1, 10)),
+        ('2024-07-01T10:00[!Europe/Paris]', (2024, 7, 1, 10)),
+        # with an offset, the instant is kept and converted into the zone
+        ('2024-03-01T09:00:00Z[Europe/Paris]', (2024, 3, 1, 10)),
+        (
+            '2024-03-01T10:00[Mars/Olympus_Mons]',
+            Err('Input should be a valid datetime, unknown timezone `Mars/Olympus_Mons` [type=datetime_parsing,'),
+        ),
+        ('2024-03-01T10:00[]', Err('[type=datetime_parsing,')),
+        # key-value tags after the timezone are ignored
+        ('2024-03-01T10:00[Europe/Paris][u-ca=gregory]', (2024, 3, 1, 10)),
+        ('2024-03-01T10:00[!Europe/Paris][u-ca=gregory][foo=bar]', (2024, 3, 1, 10)),
+        # unless they're critical
+        ('2024-03-01T10:00[Europe/Paris][!u-ca=gregory]', Err('[type=datetime_parsing,')),
+    ],
+)
+def test_datetime_zone_annotation(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        expected = datetime(*expected, tzinfo=ZoneInfo('Europe/Paris'))
+        assert output == expected
+        assert output.tzinfo is expected.tzinfo
+        assert output.utcoffset() == expected.utcoffset()
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('2024-03-01T10:00Z[u-ca=gregory]', datetime(2024, 3, 1, 10, tzinfo=timezone.utc)),
+        (
+            '2024-03-01T10:00+01:00[u-ca=gregory][foo=bar]',
+            datetime(2024, 3, 1, 10, tzinfo=timezone(timedelta(hours=1))),
+        ),
+        ('2024-03-01T10:00Z[!u-ca=gregory]', Err('[type=datetime_parsing,')),
+    ],
+)
+def test_datetime_tag_annotation(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.datetime_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert output.utcoffset() == expected.utcoffset()
+
+
+@pytest.mark.skipif(ZoneInfo is not None, reason='zoneinfo is available')
+def test_zoneinfo_unavailable():
+    v = SchemaValidator(core_schema.datetime_schema())
+    with pytest.raises(ValidationError, match='timezone names require the `zoneinfo` module'):
+        v.validate_python('2024-03-01T10:00[Europe/Paris]')
+    with pytest.raises(SchemaError, match='The `timezone` schema requires the `zoneinfo` module'):
+        SchemaValidator(core_schema.timezone_schema())
+
+
+@pytest.mark.skipif(ZoneInfo is None, reason='zoneinfo is only available in Python >= 3.9')
+def test_datetime_zone_annotation_constraints():
+    v = SchemaValidator(core_schema.datetime_schema(lt=datetime(2024, 7, 1, 8, 30, tzinfo=timezone.utc)))
+    # 10:00 in Paris during summer time is 08:00 UTC
+    assert v.validate_python('2024-07-01T10:00[Europe/Paris]') == datetime(2024, 7, 1, 8, tzinfo=timezone.utc)
+    with pytest.raises(ValidationError, match='Input should be less than 2024-07-01T08:30:00Z'):
+        v.validate_python('2024-07-01T11:00[Europe/Paris]')
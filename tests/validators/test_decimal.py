@@ -467,3 +467,78 @@ def test_validate_max_digits_and_decimal_places_edge_case() -> None:
     assert v.validate_python(Decimal('9999999999999999.999999999999999999')) == Decimal(
         '9999999999999999.999999999999999999'
     )
+
+
+@pytest.mark.parametrize(
+    'schema_kwargs,input_value,valid',
+    [
+        # `Decimal.normalize()` rounds to the context precision of 28 digits before the digits are counted
+        ({'max_digits': 28}, '1.00000000000000000000000000001', True),
+        ({'decimal_places': 28}, '1.00000000000000000000000000001', True),
+        ({'decimal_places': 28}, '0.99999999999999999999999999999', True),
+        ({'max_digits': 28}, '1.000000000000000000000000000150', True),
+        ({'max_digits': 28}, '12345678901234567890123456789.5', False),
+        ({'max_digits': 28, 'decimal_places': 27}, '12345678901234567890123456789.5', False),
+    ],
+)
+def test_validate_digits_rounded_to_context_precision(schema_kwargs, input_value, valid):
+    v = SchemaValidator({'type': 'decimal', **schema_kwargs})
+    if valid:
+        assert v.validate_python(Decimal(input_value)) == Decimal(input_value)
+    else:
+        with pytest.raises(ValidationError, match=r'\[type=decimal_(max_digits|whole_digits)'):
+            v.validate_python(Decimal(input_value))
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        '1.500',
+        '-0.00',
+        '0E+3',
+        '1E+30',
+        '000123.4500',
+        ' 42 ',
+        '.5',
+        '5.',
+        'NaN',
+        '-sNaN',
+        'NaN012',
+        '-Infinity',
+        '1_000',
+    ],
+)
+def test_decimal_parsing_preserves_representation(input_value):
+    v = SchemaValidator({'type': 'decimal', 'allow_inf_nan': True})
+    output = v.validate_python(input_value)
+    assert isinstance(output, Decimal)
+    assert output.as_tuple() == Decimal(input_value).as_tuple()
+
+
+def test_decimal_constraints_large_exponents():
+    v = SchemaValidator({'type': 'decimal', 'multiple_of': Decimal('0.3'), 'lt': Decimal('1e999999')})
+    assert v.validate_json('"3e999998"') == Decimal('3e999998')
+    with pytest.raises(ValidationError, match=r'Input should be a multiple of 0\.3 \[type=multiple_of'):
+        v.validate_json('"1e999998"')
+    with pytest.raises(ValidationError, match=r'Input should be less than 1E\+999999 \[type=less_than'):
+        v.validate_json('"3e999999"')
+
+
+@pytest.mark.parametrize(
+    'multiple_of,input_value,valid',
+    [
+        (Decimal('0.1'), '0.3', True),
+        (Decimal('0.1'), '0.35', False),
+        (Decimal('2.5'), '-7.5', True),
+        (Decimal('1e-30'), '1.000000000000000000000000000001', True),
+        (0.1, '0.3', False),
+        (0.5, '1.5', True),
+    ],
+)
+def test_decimal_multiple_of_exact(multiple_of, input_value, valid):
+    v = SchemaValidator({'type': 'decimal', 'multiple_of': multiple_of})
+    if valid:
+        assert v.validate_json(f'"{input_value}"') == Decimal(input_value)
+    else:
+        with pytest.raises(ValidationError, match='type=multiple_of'):
+            v.validate_json(f'"{input_value}"')
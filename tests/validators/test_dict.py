@@ -235,3 +235,29 @@ def test_json_dict():
     assert exc_info.value.errors(include_url=False) == [
         {'type': 'dict_type', 'loc': (), 'msg': 'Input should be an object', 'input': 1}
     ]
+
+
+def test_dict_fail_fast():
+    v = SchemaValidator(
+        {'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'int'}, 'fail_fast': True}
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'b', '1': 'c', 'd': 2})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a', '[key]'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'a',
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'1': 'c', 'd': 2})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('1',)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'b', '1': 'c', 'd': 2}, fail_fast=False)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a', '[key]'), ('a',), ('1',), ('d', '[key]')]
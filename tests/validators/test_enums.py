@@ -0,0 +1,244 @@
+import re
+from enum import Enum
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+
+def test_plain_enum():
+    class MyEnum(Enum):
+        a = 1
+        b = 2
+
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values())))
+
+    # debug(v)
+    assert v.validate_python(MyEnum.a) is MyEnum.a
+    assert v.validate_python(MyEnum.b) is MyEnum.b
+    assert v.validate_python(1) is MyEnum.a
+    assert v.validate_python(2) is MyEnum.b
+    assert v.validate_json('1') is MyEnum.a
+
+    with pytest.raises(ValidationError, match=r'Input should be 1 or 2 \[type=enum, input_value=3, input_type=int\]'):
+        v.validate_python(3)
+
+    with pytest.raises(ValidationError, match=r"Input should be 1 or 2 \[type=enum, input_value='1', input_type=str\]"):
+        v.validate_python('1')
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('3')
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'enum', 'loc': (), 'msg': 'Input should be 1 or 2', 'input': 3, 'ctx': {'expected': '1 or 2'}}
+    ]
+
+
+def test_plain_enum_strict():
+    class MyEnum(Enum):
+        a = 1
+        b = 2
+
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), strict=True))
+
+    assert v.validate_python(MyEnum.a) is MyEnum.a
+    assert v.validate_json('1') is MyEnum.a
+
+    with pytest.raises(ValidationError, match=r'Input should be an instance of .*MyEnum \[type=is_instance_of'):
+        v.validate_python(1)
+
+    with pytest.raises(ValidationError, match=r'Input should be 1 or 2 \[type=enum'):
+        v.validate_json('3')
+
+
+def test_enum_subclass_instance():
+    # only a member-less enum can be subclassed, its subclass members are instances of it
+    class BaseEnum(Enum):
+        def describe(self):
+            return self.name
+
+    class MyEnum(BaseEnum):
+        a = 1
+
+    v = SchemaValidator(core_schema.enum_schema(BaseEnum, list(MyEnum.__members__.values())))
+    assert v.validate_python(MyEnum.a) is MyEnum.a
+    assert v.validate_python(1) is MyEnum.a
+
+    v_strict = SchemaValidator(core_schema.enum_schema(BaseEnum, list(MyEnum.__members__.values()), strict=True))
+    assert v_strict.validate_python(MyEnum.a) is MyEnum.a
+    with pytest.raises(ValidationError, match=r'Input should be an instance of .*BaseEnum \[type=is_instance_of'):
+        v_strict.validate_python(1)
+
+
+def test_int_enum():
+    class MyEnum(int, Enum):
+        a = 1
+        b = 2
+
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='int'))
+
+    # debug(v)
+    assert v.validate_python(MyEnum.a) is MyEnum.a
+    assert v.validate_python(1) is MyEnum.a
+    assert v.validate_python(1.0) is MyEnum.a
+    assert v.validate_python('1') is MyEnum.a
+    assert v.validate_json('1') is MyEnum.a
+    assert v.validate_json('"1"') is MyEnum.a
+
+    with pytest.raises(ValidationError, match=r'Input should be 1 or 2 \[type=enum, input_value=3, input_type=int\]'):
+        v.validate_python(3)
+
+    with pytest.raises(ValidationError, match=r'Input should be 1 or 2 \[type=enum, input_value=1.5, input_type=float'):
+        v.validate_python(1.5)
+
+    v_strict = SchemaValidator(
+        core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='int', strict=True)
+    )
+    assert v_strict.validate_json('1') is MyEnum.a
+
+    with pytest.raises(ValidationError, match=r'Input should be 1 or 2 \[type=enum'):
+        v_strict.validate_json('"1"')
+
+
+def test_str_enum():
+    class MyEnum(str, Enum):
+        a = 'x'
+        b = 'y'
+
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='str'))
+
+    # debug(v)
+    assert v.validate_python('x') is MyEnum.a
+    assert v.validate_python(MyEnum.a) is MyEnum.a
+    assert v.validate_python(b'x') is MyEnum.a
+    assert v.validate_json('"x"') is MyEnum.a
+
+    with pytest.raises(ValidationError, match=r"Input should be 'x' or 'y' \[type=enum, input_value='a'"):
+        v.validate_python('a')
+
+
+def test_float_enum():
+    class MyEnum(float, Enum):
+        a = 1.5
+        b = 2.5
+        c = 3.0
+
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='float'))
+
+    # debug(v)
+    assert v.validate_python(MyEnum.a) is MyEnum.a
+    assert v.validate_python(1.5) is MyEnum.a
+    assert v.validate_python('1.5') is MyEnum.a
+    assert v.validate_python(3) is MyEnum.c
+    assert v.validate_json('2.5') is MyEnum.b
+    assert v.validate_json('3') is MyEnum.c
+
+    with pytest.raises(ValidationError, match=r'Input should be 1.5, 2.5 or 3.0 \[type=enum'):
+        v.validate_python(4.0)
+
+
+def test_enum_missing():
+    class MyEnum(Enum):
+        a = 1
+        b = 2
+
+        @classmethod
+        def _missing_(cls, v):
+            if v == 'a':
+                return cls.a
+            return None
+
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), missing=MyEnum._missing_))
+
+    assert v.validate_python(1) is MyEnum.a
+    assert v.validate_python('a') is MyEnum.a
+    assert v.validate_json('"a"') is MyEnum.a
+
+    with pytest.raises(ValidationError, match=r"Input should be 1 or 2 \[type=enum, input_value='b', input_type=str\]"):
+        v.validate_python('b')
+
+
+def test_enum_missing_wrong_type():
+    class MyEnum(Enum):
+        a = 1
+        b = 2
+
+        @classmethod
+        def _missing_(cls, v):
+            return 'foobar'
+
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), missing=MyEnum._missing_))
+
+    msg = "MyEnum._missing_: returned 'foobar' instead of None or a valid member"
+    with pytest.raises(TypeError, match=re.escape(msg)):
+        v.validate_python(3)
+
+
+def test_enum_missing_raises():
+    class MyEnum(Enum):
+        a = 1
+
+        @classmethod
+        def _missing_(cls, v):
+            raise ValueError('boom')
+
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), missing=MyEnum._missing_))
+
+    with pytest.raises(ValueError, match='^boom$') as exc_info:
+        v.validate_python(2)
+    assert type(exc_info.value) is ValueError
+
+
+def test_enum_in_model_field():
+    class MyEnum(str, Enum):
+        a = 'a'
+        b = 'b'
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='str')
+                )
+            }
+        )
+    )
+    assert v.validate_json('{"x": "b"}') == {'x': MyEnum.b}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': 'c'})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'enum',
+            'loc': ('x',),
+            'msg': "Input should be 'a' or 'b'",
+            'input': 'c',
+            'ctx': {'expected': "'a' or 'b'"},
+        }
+    ]
+
+
+def test_enum_in_union():
+    class MyEnum(Enum):
+        a = 1
+
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.int_schema(), core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()))]
+        )
+    )
+    # the exact enum member wins, but a plain int is validated by the int schema
+    assert v.validate_python(MyEnum.a) is MyEnum.a
+    assert v.validate_python(1) == 1
+
+
+def test_invalid_schema():
+    class MyEnum(Enum):
+        a = 1
+
+    with pytest.raises(SchemaError, match='`members` should have length > 0'):
+        SchemaValidator(core_schema.enum_schema(MyEnum, []))
+
+    with pytest.raises(SchemaError, match="`sub_type` must be one of: 'int', 'str', 'float' or None"):
+        SchemaValidator(core_schema.enum_schema(MyEnum, [MyEnum.a], sub_type='bool'))
@@ -0,0 +1,192 @@
+import json
+import pickle
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b/c': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'd': core_schema.typed_dict_field(
+                    core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]), required=False
+                ),
+            }
+        )
+    )
+
+
+def test_positions(validator):
+    data = '{\n  "a": "x",\n  "b/c": [1, 2, "z"]\n}'
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json(data, error_positions=True)
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'offset': 9,
+            'line': 2,
+            'column': 8,
+            'json_pointer': '/a',
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('b/c', 2),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'z',
+            'offset': 30,
+            'line': 3,
+            'column': 17,
+            'json_pointer': '/b~1c/2',
+        },
+    ]
+    assert [data[e['offset'] : e['offset'] + 3] for e in exc_info.value.errors()] == ['"x"', '"z"']
+
+
+def test_positions_json(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json('{"a": 1, "b/c": [1.5]}', error_positions=True)
+    # insert_assert(json.loads(exc_info.value.json(include_url=False)))
+    assert json.loads(exc_info.value.json(include_url=False)) == [
+        {
+            'type': 'int_from_float',
+            'loc': ['b/c', 0],
+            'msg': 'Input should be a valid integer, got a number with a fractional part',
+            'input': 1.5,
+            'offset': 17,
+            'line': 1,
+            'column': 18,
+            'json_pointer': '/b~1c/0',
+        }
+    ]
+
+
+def test_no_positions_by_default(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json('{"a": "x", "b/c": []}')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert 'offset' not in json.loads(exc_info.value.json())[0]
+
+
+def test_missing_field_uses_parent(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json('  {"a": 1}', error_positions=True)
+    error = exc_info.value.errors(include_url=False)[0]
+    assert error['type'] == 'missing'
+    assert (error['offset'], error['line'], error['column'], error['json_pointer']) == (2, 1, 3, '/b~1c')
+
+
+def test_union_member_loc(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json('{"a": 1, "b/c": [], "d": [1]}', error_positions=True)
+    errors = exc_info.value.errors(include_url=False)
+    assert [(e['loc'], e['offset'], e['json_pointer']) for e in errors] == [
+        (('d', 'int'), 25, '/d'),
+        (('d', 'str'), 25, '/d'),
+    ]
+
+
+def test_union_member_name_matching_key():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.int_schema(), core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())]
+        )
+    )
+    data = '{"int": 1, "k": "v"}'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(data, error_positions=True)
+    errors = exc_info.value.errors(include_url=False)
+    # the `int` union member name isn't looked up as a key in the input
+    assert [(e['loc'], e['offset'], e['json_pointer']) for e in errors] == [
+        (('int',), 0, ''),
+        (('dict[str,int]', 'k'), 16, '/k'),
+    ]
+
+
+@pytest.mark.parametrize('loc_by_alias,loc', [(True, ('fieldA', 1)), (False, ('field_a', 1))])
+def test_alias_positions(loc_by_alias, loc):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.int_schema()), validation_alias='fieldA'
+                ),
+            }
+        )
+    )
+    data = '{"field_a": [1, "a"],\n  "fieldA": [1, "x"]}'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(data, error_positions=True, loc_by_alias=loc_by_alias)
+    error = exc_info.value.errors(include_url=False)[0]
+    assert error['loc'] == loc
+    assert (error['offset'], error['line'], error['column'], error['json_pointer']) == (38, 2, 17, '/fieldA/1')
+    assert data[error['offset'] : error['offset'] + 3] == '"x"'
+
+
+def test_duplicate_keys_last_wins(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json('{"a": 1, "a": "x", "b/c": []}', error_positions=True)
+    assert exc_info.value.errors(include_url=False)[0]['offset'] == 14
+
+
+@pytest.mark.parametrize(
+    'input_value,offset,line,column',
+    [
+        ('{"a": 1,', 8, 1, 9),
+        ('[1, 2', 5, 1, 6),
+        ('{\n  "a" 1}', 8, 2, 7),
+    ],
+)
+def test_invalid_json(validator, input_value, offset, line, column):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json(input_value, error_positions=True)
+    error = exc_info.value.errors(include_url=False)[0]
+    assert error['type'] == 'json_invalid'
+    assert (error['offset'], error['line'], error['column'], error['json_pointer']) == (offset, line, column, '')
+
+
+def test_multibyte_offsets_are_bytes():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('["£", 1, "x"]'.encode(), error_positions=True)
+    error = exc_info.value.errors(include_url=False)
+    assert [(e['loc'], e['offset'], e['column']) for e in error] == [((0,), 1, 2), ((2,), 10, 11)]
+
+
+def test_pointer_escaping():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"~a/b": "x"}', error_positions=True)
+    assert exc_info.value.errors(include_url=False)[0]['json_pointer'] == '/~0a~1b'
+
+
+def test_allow_partial(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json('{"a": "x", "b/c": [1, 2', allow_partial=True, error_positions=True)
+    error = exc_info.value.errors(include_url=False)[0]
+    assert (error['loc'], error['offset']) == (('a',), 6)
+
+
+def test_pickle_and_from_exception_data(validator):
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_json('{"a": "x", "b/c": []}', error_positions=True)
+    original = exc_info.value
+    assert pickle.loads(pickle.dumps(original)).errors() == original.errors()
+
+    rebuilt = ValidationError.from_exception_data(original.title, original.errors())
+    assert rebuilt.errors()[0]['offset'] == 6
@@ -305,13 +305,13 @@ def test_generator_error():
     [
         pytest.param(
             {1: 10, 2: 20, '3': '30'}.items(),
-            {'type': 'tuple-variable', 'items_schema': {'type': 'any'}},
+            {'type': 'tuple', 'items_schema': [{'type': 'any'}], 'variadic_item_index': 0},
             [(1, 10), (2, 20), ('3', '30')],
             id='Tuple[Any, Any]',
         ),
         pytest.param(
             {1: 10, 2: 20, '3': '30'}.items(),
-            {'type': 'tuple-variable', 'items_schema': {'type': 'int'}},
+            {'type': 'tuple', 'items_schema': [{'type': 'int'}], 'variadic_item_index': 0},
             [(1, 10), (2, 20), (3, 30)],
             id='Tuple[int, int]',
         ),
@@ -502,3 +502,77 @@ def test_list_dict_items_input(testcase: ListInputTestCase) -> None:
         output = v.validate_python(testcase.input)
         assert output == testcase.output
         assert output is not testcase.input
+
+
+@pytest.mark.parametrize(
+    'fail_fast,expected',
+    [
+        pytest.param(
+            True,
+            [
+                {
+                    'type': 'int_parsing',
+                    'loc': (1,),
+                    'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                    'input': 'not-num',
+                }
+            ],
+            id='fail_fast',
+        ),
+        pytest.param(
+            False,
+            [
+                {
+                    'type': 'int_parsing',
+                    'loc': (1,),
+                    'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                    'input': 'not-num',
+                },
+                {
+                    'type': 'int_parsing',
+                    'loc': (2,),
+                    'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                    'input': 'again',
+                },
+            ],
+            id='not_fail_fast',
+        ),
+    ],
+)
+def test_list_fail_fast(fail_fast, expected):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), fail_fast=fail_fast))
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'not-num', 'again'])
+
+    assert exc_info.value.errors(include_url=False) == expected
+
+
+def test_list_fail_fast_config_and_call():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'fail_fast': True})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "a", "b"]')
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,)]
+
+    # the per-call setting overrides config
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "a", "b"]', fail_fast=False)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,), (2,)]
+
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'a', 'b'], fail_fast=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,)]
+
+
+def test_list_fail_fast_nested():
+    v = SchemaValidator(core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())))
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[1, 'a', 'b'], ['c']], fail_fast=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0, 1)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([[1, 'a', 'b'], ['c']])
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0, 1), (0, 2), (1, 0)]
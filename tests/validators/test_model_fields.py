@@ -1781,3 +1781,128 @@ def test_extra_behavior_ignore(config: Union[core_schema.CoreConfig, None], sche
         }
     ]
     assert 'not_f' not in m
+
+
+def test_model_fields_fail_fast():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        ),
+        CoreConfig(fail_fast=True),
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': [1, 'x', 'y']})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',)]
+
+
+@pytest.fixture(scope='module')
+def aliased_fields_validator():
+    return SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'field_a': core_schema.model_field(core_schema.int_schema(), validation_alias='fieldA'),
+                'field_b': core_schema.model_field(
+                    core_schema.list_schema(
+                        core_schema.model_fields_schema(
+                            {'sub_c': core_schema.model_field(core_schema.int_schema(), validation_alias='subC')}
+                        )
+                    ),
+                    validation_alias=['outer', 'fieldB'],
+                ),
+                'field_d': core_schema.model_field(core_schema.int_schema()),
+            }
+        )
+    )
+
+
+def test_loc_by_alias_override(aliased_fields_validator):
+    v = aliased_fields_validator
+    input_value = {'fieldA': 'x', 'outer': {'fieldB': [{'subC': 'y'}, {}]}, 'field_d': 'z'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert [e['loc'] for e in exc_info.value.errors()] == [
+        ('fieldA',),
+        ('outer', 'fieldB', 0, 'subC'),
+        ('outer', 'fieldB', 1, 'subC'),
+        ('field_d',),
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value, loc_by_alias=False)
+    assert [e['loc'] for e in exc_info.value.errors()] == [
+        ('field_a',),
+        ('field_b', 0, 'sub_c'),
+        ('field_b', 1, 'sub_c'),
+        ('field_d',),
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"fieldA": 1, "outer": {"fieldB": []}}', loc_by_alias=False)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('field_d',)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_strings({'fieldA': 'x', 'field_d': '1'}, loc_by_alias=False)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('field_a',), ('field_b',)]
+
+
+def test_loc_by_alias_override_config():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'field_a': core_schema.model_field(core_schema.int_schema(), validation_alias='fieldA')}
+        ),
+        CoreConfig(loc_by_alias=False),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('field_a',)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({}, loc_by_alias=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('fieldA',)]
+
+
+def test_field_loc(aliased_fields_validator):
+    with pytest.raises(ValidationError) as exc_info:
+        aliased_fields_validator.validate_python({'fieldA': 'x', 'outer': {'fieldB': [{}]}, 'field_d': 'z'})
+    # insert_assert(exc_info.value.errors(include_url=False, include_field_loc=True))
+    assert exc_info.value.errors(include_url=False, include_field_loc=True) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('fieldA',),
+            'field_loc': ('field_a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'missing',
+            'loc': ('outer', 'fieldB', 0, 'subC'),
+            'field_loc': ('field_b', 0, 'sub_c'),
+            'msg': 'Field required',
+            'input': {},
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('field_d',),
+            'field_loc': ('field_d',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'z',
+        },
+    ]
+    assert 'field_loc' not in exc_info.value.errors()[0]
@@ -242,13 +242,13 @@ def test_generator_error():
     [
         pytest.param(
             {1: 10, 2: 20, '3': '30'}.items(),
-            {'type': 'tuple-variable', 'items_schema': {'type': 'any'}},
+            {'type': 'tuple', 'items_schema': [{'type': 'any'}], 'variadic_item_index': 0},
             {(1, 10), (2, 20), ('3', '30')},
             id='Tuple[Any, Any]',
         ),
         pytest.param(
             {1: 10, 2: 20, '3': '30'}.items(),
-            {'type': 'tuple-variable', 'items_schema': {'type': 'int'}},
+            {'type': 'tuple', 'items_schema': [{'type': 'int'}], 'variadic_item_index': 0},
             {(1, 10), (2, 20), (3, 30)},
             id='Tuple[int, int]',
         ),
@@ -277,3 +277,12 @@ def test_set_any(input_value, expected):
     output = v.validate_python(input_value)
     assert output == expected
     assert isinstance(output, set)
+
+
+@pytest.mark.parametrize('schema_type', ['set', 'frozenset'])
+def test_set_fail_fast(schema_type):
+    v = SchemaValidator({'type': schema_type, 'items_schema': {'type': 'int'}, 'fail_fast': True})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'a', 'b'])
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,)]
@@ -294,3 +294,85 @@ def test_tz_constraint_too_high():
 def test_tz_constraint_wrong():
     with pytest.raises(SchemaError, match="Input should be 'aware' or 'naive"):
         validate_core_schema(core_schema.time_schema(tz_constraint='wrong'))
+
+
+@pytest.mark.parametrize(
+    'kwargs,input_value,expected',
+    [
+        (dict(to_tz='utc'), '12:13:14+02:00', time(10, 13, 14, tzinfo=timezone.utc)),
+        (dict(to_tz='utc'), '01:00:00+02:00', time(23, tzinfo=timezone.utc)),
+        (dict(to_tz=3600), '23:30:00.5Z', time(0, 30, 0, 500_000, tzinfo=timezone(timedelta(hours=1)))),
+        (dict(to_tz='utc'), '12:13:14', time(12, 13, 14)),
+        (dict(naive_tz=-7200), '12:13:14', time(12, 13, 14, tzinfo=timezone(timedelta(hours=-2)))),
+        (dict(naive_tz=3600, to_tz='utc'), '00:30', time(23, 30, tzinfo=timezone.utc)),
+        # conversion happens before `tz_constraint` is checked
+        (dict(naive_tz='utc', tz_constraint='aware'), '12:13:14', time(12, 13, 14, tzinfo=timezone.utc)),
+        (dict(to_tz='utc', tz_constraint=0), '12:13:14+01:00', time(11, 13, 14, tzinfo=timezone.utc)),
+        (dict(to_tz='utc', tz_constraint='aware'), '12:13:14', Err('Input should have timezone info')),
+    ],
+)
+def test_time_tz_conversion(py_and_json: PyAndJson, kwargs, input_value, expected):
+    v = py_and_json(core_schema.time_schema(**kwargs))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert output.utcoffset() == expected.utcoffset()
+
+
+def test_time_to_tz_python():
+    v = SchemaValidator(core_schema.time_schema(to_tz='utc'))
+    output = v.validate_python(time(1, 2, 3, 4, tzinfo=timezone(timedelta(hours=5, minutes=30))))
+    assert output == time(19, 32, 3, 4, tzinfo=timezone.utc)
+    assert repr(output.tzinfo) == 'TzInfo(UTC)'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('12:13:14', time(12, 13, 14)),
+        ('12.13', time(12, 13)),
+        ('1:05 PM', time(13, 5)),
+        ('12:05 am', time(0, 5)),
+        ('12:05 pm', time(12, 5)),
+        ('12.13.14.5 +0130', time(12, 13, 14, 500_000, tzinfo=timezone(timedelta(hours=1, minutes=30)))),
+        (
+            '25.00',
+            Err(
+                "Input should be in a valid time format, invalid time separator, expected `:`, "
+                "and does not match any of the formats '%H.%M', '%I:%M %p', '%H.%M.%S.%f %z' [type=time_parsing,"
+            ),
+        ),
+    ],
+)
+def test_time_formats(input_value, expected):
+    v = SchemaValidator(core_schema.time_schema(formats=['%H.%M', '%I:%M %p', '%H.%M.%S.%f %z']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'unit,input_value,expected',
+    [
+        ('ms', 3_723_500, time(1, 2, 3, 500_000, tzinfo=timezone.utc)),
+        ('ms', '3723500', time(1, 2, 3, 500_000, tzinfo=timezone.utc)),
+        ('us', 3_723_000_001, time(1, 2, 3, 1, tzinfo=timezone.utc)),
+        ('s', 3723.5, time(1, 2, 3, 500_000, tzinfo=timezone.utc)),
+    ],
+)
+def test_time_timestamp_unit(py_and_json: PyAndJson, unit, input_value, expected):
+    v = py_and_json(core_schema.time_schema(timestamp_unit=unit))
+    assert v.validate_test(input_value) == expected
+
+
+def test_time_naive_tz_keeps_aware_tzinfo():
+    v = SchemaValidator(core_schema.time_schema(naive_tz='utc'))
+    value = time(1, 2, 3, tzinfo=timezone(timedelta(hours=2), 'CEST'))
+    output = v.validate_python(value)
+    assert output is value
+    assert output.tzname() == 'CEST'
@@ -136,45 +136,48 @@ def test_timedelta_strict_json(input_value, expected):
         ({}, 'P0Y0M3D2WT1H2M3S', timedelta(days=3, weeks=2, hours=1, minutes=2, seconds=3)),
         ({'le': timedelta(days=3)}, 'P2DT1H', timedelta(days=2, hours=1)),
         ({'le': timedelta(days=3)}, 'P3DT0H', timedelta(days=3)),
-        ({'le': timedelta(days=3)}, 'P3DT1H', Err('Input should be less than or equal to 3 days')),
+        ({'le': timedelta(days=3)}, 'P3DT1H', Err('Input should be less than or equal to P3D')),
         ({'lt': timedelta(days=3)}, 'P2DT1H', timedelta(days=2, hours=1)),
-        ({'lt': timedelta(days=3)}, 'P3DT1H', Err('Input should be less than 3 days')),
+        ({'lt': timedelta(days=3)}, 'P3DT1H', Err('Input should be less than P3D')),
         ({'ge': timedelta(days=3)}, 'P3DT1H', timedelta(days=3, hours=1)),
         ({'ge': timedelta(days=3)}, 'P3D', timedelta(days=3)),
-        ({'ge': timedelta(days=3)}, 'P2DT1H', Err('Input should be greater than or equal to 3 days')),
+        ({'ge': timedelta(days=3)}, 'P2DT1H', Err('Input should be greater than or equal to P3D')),
         ({'gt': timedelta(days=3)}, 'P3DT1H', timedelta(days=3, hours=1)),
         ({'le': timedelta(seconds=-86400.123)}, '-PT86400.123S', timedelta(seconds=-86400.123)),
         ({'le': timedelta(seconds=-86400.123)}, '-PT86400.124S', timedelta(seconds=-86400.124)),
         (
             {'le': timedelta(seconds=-86400.123)},
             '-PT86400.122S',
-            Err(
-                'Input should be less than or equal to -2 days and 23 hours and 59 minutes and 59 seconds and 877000 microseconds [type=less_than_equal'
-            ),
+            Err('Input should be less than or equal to -P1DT0.123S [type=less_than_equal'),
         ),
         ({'gt': timedelta(seconds=-86400.123)}, timedelta(seconds=-86400.122), timedelta(seconds=-86400.122)),
         ({'gt': timedelta(seconds=-86400.123)}, '-PT86400.122S', timedelta(seconds=-86400.122)),
         (
             {'gt': timedelta(seconds=-86400.123)},
             '-PT86400.124S',
-            Err(
-                'Input should be greater than -2 days and 23 hours and 59 minutes and 59 seconds and 877000 microseconds [type=greater_than'
-            ),
+            Err('Input should be greater than -P1DT0.123S [type=greater_than'),
         ),
         (
             {'gt': timedelta(hours=1, minutes=30)},
             'PT180S',
-            Err('Input should be greater than 1 hour and 30 minutes [type=greater_than'),
+            Err('Input should be greater than PT5400S [type=greater_than'),
         ),
-        ({'gt': timedelta()}, '-P0DT0.1S', Err('Input should be greater than 0 seconds [type=greater_than')),
-        ({'gt': timedelta()}, 'P0DT0.0S', Err('Input should be greater than 0 seconds [type=greater_than')),
+        ({'gt': timedelta()}, '-P0DT0.1S', Err('Input should be greater than PT0S [type=greater_than')),
+        ({'gt': timedelta()}, 'P0DT0.0S', Err('Input should be greater than PT0S [type=greater_than')),
         ({'ge': timedelta()}, 'P0DT0.0S', timedelta()),
         ({'lt': timedelta()}, '-PT0S', timedelta()),
         (
             {'lt': timedelta(days=740, weeks=1, hours=48, minutes=60, seconds=61, microseconds=100000)},
             'P2Y1W10DT48H60M61.100000S',
-            Err('Input should be less than 749 days and 1 hour and 1 minute and 1 second and 100000 microseconds'),
+            Err('Input should be less than P749DT3661.1S'),
+        ),
+        # bounds of more than a year are rendered in days, not 365 day years
+        (
+            {'ge': timedelta(days=400)},
+            'P1D',
+            Err('Input should be greater than or equal to P400D [type=greater_than_equal'),
         ),
+        ({'gt': timedelta(days=-366)}, '-P367D', Err('Input should be greater than -P366D [type=greater_than')),
     ],
     ids=repr,
 )
@@ -284,8 +287,137 @@ def test_pandas():
     assert v.validate_python(two_hours.to_pytimedelta()) == two_hours
 
     one_55 = pandas.Timestamp('2023-01-01T01:55:00Z') - pandas.Timestamp('2023-01-01T00:00:00Z')
-    msg = r'Input should be greater than or equal to 2 hours'
+    msg = r'Input should be greater than or equal to PT7200S'
     with pytest.raises(ValidationError, match=msg):
         v.validate_python(one_55)
     with pytest.raises(ValidationError, match=msg):
         v.validate_python(one_55.to_pytimedelta())
+
+
+@pytest.mark.parametrize(
+    'duration_unit,input_value,expected',
+    [
+        ('s', 90, timedelta(seconds=90)),
+        ('ms', 1500, timedelta(seconds=1, milliseconds=500)),
+        ('ms', 1500.5, timedelta(seconds=1, microseconds=500_500)),
+        ('ms', -1, timedelta(milliseconds=-1)),
+        ('min', 90, timedelta(hours=1, minutes=30)),
+        ('min', 1.5, timedelta(seconds=90)),
+        ('min', '01:30', timedelta(hours=1, minutes=30)),
+    ],
+)
+def test_duration_unit(py_and_json: PyAndJson, duration_unit, input_value, expected):
+    v = py_and_json({'type': 'timedelta', 'duration_unit': duration_unit})
+    assert v.validate_test(input_value) == expected
+
+
+def test_duration_unit_config():
+    v = SchemaValidator({'type': 'timedelta'}, {'duration_unit': 'min'})
+    assert v.validate_python(2) == timedelta(minutes=2)
+    v = SchemaValidator({'type': 'timedelta', 'duration_unit': 'ms'}, {'duration_unit': 'min'})
+    assert v.validate_python(2) == timedelta(milliseconds=2)
+
+
+def test_invalid_duration_unit():
+    with pytest.raises(SchemaError, match='Invalid duration unit: `h`, expected `ms`, `s` or `min`'):
+        SchemaValidator({'type': 'timedelta', 'duration_unit': 'h'})
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1h30m', timedelta(hours=1, minutes=30)),
+        ('2 days', timedelta(days=2)),
+        ('2 days, 4 hours', timedelta(days=2, hours=4)),
+        ('1.5h', timedelta(hours=1, minutes=30)),
+        ('1 week 2d', timedelta(days=9)),
+        ('-3m 20s', timedelta(minutes=-3, seconds=-20)),
+        ('250ms', timedelta(milliseconds=250)),
+        ('10 Minutes', timedelta(minutes=10)),
+        ('90', timedelta(seconds=90)),
+        ('1 mo', timedelta(days=30)),
+        ('1y', timedelta(days=365)),
+        ('P1DT2H', timedelta(days=1, hours=2)),
+        ('1 day, 01:00:00', timedelta(days=1, hours=1)),
+        ('1 fortnight', Err('type=time_delta_parsing')),
+        ('1h 30', Err('type=time_delta_parsing')),
+        ('h', Err('type=time_delta_parsing')),
+        ('', Err('type=time_delta_parsing')),
+    ],
+)
+def test_human_durations(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json({'type': 'timedelta', 'allow_human_durations': True})
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_human_durations_unit():
+    v = SchemaValidator({'type': 'timedelta', 'allow_human_durations': True, 'duration_unit': 'min'})
+    assert v.validate_python('90') == timedelta(minutes=90)
+
+
+def test_human_durations_disabled():
+    v = SchemaValidator({'type': 'timedelta'})
+    with pytest.raises(ValidationError, match='type=time_delta_parsing'):
+        v.validate_python('1h30m')
+
+
+@pytest.mark.parametrize(
+    'input_value,unit',
+    [
+        ('P1M', 'months'),
+        ('P1Y', 'years'),
+        ('-P1Y2M', 'years'),
+        ('P2M1D', 'months'),
+        ('3 months', 'months'),
+        ('1y 2d', 'years'),
+    ],
+)
+def test_forbid_calendar_units(input_value, unit):
+    v = SchemaValidator(
+        {'type': 'timedelta', 'allow_calendar_units': False, 'allow_human_durations': True},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'time_delta_parsing',
+            'loc': (),
+            'msg': f'Input should be a valid timedelta, {unit} have no fixed duration, use days or weeks instead',
+            'input': input_value,
+            'ctx': {'error': f'{unit} have no fixed duration, use days or weeks instead'},
+        }
+    ]
+
+
+def test_calendar_units_allowed_by_default():
+    v = SchemaValidator({'type': 'timedelta'})
+    assert v.validate_python('P1M') == timedelta(days=30)
+    assert v.validate_python('P1Y') == timedelta(days=365)
+
+
+def test_forbid_calendar_units_minutes():
+    v = SchemaValidator({'type': 'timedelta', 'allow_calendar_units': False})
+    assert v.validate_python('PT1M') == timedelta(minutes=1)
+    assert v.validate_python('P1W2DT3M') == timedelta(days=9, minutes=3)
+
+
+def test_constraint_error_context(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'timedelta', 'ge': timedelta(hours=2), 'lt': timedelta(days=1, minutes=1)})
+    assert v.validate_test('PT2H') == timedelta(hours=2)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test('PT1H')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'greater_than_equal',
+            'loc': (),
+            'msg': 'Input should be greater than or equal to PT7200S',
+            'input': timedelta(hours=1),
+            'ctx': {'ge': 'PT7200S'},
+        }
+    ]
+    with pytest.raises(ValidationError, match=r'Input should be less than P1DT60S \[type=less_than'):
+        v.validate_test('P2D')
@@ -0,0 +1,46 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+ZoneInfo = pytest.importorskip('zoneinfo').ZoneInfo
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('America/New_York', ZoneInfo('America/New_York')),
+        ('Europe/Paris', ZoneInfo('Europe/Paris')),
+        ('UTC', ZoneInfo('UTC')),
+        ('Mars/Olympus_Mons', Err('Input should be a valid IANA timezone name [type=timezone_name,')),
+        ('../etc/passwd', Err('Input should be a valid IANA timezone name [type=timezone_name,')),
+        ('', Err('Input should be a valid IANA timezone name [type=timezone_name,')),
+        (123, Err('Input should be a string or ZoneInfo object [type=timezone_type,')),
+    ],
+)
+def test_timezone(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.timezone_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output is expected
+
+
+def test_timezone_instance():
+    v = SchemaValidator(core_schema.timezone_schema())
+    tz = ZoneInfo('Asia/Tokyo')
+    assert v.validate_python(tz) is tz
+
+
+def test_timezone_strict():
+    v = SchemaValidator(core_schema.timezone_schema(strict=True))
+    tz = ZoneInfo('Asia/Tokyo')
+    assert v.validate_python(tz) is tz
+    assert v.validate_json('"Asia/Tokyo"') is tz
+    with pytest.raises(ValidationError, match='Input should be an instance of ZoneInfo'):
+        v.validate_python('Asia/Tokyo')
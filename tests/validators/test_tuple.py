@@ -5,19 +5,19 @@ from typing import Any, Dict, Type
 import pytest
 from dirty_equals import IsNonNegative, IsTuple
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
 
 @pytest.mark.parametrize(
-    'mode,items,input_value,expected',
+    'variadic_item_index,items,input_value,expected',
     [
-        ('variable', {'type': 'int'}, [1, 2, 3], (1, 2, 3)),
-        ('variable', {'type': 'int'}, 1, Err('[type=tuple_type, input_value=1, input_type=int]')),
-        ('positional', [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}], [1, 2, '3'], (1, 2, 3)),
+        (0, [{'type': 'int'}], [1, 2, 3], (1, 2, 3)),
+        (0, [{'type': 'int'}], 1, Err('[type=tuple_type, input_value=1, input_type=int]')),
+        (None, [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}], [1, 2, '3'], (1, 2, 3)),
         (
-            'positional',
+            None,
             [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}],
             5,
             Err('[type=tuple_type, input_value=5, input_type=int]'),
@@ -25,8 +25,8 @@ from ..conftest import Err, PyAndJson, infinite_generator
     ],
     ids=repr,
 )
-def test_tuple_json(py_and_json: PyAndJson, mode, items, input_value, expected):
-    v = py_and_json({'type': f'tuple-{mode}', 'items_schema': items})
+def test_tuple_json(py_and_json: PyAndJson, variadic_item_index, items, input_value, expected):
+    v = py_and_json(core_schema.tuple_schema(items, variadic_item_index=variadic_item_index))
     if isinstance(expected, Err):
         with pytest.raises(ValidationError, match=re.escape(expected.message)):
             v.validate_test(input_value)
@@ -35,7 +35,7 @@ def test_tuple_json(py_and_json: PyAndJson, mode, items, input_value, expected):
 
 
 def test_any_no_copy():
-    v = SchemaValidator({'type': 'tuple-variable'})
+    v = SchemaValidator({'type': 'tuple', 'items_schema': [{'type': 'any'}], 'variadic_item_index': 0})
     input_value = (1, '2', b'3')
     output = v.validate_python(input_value)
     assert output == input_value
@@ -44,20 +44,20 @@ def test_any_no_copy():
 
 
 @pytest.mark.parametrize(
-    'mode,items,input_value,expected',
+    'variadic_item_index,items,input_value,expected',
     [
-        ('variable', {'type': 'int'}, (1, 2, '33'), (1, 2, 33)),
-        ('variable', {'type': 'str'}, (b'1', b'2', '33'), ('1', '2', '33')),
-        ('positional', [{'type': 'int'}, {'type': 'str'}, {'type': 'float'}], (1, b'a', 33), (1, 'a', 33.0)),
+        (0, [{'type': 'int'}], (1, 2, '33'), (1, 2, 33)),
+        (0, [{'type': 'str'}], (b'1', b'2', '33'), ('1', '2', '33')),
+        (None, [{'type': 'int'}, {'type': 'str'}, {'type': 'float'}], (1, b'a', 33), (1, 'a', 33.0)),
     ],
 )
-def test_tuple_strict_passes_with_tuple(mode, items, input_value, expected):
-    v = SchemaValidator({'type': f'tuple-{mode}', 'items_schema': items, 'strict': True})
+def test_tuple_strict_passes_with_tuple(variadic_item_index, items, input_value, expected):
+    v = SchemaValidator(core_schema.tuple_schema(items, variadic_item_index=variadic_item_index, strict=True))
     assert v.validate_python(input_value) == expected
 
 
 def test_empty_positional_tuple():
-    v = SchemaValidator({'type': 'tuple-positional', 'items_schema': []})
+    v = SchemaValidator({'type': 'tuple', 'items_schema': []})
     assert v.validate_python(()) == ()
     assert v.validate_python([]) == ()
     with pytest.raises(ValidationError) as exc_info:
@@ -76,11 +76,11 @@ def test_empty_positional_tuple():
 
 
 @pytest.mark.parametrize(
-    'mode,items', [('variable', {'type': 'int'}), ('positional', [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}])]
+    'variadic_item_index,items', [(0, [{'type': 'int'}]), (None, [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}])]
 )
 @pytest.mark.parametrize('wrong_coll_type', [list, set, frozenset])
-def test_tuple_strict_fails_without_tuple(wrong_coll_type: Type[Any], mode, items):
-    v = SchemaValidator({'type': f'tuple-{mode}', 'items_schema': items, 'strict': True})
+def test_tuple_strict_fails_without_tuple(wrong_coll_type: Type[Any], variadic_item_index, items):
+    v = SchemaValidator(core_schema.tuple_schema(items, variadic_item_index=variadic_item_index, strict=True))
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python(wrong_coll_type([1, 2, '33']))
     assert exc_info.value.errors(include_url=False) == [
@@ -119,7 +119,7 @@ def test_tuple_strict_fails_without_tuple(wrong_coll_type: Type[Any], mode, item
     ids=repr,
 )
 def test_tuple_var_len_kwargs(kwargs: Dict[str, Any], input_value, expected):
-    v = SchemaValidator({'type': 'tuple-variable', **kwargs})
+    v = SchemaValidator({'type': 'tuple', 'items_schema': [{'type': 'any'}], 'variadic_item_index': 0, **kwargs})
     if isinstance(expected, Err):
         with pytest.raises(ValidationError, match=re.escape(expected.message)):
             v.validate_python(input_value)
@@ -128,7 +128,7 @@ def test_tuple_var_len_kwargs(kwargs: Dict[str, Any], input_value, expected):
 
 
 @pytest.mark.parametrize(
-    'mode,items', [('variable', {'type': 'int'}), ('positional', [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}])]
+    'variadic_item_index,items', [(0, [{'type': 'int'}]), (None, [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}])]
 )
 @pytest.mark.parametrize(
     'input_value,expected',
@@ -144,8 +144,8 @@ def test_tuple_var_len_kwargs(kwargs: Dict[str, Any], input_value, expected):
     ],
     ids=repr,
 )
-def test_tuple_validate(input_value, expected, mode, items):
-    v = SchemaValidator({'type': f'tuple-{mode}', 'items_schema': items})
+def test_tuple_validate(input_value, expected, variadic_item_index, items):
+    v = SchemaValidator(core_schema.tuple_schema(items, variadic_item_index=variadic_item_index))
     if isinstance(expected, Err):
         with pytest.raises(ValidationError, match=re.escape(expected.message)):
             v.validate_python(input_value)
@@ -157,10 +157,10 @@ def test_tuple_validate(input_value, expected, mode, items):
 # on the first test run. This is a workaround to make sure the generator is
 # always recreated.
 @pytest.mark.parametrize(
-    'mode,items', [('variable', {'type': 'int'}), ('positional', [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}])]
+    'variadic_item_index,items', [(0, [{'type': 'int'}]), (None, [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}])]
 )
-def test_tuple_validate_iterator(mode, items):
-    v = SchemaValidator({'type': f'tuple-{mode}', 'items_schema': items})
+def test_tuple_validate_iterator(variadic_item_index, items):
+    v = SchemaValidator(core_schema.tuple_schema(items, variadic_item_index=variadic_item_index))
     assert v.validate_python((x for x in [1, 2, '3'])) == (1, 2, 3)
 
 
@@ -175,7 +175,7 @@ def test_tuple_validate_iterator(mode, items):
     ],
 )
 def test_tuple_var_len_errors(input_value, index):
-    v = SchemaValidator({'type': 'tuple-variable', 'items_schema': {'type': 'int'}})
+    v = SchemaValidator({'type': 'tuple', 'items_schema': [{'type': 'int'}], 'variadic_item_index': 0})
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python(input_value)
     assert exc_info.value.errors(include_url=False) == [
@@ -203,7 +203,7 @@ def test_tuple_var_len_errors(input_value, index):
     ],
 )
 def test_tuple_fix_len_errors(input_value, items, index):
-    v = SchemaValidator({'type': 'tuple-positional', 'items_schema': items})
+    v = SchemaValidator({'type': 'tuple', 'items_schema': items})
     with pytest.raises(ValidationError) as exc_info:
         assert v.validate_python(input_value)
     assert exc_info.value.errors(include_url=False) == [
@@ -219,7 +219,7 @@ def test_tuple_fix_len_errors(input_value, items, index):
 def test_multiple_missing(py_and_json: PyAndJson):
     v = py_and_json(
         {
-            'type': 'tuple-positional',
+            'type': 'tuple',
             'items_schema': [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}, {'type': 'int'}],
         }
     )
@@ -239,7 +239,7 @@ def test_multiple_missing(py_and_json: PyAndJson):
 
 
 def test_extra_arguments(py_and_json: PyAndJson):
-    v = py_and_json({'type': 'tuple-positional', 'items_schema': [{'type': 'int'}, {'type': 'int'}]})
+    v = py_and_json({'type': 'tuple', 'items_schema': [{'type': 'int'}, {'type': 'int'}]})
     assert v.validate_test([1, 2]) == (1, 2)
     with pytest.raises(ValidationError) as exc_info:
         v.validate_test([1, 2, 3, 4])
@@ -256,7 +256,7 @@ def test_extra_arguments(py_and_json: PyAndJson):
 
 
 def test_positional_empty(py_and_json: PyAndJson):
-    v = py_and_json({'type': 'tuple-positional', 'items_schema': []})
+    v = py_and_json({'type': 'tuple', 'items_schema': []})
     assert v.validate_test([]) == ()
     assert v.validate_python(()) == ()
     with pytest.raises(ValidationError, match='type=too_long,'):
@@ -264,7 +264,7 @@ def test_positional_empty(py_and_json: PyAndJson):
 
 
 def test_positional_empty_extra(py_and_json: PyAndJson):
-    v = py_and_json({'type': 'tuple-positional', 'items_schema': [], 'extras_schema': {'type': 'int'}})
+    v = py_and_json({'type': 'tuple', 'items_schema': [{'type': 'int'}], 'variadic_item_index': 0})
     assert v.validate_test([]) == ()
     assert v.validate_python(()) == ()
     assert v.validate_test([1]) == (1,)
@@ -273,7 +273,15 @@ def test_positional_empty_extra(py_and_json: PyAndJson):
 
 @pytest.mark.parametrize('input_value,expected', [((1, 2, 3), (1, 2, 3)), ([1, 2, 3], [1, 2, 3])])
 def test_union_tuple_list(input_value, expected):
-    v = SchemaValidator({'type': 'union', 'choices': [{'type': 'tuple-variable'}, {'type': 'list'}]})
+    v = SchemaValidator(
+        {
+            'type': 'union',
+            'choices': [
+                {'type': 'tuple', 'items_schema': [{'type': 'any'}], 'variadic_item_index': 0},
+                {'type': 'list'},
+            ],
+        }
+    )
     assert v.validate_python(input_value) == expected
 
 
@@ -313,8 +321,8 @@ def test_union_tuple_var_len(input_value, expected):
         {
             'type': 'union',
             'choices': [
-                {'type': 'tuple-variable', 'items_schema': {'type': 'int'}, 'strict': True},
-                {'type': 'tuple-variable', 'items_schema': {'type': 'str'}, 'strict': True},
+                {'type': 'tuple', 'items_schema': [{'type': 'int'}], 'variadic_item_index': 0, 'strict': True},
+                {'type': 'tuple', 'items_schema': [{'type': 'str'}], 'variadic_item_index': 0, 'strict': True},
             ],
         }
     )
@@ -361,12 +369,12 @@ def test_union_tuple_fix_len(input_value, expected):
             'type': 'union',
             'choices': [
                 {
-                    'type': 'tuple-positional',
+                    'type': 'tuple',
                     'items_schema': [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}],
                     'strict': True,
                 },
                 {
-                    'type': 'tuple-positional',
+                    'type': 'tuple',
                     'items_schema': [{'type': 'str'}, {'type': 'str'}, {'type': 'str'}],
                     'strict': True,
                 },
@@ -383,7 +391,7 @@ def test_union_tuple_fix_len(input_value, expected):
 
 
 def test_tuple_fix_error():
-    v = SchemaValidator({'type': 'tuple-positional', 'items_schema': [{'type': 'int'}, {'type': 'str'}]})
+    v = SchemaValidator({'type': 'tuple', 'items_schema': [{'type': 'int'}, {'type': 'str'}]})
     with pytest.raises(ValidationError) as exc_info:
         v.validate_python([1])
 
@@ -406,9 +414,9 @@ def test_tuple_fix_error():
 def test_tuple_fix_extra(input_value, expected, cache):
     v = SchemaValidator(
         {
-            'type': 'tuple-positional',
-            'items_schema': [{'type': 'int'}, {'type': 'str'}],
-            'extras_schema': {'type': 'str'},
+            'type': 'tuple',
+            'items_schema': [{'type': 'int'}, {'type': 'str'}, {'type': 'str'}],
+            'variadic_item_index': 2,
         }
     )
 
@@ -421,9 +429,7 @@ def test_tuple_fix_extra(input_value, expected, cache):
 
 
 def test_tuple_fix_extra_any():
-    v = SchemaValidator(
-        {'type': 'tuple-positional', 'items_schema': [{'type': 'str'}], 'extras_schema': {'type': 'any'}}
-    )
+    v = SchemaValidator({'type': 'tuple', 'items_schema': [{'type': 'str'}, {'type': 'any'}], 'variadic_item_index': 1})
     assert v.validate_python([b'1']) == ('1',)
     assert v.validate_python([b'1', 2]) == ('1', 2)
     assert v.validate_python((b'1', 2)) == ('1', 2)
@@ -443,7 +449,7 @@ def test_generator_error():
             raise RuntimeError('error')
         yield 3
 
-    v = SchemaValidator({'type': 'tuple-variable', 'items_schema': {'type': 'int'}})
+    v = SchemaValidator({'type': 'tuple', 'items_schema': [{'type': 'int'}], 'variadic_item_index': 0})
     assert v.validate_python(gen(False)) == (1, 2, 3)
 
     msg = r'Error iterating over object, error: RuntimeError: error \[type=iteration_error,'
@@ -456,13 +462,13 @@ def test_generator_error():
     [
         pytest.param(
             {1: 10, 2: 20, '3': '30'}.items(),
-            {'type': 'tuple-variable', 'items_schema': {'type': 'any'}},
+            {'type': 'tuple', 'items_schema': [{'type': 'any'}], 'variadic_item_index': 0},
             ((1, 10), (2, 20), ('3', '30')),
             id='Tuple[Any, Any]',
         ),
         pytest.param(
             {1: 10, 2: 20, '3': '30'}.items(),
-            {'type': 'tuple-variable', 'items_schema': {'type': 'int'}},
+            {'type': 'tuple', 'items_schema': [{'type': 'int'}], 'variadic_item_index': 0},
             ((1, 10), (2, 20), (3, 30)),
             id='Tuple[int, int]',
         ),
@@ -470,7 +476,7 @@ def test_generator_error():
     ],
 )
 def test_frozenset_from_dict_items(input_value, items_schema, expected):
-    v = SchemaValidator({'type': 'tuple-variable', 'items_schema': items_schema})
+    v = SchemaValidator({'type': 'tuple', 'items_schema': [items_schema], 'variadic_item_index': 0})
     output = v.validate_python(input_value)
     assert isinstance(output, tuple)
     assert output == expected
@@ -487,8 +493,9 @@ def test_frozenset_from_dict_items(input_value, items_schema, expected):
 def test_length_constraints_omit(input_value, expected):
     v = SchemaValidator(
         {
-            'type': 'tuple-variable',
-            'items_schema': {'type': 'default', 'schema': {'type': 'int'}, 'on_error': 'omit'},
+            'type': 'tuple',
+            'items_schema': [{'type': 'default', 'schema': {'type': 'int'}, 'on_error': 'omit'}],
+            'variadic_item_index': 0,
             'max_length': 4,
         }
     )
@@ -497,3 +504,132 @@ def test_length_constraints_omit(input_value, expected):
             v.validate_python(input_value)
     else:
         assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        {'type': 'tuple', 'items_schema': [{'type': 'int'}], 'variadic_item_index': 0, 'fail_fast': True},
+        {'type': 'tuple', 'items_schema': [{'type': 'int'}, {'type': 'int'}, {'type': 'int'}]},
+    ],
+    ids=['variable', 'positional'],
+)
+def test_tuple_fail_fast(schema):
+    v = SchemaValidator(schema, {'fail_fast': True})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, 'a', 'b'))
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,)]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ((1, 2.5), (1, 2.5)),
+        ((1, 'a', 2.5), (1, 'a', 2.5)),
+        ([1, 'a', 'b', 'c', 2.5], (1, 'a', 'b', 'c', 2.5)),
+        ((x for x in [1, 'a', 'b', 2.5]), (1, 'a', 'b', 2.5)),
+        (
+            (1,),
+            Err('type=missing', errors=[{'type': 'missing', 'loc': (1,), 'msg': 'Field required', 'input': (1,)}]),
+        ),
+        (
+            (),
+            Err(
+                'type=missing',
+                errors=[
+                    {'type': 'missing', 'loc': (0,), 'msg': 'Field required', 'input': ()},
+                    {'type': 'missing', 'loc': (1,), 'msg': 'Field required', 'input': ()},
+                ],
+            ),
+        ),
+        (
+            ('x', 'a', 1, 'b', 'y'),
+            Err(
+                'type=int_parsing',
+                errors=[
+                    {
+                        'type': 'int_parsing',
+                        'loc': (0,),
+                        'msg': 'Input should be a valid integer, unable to parse string as an integer',
+                        'input': 'x',
+                    },
+                    {'type': 'string_type', 'loc': (2,), 'msg': 'Input should be a valid string', 'input': 1},
+                    {
+                        'type': 'float_parsing',
+                        'loc': (4,),
+                        'msg': 'Input should be a valid number, unable to parse string as a number',
+                        'input': 'y',
+                    },
+                ],
+            ),
+        ),
+    ],
+    ids=repr,
+)
+def test_variadic_item_in_middle(input_value, expected):
+    v = SchemaValidator(
+        core_schema.tuple_schema(
+            [core_schema.int_schema(), core_schema.str_schema(), core_schema.float_schema()], variadic_item_index=1
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_python(input_value)
+        assert exc_info.value.errors(include_url=False) == expected.errors
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_variadic_item_first(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.tuple_schema(
+            [core_schema.str_schema(), core_schema.int_schema(), core_schema.int_schema()], variadic_item_index=0
+        )
+    )
+    assert v.validate_test([1, 2]) == (1, 2)
+    assert v.validate_test(['a', 'b', 1, 2]) == ('a', 'b', 1, 2)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(['a', 1, 'b'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', (2,))]
+
+
+def test_variadic_length_constraints():
+    v = SchemaValidator(
+        core_schema.tuple_schema(
+            [core_schema.int_schema(), core_schema.str_schema(), core_schema.int_schema()],
+            variadic_item_index=1,
+            min_length=3,
+            max_length=4,
+        )
+    )
+    assert v.validate_python((1, 'a', 2)) == (1, 'a', 2)
+    assert v.validate_python((1, 'a', 'b', 2)) == (1, 'a', 'b', 2)
+    with pytest.raises(ValidationError, match='Tuple should have at least 3 items after validation, not 2'):
+        v.validate_python((1, 2))
+    with pytest.raises(ValidationError, match='Tuple should have at most 4 items after validation, not 5'):
+        v.validate_python((1, 'a', 'b', 'c', 2))
+
+
+def test_variadic_name():
+    v = SchemaValidator(
+        {
+            'type': 'union',
+            'choices': [
+                core_schema.tuple_schema(
+                    [core_schema.int_schema(), core_schema.str_schema(), core_schema.float_schema()],
+                    variadic_item_index=1,
+                    strict=True,
+                ),
+                core_schema.int_schema(),
+            ],
+        }
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1])
+    assert exc_info.value.errors()[0]['loc'] == ('tuple[int, str, ..., float]',)
+
+
+def test_variadic_item_index_out_of_range():
+    with pytest.raises(SchemaError, match='`variadic_item_index` 1 is out of range for `items_schema` of length 1'):
+        SchemaValidator({'type': 'tuple', 'items_schema': [{'type': 'int'}], 'variadic_item_index': 1})
@@ -1196,3 +1196,61 @@ def test_leak_typed_dict():
     gc.collect()
 
     assert ref() is None
+
+
+def test_typed_dict_fail_fast():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c': core_schema.typed_dict_field(core_schema.int_schema()),
+            },
+            extra_behavior='forbid',
+            config=CoreConfig(fail_fast=True),
+        )
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 'y', 'd': 1})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 2, 'c': 3, 'd': 1, 'e': 2})
+    assert [e['type'] for e in exc_info.value.errors()] == ['extra_forbidden']
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 'y', 'd': 1}, fail_fast=False)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',), ('b',), ('c',), ('d',)]
+
+
+def test_loc_by_alias_override():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'field_a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='fieldA'),
+                'field_b': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='fieldB'),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"fieldA": "x"}', loc_by_alias=False)
+    assert [(e['loc'], e['field_loc']) for e in exc_info.value.errors(include_field_loc=True)] == [
+        (('field_a',), ('field_a',)),
+        (('field_b',), ('field_b',)),
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"fieldA": "x"}')
+    assert [(e['loc'], e['field_loc']) for e in exc_info.value.errors(include_field_loc=True)] == [
+        (('fieldA',), ('field_a',)),
+        (('fieldB',), ('field_b',)),
+    ]